@@ -3,37 +3,34 @@
 //! # Dice Duel
 //!
 //! A two-player dice game where each player rolls two dice.
-//! The player with the highest total wins (ties go to player 1).
+//! The player with the highest total wins; equal totals are a draw and both
+//! stakes are returned by the Game Hub.
+//!
+//! **Commit-Reveal Rolls:**
+//! Each player rolls by committing to `keccak256(secret)` for a 32-byte secret
+//! of their choosing, then reveals the secret once both have rolled. Rolls and
+//! reveals are passed on to the randomness beacon, which combines both secrets
+//! into the seed the dice are rolled from, so neither player can know the
+//! result before the other has committed. A player who does not reveal within
+//! the reveal window forfeits the game (`claim_forfeit`).
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
+//!
+//! **Series and Rematches:**
+//! Players can agree once to a best-of-N series (`start_series`); the hub holds
+//! the series stakes and pays the series winner. Each later game is started
+//! with `rematch`, which needs no further player authorization inside a series.
+//! Outside a series, `rematch` replays a finished game with the same players
+//! and stakes once both players authorize it.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror, contractimpl,
     contracttype, vec
 };
-
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+use sgs_common::admin::{self, Role};
+use sgs_common::{beacon, hub, schema, ttl, Outcome};
 
 // ============================================================================
 // Errors
@@ -48,6 +45,13 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    SessionAlreadyExists = 6,
+    GameInProgress = 7,
+    InvalidReveal = 8,
+    AlreadyRevealed = 9,
+    RevealWindowClosed = 10,
+    RevealWindowOpen = 11,
+    SessionExpiring = 12,
 }
 
 // ============================================================================
@@ -61,43 +65,177 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_rolled: bool,
-    pub player2_rolled: bool,
+    /// `keccak256(secret)` committed by player 1's roll
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Player 1's secret, once revealed
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
+    /// Last ledger on which secrets can be revealed, set once both players have rolled
+    pub reveal_deadline: Option<u32>,
+    /// Beacon output the dice were rolled from
+    pub seed: Option<BytesN<32>>,
     pub player1_die1: Option<u32>,
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
     pub winner: Option<Address>,
+    pub is_draw: bool,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Storage Schema
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
+// Version 1: the original layout
+// Version 2: `Game` gains `is_draw`; rolls are commitments to secrets that are
+//            revealed to the randomness beacon, which seeds the dice
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 2;
+
+/// `Game` as stored at schema version 1
+#[contracttype(export = false)]
+#[derive(Clone)]
+struct GameV1 {
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    player1_rolled: bool,
+    player2_rolled: bool,
+    player1_die1: Option<u32>,
+    player1_die2: Option<u32>,
+    player2_die1: Option<u32>,
+    player2_die2: Option<u32>,
+    winner: Option<Address>,
+}
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+impl From<GameV1> for Game {
+    fn from(old: GameV1) -> Self {
+        // Version 1 rolls carried no secret, so unfinished games go back to
+        // the roll phase; finished games keep their dice and result. Version 1
+        // had no draws; ties went to player 1.
+        Game {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_secret: None,
+            player2_secret: None,
+            reveal_deadline: None,
+            seed: None,
+            player1_die1: old.player1_die1,
+            player1_die2: old.player1_die2,
+            player2_die1: old.player2_die1,
+            player2_die2: old.player2_die2,
+            winner: old.winner,
+            is_draw: false,
+        }
+    }
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Ledgers both players have to reveal once both have rolled (~1 hour)
+const REVEAL_WINDOW_LEDGERS: u32 = 720;
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Record a new game for a session the hub has just opened
+fn create_game(
+    env: &Env,
+    session_id: u32,
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+) -> Result<(), Error> {
+    // The hub never reissues an ID, but never overwrite a game in progress
+    let game_key = DataKey::Game(session_id);
+    if env.storage().temporary().has(&game_key) {
+        return Err(Error::SessionAlreadyExists);
+    }
+
+    // Both players commit to the beacon, which seeds the dice once both reveal
+    beacon::request_session(env, session_id, &player1, &player2, REVEAL_WINDOW_LEDGERS);
+
+    // Create game (dice are derived once both players reveal)
+    let game = Game {
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        player1_commitment: None,
+        player2_commitment: None,
+        player1_secret: None,
+        player2_secret: None,
+        reveal_deadline: None,
+        seed: None,
+        player1_die1: None,
+        player1_die2: None,
+        player2_die1: None,
+        player2_die2: None,
+        winner: None,
+        is_draw: false,
+    };
+
+    // Store game in temporary storage with 30-day TTL
+    env.storage().temporary().set(&game_key, &game);
+
+    // Set TTL to ensure game is retained for at least 30 days
+    ttl::extend_temporary(env, &game_key);
+
+    Ok(())
+}
+
 /// Roll a single die (1-6) using deterministic PRNG
 fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
     env.prng().seed(seed.into());
     env.prng().gen_range::<u64>(1..=6) as u32
 }
 
+/// Roll one die from the game's base seed; `player` and `die` are 1 or 2
+fn roll_from_base(env: &Env, base_seed: &BytesN<32>, player: u8, die: u8) -> u32 {
+    let mut roll_seed_bytes = Bytes::from(base_seed.clone());
+    roll_seed_bytes.append(&Bytes::from_array(env, &[player, die]));
+    roll_die(env, env.crypto().keccak256(&roll_seed_bytes).into())
+}
+
+/// Record the result of a game and end its hub session
+fn settle(env: &Env, session_id: u32, game: &mut Game, outcome: Outcome) -> Option<Address> {
+    game.winner = match outcome {
+        Outcome::Player1 => Some(game.player1.clone()),
+        Outcome::Player2 => Some(game.player2.clone()),
+        Outcome::Draw | Outcome::Cancelled => None,
+    };
+    game.is_draw = outcome == Outcome::Draw;
+    env.storage()
+        .temporary()
+        .set(&DataKey::Game(session_id), game);
+
+    // Call GameHub to end the session
+    // This unlocks points and updates standings
+    // Event emitted by the Game Hub contract (GameEnded)
+    // Games started before the hub recorded sessions have nothing to settle
+    if hub::find_session(env, session_id).is_some() {
+        hub::client(env).end_game(&session_id, &outcome);
+    }
+
+    game.winner.clone()
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -113,11 +251,8 @@ impl DiceDuelContract {
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
+        schema::init(&env, SCHEMA_VERSION);
     }
 
     /// Start a new game between two players with points.
@@ -127,85 +262,153 @@ impl DiceDuelContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    ///
+    /// # Returns
+    /// * `u32` - Session ID allocated by the Game Hub
     pub fn start_game(
         env: Env,
-        session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        // Require authentication from both players (each consents to the opponent and their stake)
+        player1.require_auth_for_args(vec![&env, player2.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player1.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub::client(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
+        let session_id = game_hub.start_game(
             &env.current_contract_address(),
-            &session_id,
             &player1,
             &player2,
             &player1_points,
             &player2_points,
         );
 
-        // Create game (dice not rolled yet - will be generated in reveal_winner)
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+        create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
-            player1_die1: None,
-            player1_die2: None,
-            player2_die1: None,
-            player2_die2: None,
-            winner: None,
-        };
+        )?;
+
+        // Event emitted by GameHub contract (GameStarted)
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        Ok(session_id)
+    }
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
+    /// Start a best-of-N series between two players and its first game.
+    /// The points are locked once by the Game Hub for the whole series and
+    /// paid to the series winner; the games themselves stake nothing.
+    ///
+    /// # Arguments
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points committed by player 1 to the series
+    /// * `player2_points` - Points committed by player 2 to the series
+    /// * `best_of` - Number of games in the series (odd)
+    ///
+    /// # Returns
+    /// * `u32` - Session ID of the first game
+    pub fn start_series(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        best_of: u32,
+    ) -> Result<u32, Error> {
+        if player1 == player2 {
+            panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
+        }
+
+        // Each player consents once to the opponent, their stake and the series length
+        player1.require_auth_for_args(vec![&env, player2.into_val(&env), player1_points.into_val(&env), best_of.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player1.into_val(&env), player2_points.into_val(&env), best_of.into_val(&env)]);
+
+        let game_hub = hub::client(&env);
+        let series_id = game_hub.start_series(
+            &env.current_contract_address(),
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+            &best_of,
+        );
+        let session_id = game_hub.start_series_game(&series_id);
+
+        create_game(&env, session_id, player1, player2, 0, 0)?;
+
+        Ok(session_id)
+    }
+
+    /// Start a new game with the same players as a finished one.
+    /// Inside a series this is the series' next game and needs no player
+    /// authorization; otherwise it replays the stakes and both players must
+    /// authorize it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the finished game
+    ///
+    /// # Returns
+    /// * `u32` - Session ID of the new game
+    pub fn rematch(env: Env, session_id: u32) -> Result<u32, Error> {
+        let previous: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        if previous.winner.is_none() && !previous.is_draw {
+            return Err(Error::GameInProgress);
+        }
 
-        // Event emitted by GameHub contract (GameStarted)
+        let game_hub = hub::client(&env);
+        if game_hub.get_session_series(&session_id).is_none() {
+            previous.player1.require_auth_for_args(vec![&env, previous.player2.into_val(&env), previous.player1_points.into_val(&env)]);
+            previous.player2.require_auth_for_args(vec![&env, previous.player1.into_val(&env), previous.player2_points.into_val(&env)]);
+        }
 
-        Ok(())
+        let new_session_id = game_hub.rematch(&session_id);
+        create_game(
+            &env,
+            new_session_id,
+            previous.player1,
+            previous.player2,
+            previous.player1_points,
+            previous.player2_points,
+        )?;
+
+        Ok(new_session_id)
     }
 
     /// Commit a roll for the current game.
-    /// Both players must roll before the winner can be revealed.
+    /// The roll is a commitment to a secret that the player reveals with
+    /// `reveal_roll` once both players have rolled.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player rolling the dice
-    pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(secret)` for the player's 32-byte secret
+    pub fn roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
@@ -216,25 +419,47 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.is_draw {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update roll commitment for the appropriate player
+        // The reveal window must close before the hub session expires, or a
+        // player could reclaim their stake from the hub instead of revealing
+        let reveal_deadline = env
+            .ledger()
+            .sequence()
+            .saturating_add(REVEAL_WINDOW_LEDGERS);
+        let session = hub::find_session(&env, session_id);
+        if session.is_some_and(|session| reveal_deadline >= session.expires_ledger) {
+            return Err(Error::SessionExpiring);
+        }
+
+        // Record the commitment for the appropriate player
         if player == game.player1 {
-            if game.player1_rolled {
+            if game.player1_commitment.is_some() {
                 return Err(Error::AlreadyRolled);
             }
-            game.player1_rolled = true;
+            game.player1_commitment = Some(commitment.clone());
         } else if player == game.player2 {
-            if game.player2_rolled {
+            if game.player2_commitment.is_some() {
                 return Err(Error::AlreadyRolled);
             }
-            game.player2_rolled = true;
+            game.player2_commitment = Some(commitment.clone());
         } else {
             return Err(Error::NotPlayer);
         }
+        beacon::client(&env).commit(
+            &env.current_contract_address(),
+            &session_id,
+            &player,
+            &commitment,
+        );
+
+        // The reveal window opens once both players have rolled
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.reveal_deadline = Some(reveal_deadline);
+        }
 
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
@@ -242,17 +467,23 @@ impl DiceDuelContract {
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
-    /// This generates dice rolls for both players, determines the winner, and ends the session.
+    /// Reveal the secret behind a roll.
+    /// Can only be called after both players have rolled and before the reveal
+    /// deadline. The second reveal derives both players' dice from the two
+    /// secrets, determines the winner and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    ///
-    /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
-        // Get game from temporary storage
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The secret committed to in `roll`
+    pub fn reveal_roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -260,85 +491,109 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotRolled)?;
+        if env.ledger().sequence() > deadline {
+            return Err(Error::RevealWindowClosed);
         }
 
-        // Check both players have rolled
-        if !game.player1_rolled || !game.player2_rolled {
-            return Err(Error::BothPlayersNotRolled);
+        let (commitment, revealed) = if player == game.player1 {
+            (&game.player1_commitment, &mut game.player1_secret)
+        } else if player == game.player2 {
+            (&game.player2_commitment, &mut game.player2_secret)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
         }
+        *revealed = Some(secret.clone());
 
-        // Generate deterministic dice rolls (1-6)
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Roll dice for both players using unique seeds
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 1]));
-        let player1_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 2]));
-        let player1_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 1]));
-        let player2_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 2]));
-        let player2_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
+        let beacon = beacon::client(&env);
+        beacon.reveal(
+            &env.current_contract_address(),
+            &session_id,
+            &player,
+            &Bytes::from(secret),
+        );
+
+        // Wait for the other player's reveal
+        if game.player1_secret.is_none() || game.player2_secret.is_none() {
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
+        }
+
+        // Both secrets are in, so the beacon has finalized the seed: neither
+        // player could know the other's secret when committing, so neither
+        // could steer the dice
+        let base_seed = beacon
+            .get_output(&env.current_contract_address(), &session_id)
+            .expect("Beacon output not finalized");
+        game.seed = Some(base_seed.clone());
+
+        let player1_die1 = roll_from_base(&env, &base_seed, 1, 1);
+        let player1_die2 = roll_from_base(&env, &base_seed, 1, 2);
+        let player2_die1 = roll_from_base(&env, &base_seed, 2, 1);
+        let player2_die2 = roll_from_base(&env, &base_seed, 2, 2);
 
         game.player1_die1 = Some(player1_die1);
         game.player1_die2 = Some(player1_die2);
         game.player2_die1 = Some(player2_die1);
         game.player2_die2 = Some(player2_die2);
 
-        // Determine winner (if tie, player1 wins)
+        // Determine winner (equal totals are a draw)
         let player1_total = player1_die1 + player1_die2;
         let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
-            game.player1.clone()
+        let outcome = if player1_total > player2_total {
+            Outcome::Player1
+        } else if player2_total > player1_total {
+            Outcome::Player2
         } else {
-            game.player2.clone()
+            Outcome::Draw
         };
+        settle(&env, session_id, &mut game, outcome);
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+        Ok(())
+    }
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
+    /// End a game whose reveal deadline has passed without both reveals.
+    /// A player who revealed wins against one who did not; if neither
+    /// revealed, the game is a draw. Anyone can call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn claim_forfeit(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Call GameHub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        // Before both players roll, the hub's session deadline refunds the stakes
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotRolled)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::RevealWindowOpen);
+        }
 
-        Ok(winner)
+        let outcome = match (game.player1_secret.is_some(), game.player2_secret.is_some()) {
+            (true, false) => Outcome::Player1,
+            (false, true) => Outcome::Player2,
+            _ => Outcome::Draw,
+        };
+        Ok(settle(&env, session_id, &mut game, outcome))
     }
 
     /// Get game information.
@@ -357,78 +612,69 @@ impl DiceDuelContract {
     }
 
     // ========================================================================
-    // Admin Functions
+    // Schema Migration
     // ========================================================================
 
-    /// Get the current admin address
+    /// Get the schema version of the stored data
     ///
     /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+    /// * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
     }
 
-    /// Set a new admin address
+    /// Convert games stored by an earlier build to the current layout (admin or upgrader).
+    /// Run after `upgrade`, once the beacon is set. Missing or already converted
+    /// games are skipped, so a large backlog can be migrated in batches; the
+    /// version only moves once the last batch is sent. Those builds chose their
+    /// own session IDs and the hub kept no record of them, so their games play
+    /// on without a hub session and end without settling anything at the hub.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
+    /// * `caller` - The admin or an account holding `Upgrader`
+    /// * `session_ids` - Session IDs of in-flight games
+    /// * `complete` - True on the last batch; records this build's schema version
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
-    }
-
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// * `u32` - Number of games converted
+    pub fn migrate(env: Env, caller: Address, session_ids: Vec<u32>, complete: bool) -> u32 {
+        admin::require_role(&env, Role::Upgrader, &caller);
+
+        let mut converted = 0;
+        for session_id in session_ids.iter() {
+            let key = DataKey::Game(session_id);
+            if schema::migrate_temporary(&env, &key, "seed", |old: GameV1| Game::from(old)) {
+                converted += 1;
+
+                // Games sent back to the roll phase roll through the beacon
+                let game: Game = env.storage().temporary().get(&key).unwrap();
+                if game.winner.is_none() && !game.is_draw {
+                    beacon::request_session(
+                        &env,
+                        session_id,
+                        &game.player1,
+                        &game.player2,
+                        REVEAL_WINDOW_LEDGERS,
+                    );
+                }
+            }
+        }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if complete {
+            schema::write_version(&env, SCHEMA_VERSION);
+        }
+        converted
     }
+}
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+// ============================================================================
+// Admin Functions
+// ============================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-}
+sgs_common::impl_admin!(DiceDuelContract);
+sgs_common::impl_hub_config!(DiceDuelContract);
+sgs_common::impl_beacon_config!(DiceDuelContract);
+sgs_common::impl_upgrade!(DiceDuelContract);
 
 // ============================================================================
 // Tests
@@ -3,9 +3,14 @@
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use crate::{DataKey, DiceDuelContract, DiceDuelContractClient, Error, GameV1, Outcome};
+use randomness::{RandomnessContract, RandomnessContractClient};
+use sgs_common::admin::CommonKey;
+use sgs_common::{Session, SessionStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Val, Vec,
+};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -17,24 +22,114 @@ pub struct MockGameHub;
 #[contractimpl]
 impl MockGameHub {
     pub fn start_game(
+        env: Env,
+        _game_id: Address,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) -> u32 {
+        // Allocate sequential session IDs like the real hub
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("next_id"))
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &(session_id + 1));
+        session_id
+    }
+
+    pub fn start_series(
         _env: Env,
         _game_id: Address,
-        _session_id: u32,
         _player1: Address,
         _player2: Address,
         _player1_points: i128,
         _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
+        _best_of: u32,
+    ) -> u32 {
+        // A single series is enough for these tests
+        1
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    pub fn start_series_game(env: Env, series_id: u32) -> u32 {
+        let session_id = Self::start_game(
+            env.clone(),
+            env.current_contract_address(),
+            env.current_contract_address(),
+            env.current_contract_address(),
+            0,
+            0,
+        );
+        env.storage()
+            .instance()
+            .set(&(symbol_short!("series"), session_id), &series_id);
+        session_id
+    }
+
+    pub fn rematch(env: Env, session_id: u32) -> u32 {
+        match Self::get_session_series(env.clone(), session_id) {
+            Some(series_id) => Self::start_series_game(env, series_id),
+            None => Self::start_game(
+                env.clone(),
+                env.current_contract_address(),
+                env.current_contract_address(),
+                env.current_contract_address(),
+                0,
+                0,
+            ),
+        }
+    }
+
+    pub fn get_session_series(env: Env, session_id: u32) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&(symbol_short!("series"), session_id))
+    }
+
+    /// Test hook: make the hub hand out an ID it has already issued
+    pub fn set_next_session_id(env: Env, session_id: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &session_id);
+    }
+
+    pub fn get_session(env: Env, _session_id: u32) -> Session {
+        Session {
+            game_id: env.storage().instance().get(&symbol_short!("game")).unwrap(),
+            players: vec![&env],
+            stakes: vec![&env],
+            status: SessionStatus::Active,
+            start_ledger: 0,
+            expires_ledger: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("expires"))
+                .unwrap_or(u32::MAX),
+            token: None,
+            fee_bps: 0,
+        }
+    }
+
+    /// Test hook: make every session expire after `ledger`
+    pub fn set_expires_ledger(env: Env, ledger: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("expires"), &ledger);
     }
 
-    pub fn add_game(_env: Env, _game_address: Address) {
+    pub fn end_game(_env: Env, _session_id: u32, _outcome: Outcome) {
         // Mock implementation - does nothing
     }
+
+    pub fn add_game(env: Env, game_address: Address) {
+        // Sessions are reported as the game's own
+        env.storage()
+            .instance()
+            .set(&symbol_short!("game"), &game_address);
+    }
 }
 
 // ============================================================================
@@ -77,12 +172,33 @@ fn setup_test() -> (
     // Register dice-duel as a whitelisted game (mock does nothing)
     game_hub.add_game(&contract_id);
 
+    // Deploy the randomness beacon the dice are seeded from
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
 
     (env, client, game_hub, player1, player2)
 }
 
+/// Point the game at a real Game Hub with 1000 points for each player
+fn use_real_hub(
+    env: &Env,
+    client: &DiceDuelContractClient<'static>,
+    player1: &Address,
+    player2: &Address,
+) -> mock_game_hub::MockGameHubClient<'static> {
+    let admin = client.get_admin();
+    let hub_addr = env.register(mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(env, &hub_addr);
+    hub.add_game(&client.address);
+    hub.add_points(player1, &1000);
+    hub.add_points(player2, &1000);
+    client.set_hub(&admin, &hub_addr);
+    hub
+}
+
 /// Assert that a Result contains a specific dice_duel error
 fn assert_dice_duel_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
@@ -117,46 +233,77 @@ fn assert_dice_duel_error<T, E>(
     }
 }
 
+/// A player's secret and the commitment they roll with
+fn secret_roll(env: &Env, seed: u8) -> (BytesN<32>, BytesN<32>) {
+    let secret = BytesN::from_array(env, &[seed; 32]);
+    let commitment = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+    (secret, commitment)
+}
+
+/// Roll for both players and reveal both secrets, which ends the game
+fn play_out(
+    env: &Env,
+    client: &DiceDuelContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    let (secret1, commitment1) = secret_roll(env, 1);
+    let (secret2, commitment2) = secret_roll(env, 2);
+    client.roll(&session_id, player1, &commitment1);
+    client.roll(&session_id, player2, &commitment2);
+    client.reveal_roll(&session_id, player1, &secret1);
+    client.reveal_roll(&session_id, player2, &secret2);
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
 
     // Verify initial state
     let game = client.get_game(&session_id);
     assert!(game.winner.is_none());
+    assert!(!game.is_draw);
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(game.player1_commitment.is_none());
+    assert!(game.player2_commitment.is_none());
+    assert!(game.reveal_deadline.is_none());
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
     assert!(game.player2_die2.is_none());
 
-    // Players roll
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    // Players roll by committing to their secrets
+    let (secret1, commitment1) = secret_roll(&env, 1);
+    let (secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+    assert_eq!(client.get_game(&session_id).reveal_deadline, Some(100 + 720));
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // One reveal is not enough to roll the dice
+    client.reveal_roll(&session_id, &player2, &secret2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player2_secret, Some(secret2));
+    assert!(game.player1_die1.is_none() && game.winner.is_none());
+
+    // The second reveal rolls the dice and ends the game
+    client.reveal_roll(&session_id, &player1, &secret1);
 
     // Verify dice values and winner stored
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert!(final_game.winner.is_some() || final_game.is_draw);
 
     let p1d1 = final_game.player1_die1.unwrap();
     let p1d2 = final_game.player1_die2.unwrap();
@@ -172,74 +319,626 @@ fn test_complete_game() {
     let total2 = p2d1 + p2d2;
     assert!((2..=12).contains(&total1));
     assert!((2..=12).contains(&total2));
+
+    // Highest total wins, equal totals are a draw
+    let expected_winner = if total1 > total2 {
+        Some(player1)
+    } else if total2 > total1 {
+        Some(player2)
+    } else {
+        None
+    };
+    assert_eq!(final_game.winner, expected_winner);
+    assert_eq!(final_game.is_draw, total1 == total2);
+}
+
+#[test]
+fn test_dice_come_from_both_secrets() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    // The same session and players give different dice for different secrets
+    let mut rolls = vec![&env];
+    for seed in 2..6u8 {
+        let session_id = client.start_game(&player1, &player2, &0, &0);
+        let (secret1, commitment1) = secret_roll(&env, 1);
+        let (secret2, commitment2) = secret_roll(&env, seed);
+        client.roll(&session_id, &player1, &commitment1);
+        client.roll(&session_id, &player2, &commitment2);
+        client.reveal_roll(&session_id, &player1, &secret1);
+        client.reveal_roll(&session_id, &player2, &secret2);
+
+        let game = client.get_game(&session_id);
+        rolls.push_back((
+            game.player1_die1.unwrap(),
+            game.player1_die2.unwrap(),
+            game.player2_die1.unwrap(),
+            game.player2_die2.unwrap(),
+        ));
+    }
+    let first = rolls.get_unchecked(0);
+    assert!(rolls.iter().any(|roll| roll != first));
+}
+
+#[test]
+fn test_dice_come_from_beacon_output() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+
+    let session_id = client.start_game(&player1, &player2, &0, &0);
+    let request = beacon.get_request(&client.address, &session_id);
+    assert_eq!(
+        request.participants,
+        vec![&env, player1.clone(), player2.clone()]
+    );
+    assert_eq!(request.bond_token, None);
+
+    // Rolls and reveals reach the beacon, which finalizes on the second reveal
+    let (secret1, commitment1) = secret_roll(&env, 1);
+    let (secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+    let request = beacon.get_request(&client.address, &session_id);
+    assert_eq!(
+        request.reveal_deadline,
+        client.get_game(&session_id).reveal_deadline
+    );
+
+    client.reveal_roll(&session_id, &player1, &secret1);
+    assert_eq!(beacon.get_output(&client.address, &session_id), None);
+    client.reveal_roll(&session_id, &player2, &secret2);
+
+    let seed = beacon.get_output(&client.address, &session_id);
+    assert!(seed.is_some());
+    assert_eq!(client.get_game(&session_id).seed, seed);
 }
 
 #[test]
 fn test_cannot_roll_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 2u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
 
-    client.roll(&session_id, &player1);
-    let result = client.try_roll(&session_id, &player1);
+    let (_secret, commitment) = secret_roll(&env, 1);
+    client.roll(&session_id, &player1, &commitment);
+    let result = client.try_roll(&session_id, &player1, &commitment);
     assert_dice_duel_error(&result, Error::AlreadyRolled);
 }
 
+#[test]
+fn test_cannot_roll_when_session_expires_before_reveals() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_secret, commitment) = secret_roll(&env, 1);
+
+    // A roll at ledger 100 opens a window up to ledger 820
+    hub.set_expires_ledger(&(100 + 720));
+    let result = client.try_roll(&session_id, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::SessionExpiring);
+
+    hub.set_expires_ledger(&(100 + 721));
+    client.roll(&session_id, &player1, &commitment);
+}
+
+#[test]
+fn test_beacon_commits_close_with_the_session() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    // The last roll the game accepts is the beacon's last commit
+    hub.set_expires_ledger(&(100 + 5000));
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+    let request = beacon.get_request(&client.address, &session_id);
+    assert_eq!(request.commit_deadline, 100 + 5000 - 721);
+    assert_eq!(request.reveal_ledgers, 720);
+
+    let (_secret1, commitment1) = secret_roll(&env, 1);
+    env.ledger().set_sequence_number(100 + 5000 - 720);
+    let result = client.try_roll(&session_id, &player1, &commitment1);
+    assert_dice_duel_error(&result, Error::SessionExpiring);
+    env.ledger().set_sequence_number(100 + 5000 - 721);
+    client.roll(&session_id, &player1, &commitment1);
+}
+
+#[test]
+fn test_opponent_cannot_front_run_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, commitment1) = secret_roll(&env, 1);
+    let (secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+
+    // Player 2 sees player 1's secret in their pending reveal and tries to
+    // reveal it on the beacon first, which would make player 1's reveal fail
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+    let leaked = Bytes::from(secret1.clone());
+    let args: Vec<Val> = (
+        client.address.clone(),
+        session_id,
+        player1.clone(),
+        leaked.clone(),
+    )
+        .into_val(&env);
+    env.mock_auths(&[MockAuth {
+        address: &player2,
+        invoke: &MockAuthInvoke {
+            contract: &beacon.address,
+            fn_name: "reveal",
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+    let result = beacon.try_reveal(&client.address, &session_id, &player1, &leaked);
+    assert!(result.is_err());
+
+    // Player 1's own reveal still goes through and the game plays out
+    env.mock_all_auths();
+    client.reveal_roll(&session_id, &player1, &secret1);
+    client.reveal_roll(&session_id, &player2, &secret2);
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_some() || game.is_draw);
+}
+
 #[test]
 fn test_cannot_reveal_before_both_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 3u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
+    let (secret, commitment) = secret_roll(&env, 1);
+    client.roll(&session_id, &player1, &commitment);
 
-    let result = client.try_reveal_winner(&session_id);
+    let result = client.try_reveal_roll(&session_id, &player1, &secret);
     assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
 }
 
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, commitment1) = secret_roll(&env, 1);
+    let (secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+
+    let result = client.try_reveal_roll(&session_id, &player1, &secret2);
+    assert_dice_duel_error(&result, Error::InvalidReveal);
+
+    client.reveal_roll(&session_id, &player1, &secret1);
+    let result = client.try_reveal_roll(&session_id, &player1, &secret1);
+    assert_dice_duel_error(&result, Error::AlreadyRevealed);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_roll(&session_id, &non_player, &secret2);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+}
+
 #[test]
 fn test_non_player_cannot_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 4u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
 
-    let non_player = Address::generate(&_env);
-    let result = client.try_roll(&session_id, &non_player);
+    let non_player = Address::generate(&env);
+    let (_secret, commitment) = secret_roll(&env, 1);
+    let result = client.try_roll(&session_id, &non_player, &commitment);
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_roll_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 5u32;
     let points = 100_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
-    client.reveal_winner(&session_id);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
+    play_out(&env, &client, session_id, &player1, &player2);
 
-    let result = client.try_roll(&session_id, &player1);
+    let (_secret, commitment) = secret_roll(&env, 3);
+    let result = client.try_roll(&session_id, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_player_who_never_reveals_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, commitment1) = secret_roll(&env, 1);
+    let (secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+    client.reveal_roll(&session_id, &player2, &secret2);
+
+    // Player 1 still has until the deadline to reveal
+    env.ledger().set_sequence_number(100 + 720);
+    let result = client.try_claim_forfeit(&session_id);
+    assert_dice_duel_error(&result, Error::RevealWindowOpen);
+
+    env.ledger().set_sequence_number(100 + 721);
+    let result = client.try_reveal_roll(&session_id, &player1, &secret1);
+    assert_dice_duel_error(&result, Error::RevealWindowClosed);
+
+    assert_eq!(client.claim_forfeit(&session_id), Some(player2.clone()));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2));
+    assert!(game.player1_die1.is_none());
+
+    let result = client.try_claim_forfeit(&session_id);
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
+#[test]
+fn test_forfeit_by_both_players_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_secret1, commitment1) = secret_roll(&env, 1);
+    client.roll(&session_id, &player1, &commitment1);
+
+    // Without both rolls there is no reveal window; the hub deadline applies
+    let result = client.try_claim_forfeit(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+
+    let (_secret2, commitment2) = secret_roll(&env, 2);
+    client.roll(&session_id, &player2, &commitment2);
+    env.ledger().set_sequence_number(100 + 721);
+
+    assert_eq!(client.claim_forfeit(&session_id), None);
+    assert!(client.get_game(&session_id).is_draw);
+}
+
 #[test]
 fn test_upgrade_function_exists() {
     let (_env, client, _hub, _player1, _player2) = setup_test();
 
     // Verify upgrade function is callable by admin (mocked auth)
     let new_wasm_hash = BytesN::from_array(&_env, &[0u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
+    let result = client.try_upgrade(&client.get_admin(), &new_wasm_hash);
 
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
+
+#[test]
+fn test_reused_session_id_rejected() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    // A hub that hands out the same ID again must not overwrite the live game
+    hub.set_next_session_id(&session_id);
+    let result = client.try_start_game(&player1, &player2, &100_0000000, &100_0000000);
+    assert_dice_duel_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_game_starts_after_legacy_session_id() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let hub = use_real_hub(&env, &client, &player1, &player2);
+
+    // A game started before the hub allocated IDs still holds session 1
+    let legacy = GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100,
+        player2_points: 100,
+        player1_rolled: true,
+        player2_rolled: false,
+        player1_die1: None,
+        player1_die2: None,
+        player2_die1: None,
+        player2_die2: None,
+        winner: None,
+    };
+    write_legacy(&env, &client, 1, legacy);
+
+    // The rejected start rolls back the hub's counter too, so it is stuck on 1
+    let result = client.try_start_game(&player1, &player2, &100, &100);
+    assert_dice_duel_error(&result, Error::SessionAlreadyExists);
+
+    hub.reserve_session_ids(&1);
+    assert_eq!(client.start_game(&player1, &player2, &100, &100), 2);
+    assert_eq!(hub.get_balance(&player1), 900);
+}
+
+// ============================================================================
+// Series and Rematch Tests
+// ============================================================================
+
+#[test]
+fn test_series_games_need_no_further_auth() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_series(&player1, &player2, &100_0000000, &100_0000000, &3);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == player1));
+    assert!(auths.iter().any(|(address, _)| *address == player2));
+
+    // The series holds the stakes, so its games stake nothing
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_points, 0);
+    assert_eq!(game.player2_points, 0);
+
+    play_out(&env, &client, session_id, &player1, &player2);
+    let next_session_id = client.rematch(&session_id);
+    assert!(env.auths().is_empty());
+
+    assert_ne!(next_session_id, session_id);
+    let next_game = client.get_game(&next_session_id);
+    assert_eq!(next_game.player1, player1);
+    assert_eq!(next_game.player2, player2);
+    assert!(next_game.player1_commitment.is_none() && next_game.winner.is_none());
+}
+
+#[test]
+fn test_standalone_rematch_replays_stakes() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &50_0000000);
+    play_out(&env, &client, session_id, &player1, &player2);
+
+    let next_session_id = client.rematch(&session_id);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == player1));
+    assert!(auths.iter().any(|(address, _)| *address == player2));
+
+    let next_game = client.get_game(&next_session_id);
+    assert_eq!(next_game.player1_points, 100_0000000);
+    assert_eq!(next_game.player2_points, 50_0000000);
+}
+
+#[test]
+fn test_cannot_rematch_game_in_progress() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_secret, commitment) = secret_roll(&env, 1);
+    client.roll(&session_id, &player1, &commitment);
+
+    let result = client.try_rematch(&session_id);
+    assert_dice_duel_error(&result, Error::GameInProgress);
+    let result = client.try_rematch(&999);
+    assert_dice_duel_error(&result, Error::GameNotFound);
+}
+
+// ============================================================================
+// Schema Migration Tests
+// ============================================================================
+
+/// A version 1 game in which only player 1 has rolled
+fn v1_game(player1: &Address, player2: &Address) -> GameV1 {
+    GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 100_0000000,
+        player1_rolled: true,
+        player2_rolled: false,
+        player1_die1: None,
+        player1_die2: None,
+        player2_die1: None,
+        player2_die2: None,
+        winner: None,
+    }
+}
+
+/// Rewrite a game and the stored version the way an earlier build left them
+fn write_legacy(
+    env: &Env,
+    client: &DiceDuelContractClient<'static>,
+    session_id: u32,
+    game: impl IntoVal<Env, Val>,
+) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, &game.into_val(env));
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
+#[test]
+fn test_new_deployment_uses_current_schema() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+    assert_eq!(client.version(), 2);
+}
+
+#[test]
+fn test_migrate_keeps_in_flight_games_playable() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = 7;
+
+    // Simulate an upgrade from a build that wrote the version 1 layout
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert_eq!(client.version(), 1);
+    assert!(client.try_get_game(&session_id).is_err());
+
+    // A partial batch converts its games but leaves the version alone
+    assert_eq!(
+        client.migrate(&admin, &vec![&env, session_id, 999], &false),
+        1
+    );
+    assert_eq!(client.version(), 1);
+
+    // A version 1 roll had no secret, so the game is back in the roll phase
+    let game = client.get_game(&session_id);
+    assert!(game.player1_commitment.is_none() && game.reveal_deadline.is_none());
+    assert!(game.winner.is_none() && !game.is_draw);
+
+    // Already converted games are skipped, and the last batch moves the version
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 0);
+    assert_eq!(client.version(), 2);
+    play_out(&env, &client, session_id, &player1, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_some() || game.is_draw);
+}
+
+#[test]
+fn test_migrate_moves_version_1_games_to_the_beacon() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+    let session_id = 7;
+
+    // Version 1 rolls were never committed to the beacon
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+
+    let game = client.get_game(&session_id);
+    assert!(game.seed.is_none());
+    let request = beacon.get_request(&client.address, &session_id);
+    assert!(request.commitments.is_empty());
+
+    play_out(&env, &client, session_id, &player1, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.seed.is_some());
+    assert!(game.winner.is_some() || game.is_draw);
+}
+
+#[test]
+fn test_migrate_plays_out_games_the_hub_never_recorded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let hub = use_real_hub(&env, &client, &player1, &player2);
+    let session_id = 7;
+
+    // The original build chose session 7 itself, so the hub has no record of it
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert!(hub.try_get_session(&session_id).is_err());
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+
+    // The game plays out; nothing was locked at the hub, so no points move
+    play_out(&env, &client, session_id, &player1, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_some() || game.is_draw);
+    assert_eq!(hub.get_balance(&player1), 1000);
+    assert_eq!(hub.get_balance(&player2), 1000);
+
+    // Games started after the upgrade still settle through the hub
+    hub.reserve_session_ids(&session_id);
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    play_out(&env, &client, session_id, &player1, &player2);
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
+}
+
+#[test]
+fn test_migrate_keeps_version_1_results() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    let finished = GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 100_0000000,
+        player1_rolled: true,
+        player2_rolled: true,
+        player1_die1: Some(6),
+        player1_die2: Some(5),
+        player2_die1: Some(1),
+        player2_die2: Some(2),
+        winner: Some(player1.clone()),
+    };
+    write_legacy(&env, &client, session_id, finished);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.player1_die1, Some(6));
+    assert_eq!(game.player2_die2, Some(2));
+    assert!(!game.is_draw);
+}
+
+// ============================================================================
+// WASM Upgrade Test
+// ============================================================================
+// Games are started on the baseline release, deployed from its WASM with the
+// baseline hub, and finished after upgrading both contracts to this build.
+
+mod baseline {
+    pub mod game {
+        soroban_sdk::contractimport!(file = "fixtures/dice_duel_baseline.wasm");
+    }
+
+    pub mod hub {
+        soroban_sdk::contractimport!(file = "../mock-game-hub/fixtures/mock_game_hub_baseline.wasm");
+    }
+}
+
+/// Swap this build's code in under a contract deployed from an older WASM.
+/// Registering it runs the constructor, which an on-chain upgrade does not:
+/// the admin and hub it writes are the ones already stored, and the schema
+/// version it records is removed so the data reads as unmigrated.
+fn upgrade_in_place(env: &Env, contract_id: &Address, admin: &Address, hub: &Address) {
+    env.register_at(contract_id, DiceDuelContract, (admin, hub));
+    env.as_contract(contract_id, || {
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
+#[test]
+fn test_upgrade_from_baseline_wasm() {
+    let (env, _client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+
+    // The baseline release starts three games with IDs it picks itself
+    let hub_addr = env.register(baseline::hub::WASM, ());
+    let contract_id = env.register(baseline::game::WASM, (&admin, &hub_addr));
+    let old = baseline::game::Client::new(&env, &contract_id);
+    for session_id in 1..=3 {
+        old.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    old.roll(&1, &player1);
+    old.roll(&2, &player1);
+    old.roll(&2, &player2);
+    let finished_winner = old.reveal_winner(&2);
+
+    // The baseline hub kept no state, so this build's hub replaces it; its
+    // counter moves past the IDs the games already hold
+    env.register_at(&hub_addr, mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(&env, &hub_addr);
+    hub.add_game(&contract_id);
+    hub.add_points(&player1, &1000);
+    hub.add_points(&player2, &1000);
+    hub.reserve_session_ids(&3);
+
+    upgrade_in_place(&env, &contract_id, &admin, &hub_addr);
+    let client = DiceDuelContractClient::new(&env, &contract_id);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.get_admin(), admin);
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, 1, 2, 3], &true), 3);
+    assert_eq!(client.version(), 2);
+
+    // The finished game keeps its result; the others go back to the roll
+    // phase and play out on the beacon, settling nothing at the hub
+    assert_eq!(client.get_game(&2).winner, Some(finished_winner));
+    for session_id in [1, 3] {
+        assert!(client.get_game(&session_id).player1_commitment.is_none());
+        play_out(&env, &client, session_id, &player1, &player2);
+        let game = client.get_game(&session_id);
+        assert!(game.seed.is_some());
+        assert!(game.winner.is_some() || game.is_draw);
+    }
+    assert_eq!(hub.get_balance(&player1), 1000);
+
+    // New games take hub-allocated IDs past the baseline ones and settle there
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    assert_eq!(session_id, 4);
+    play_out(&env, &client, session_id, &player1, &player2);
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
+}
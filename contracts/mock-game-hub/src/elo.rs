@@ -0,0 +1,59 @@
+//! Integer Elo rating math.
+//!
+//! Soroban contracts cannot use floating point, so the expected score
+//! `1 / (1 + 10^(-diff / 400))` is read from a table in basis points and
+//! linearly interpolated between 25-point steps.
+
+/// Rating given to a player the first time they play a game
+pub const INITIAL_RATING: u32 = 1200;
+
+/// Maximum rating change per game
+const K_FACTOR: i64 = 32;
+
+/// Scores in basis points (1.0 = 10,000)
+pub const SCORE_WIN: i64 = 10_000;
+pub const SCORE_DRAW: i64 = 5_000;
+pub const SCORE_LOSS: i64 = 0;
+
+/// Rating difference covered by each table step
+const STEP: i64 = 25;
+
+/// Expected score (bps) for rating differences 0, 25, 50, ... 800
+const EXPECTED_BPS: [i64; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823,
+    8965, 9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848,
+    9868, 9886, 9901,
+];
+
+/// Expected score (bps) of a player whose rating is `diff` points above their opponent
+fn expected_bps(diff: i64) -> i64 {
+    if diff < 0 {
+        return SCORE_WIN - expected_bps(-diff);
+    }
+    let max = STEP * (EXPECTED_BPS.len() as i64 - 1);
+    if diff >= max {
+        return EXPECTED_BPS[EXPECTED_BPS.len() - 1];
+    }
+    let idx = (diff / STEP) as usize;
+    let low = EXPECTED_BPS[idx];
+    let high = EXPECTED_BPS[idx + 1];
+    low + (high - low) * (diff % STEP) / STEP
+}
+
+/// Rating change for `a` after one game against `b`, where `score_a` is a's
+/// result in bps. `b` changes by the same amount in the other direction.
+pub fn delta(rating_a: u32, rating_b: u32, score_a: i64) -> i64 {
+    let expected_a = expected_bps(rating_a as i64 - rating_b as i64);
+    // Round half away from zero so small edges still move ratings
+    let raw = K_FACTOR * (score_a - expected_a);
+    if raw >= 0 {
+        (raw + SCORE_WIN / 2) / SCORE_WIN
+    } else {
+        (raw - SCORE_WIN / 2) / SCORE_WIN
+    }
+}
+
+/// Apply a rating change; ratings never drop below zero
+pub fn apply(rating: u32, delta: i64) -> u32 {
+    (rating as i64 + delta).clamp(0, u32::MAX as i64) as u32
+}
@@ -1,16 +1,153 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+//! # Mock Game Hub
+//!
+//! Development stand-in for the Game Hub contract that games are played through.
+//! It exposes the same external interface that games expect (start_game, end_game)
+//! and keeps a simple points ledger so stakes are actually locked and settled.
+//! Table games with 3-8 seats use `start_game_multi` / `end_game_multi`; the
+//! two-player functions are thin wrappers over the same session logic.
+//!
+//! **Points Ledger:**
+//! - Each player has a points balance held by the hub
+//! - `start_game` locks both players' stakes for the session
+//! - `end_game` pays the locked pot to the winner, or returns each stake on a
+//!   draw or cancellation
+//!
+//! **Faucet:**
+//! When the admin configures a faucet, any address can call
+//! `claim_daily_points` once per cooldown window for a free allowance of
+//! points, topped up to at most a balance cap. This lets points-staked games
+//! run free-to-play without a token.
+//!
+//! **Token Escrow:**
+//! A game can be configured with a SEP-41 token (e.g. a Stellar Asset
+//! Contract). Its sessions then escrow real tokens instead of points: stakes
+//! are transferred to the hub on `start_game` and paid out or refunded from
+//! the hub on settlement.
+//!
+//! **Fees:**
+//! The admin can set a fee in basis points that is taken from the pot of every
+//! decided session (draws and cancellations are refunded in full). The fee is
+//! split between the hub treasury and the developer registered for the game,
+//! and accrues in the hub until each recipient calls `withdraw_fees`.
+//!
+//! **Game Registry:**
+//! Only game contracts registered by the admin can start sessions, and the
+//! calling game contract must authorize `start_game` itself. The admin can
+//! also set stake limits per game: a minimum and maximum stake, and whether
+//! the stakes in a session must be equal or within a ratio of each other.
+//!
+//! **Emergency Pause:**
+//! The admin, or an account granted the `Pauser` role, can pause the whole hub
+//! (`pause_all`) or a single game (`pause_game`) without redeploying anything.
+//! While paused, no new sessions can start; sessions already running can still
+//! be ended or reclaimed.
+//!
+//! **Sessions:**
+//! Session IDs are allocated by the hub from a counter, so they are never
+//! reused. When upgrading from a build where games chose their own session
+//! IDs, the admin moves the counter past them with `reserve_session_ids`.
+//! Every session is recorded with the game that opened it. Only that game can
+//! end the session, and only once. Each registered game has a maximum session
+//! duration; once a session passes its deadline either player can reclaim
+//! their stake with `claim_expired_session`.
+//!
+//! **Series and Rematches:**
+//! A game can start a best-of-N series between two players with
+//! `start_series`. The series stakes are escrowed once, in a pot session
+//! that stays open for `best_of` times the game's maximum session duration.
+//! Each game is then started with `start_series_game` as an unstaked hub
+//! session; the first player to win a majority of the N games (or the player
+//! ahead once N games are played) takes the pot, and a level series is
+//! refunded. Only the series result is rated. `rematch` starts a new session
+//! with the same players and stakes as a finished one, or the next game when
+//! the session belongs to a series.
+//!
+//! **Ratings:**
+//! The hub keeps an Elo rating per (game, player), updated on every decided or
+//! drawn session. Cancelled and expired sessions are not rated.
+//!
+//! **Statistics:**
+//! Decided and drawn sessions also update each player's stats, both per game
+//! and across all games, and `get_leaderboard` pages through a game's players
+//! ranked by wins or net points.
+//!
+//! **Seasons:**
+//! The admin can start a season that runs until an end ledger. While it runs,
+//! every rated session also updates season ratings (starting from the initial
+//! rating) and hub-wide season stats. Anyone can fund the season's reward pot.
+//! Once the end ledger has passed, `close_season` archives the final standings
+//! and pays the pot to the top places. Past seasons stay queryable by number.
+//!
+//! **Admin:**
+//! Admin control moves in two steps (`propose_admin`, then `accept_admin` by
+//! the new admin before the proposal expires), and routine permissions such
+//! as pausing can be granted as roles; see `sgs_common::admin`.
 
-/// Mock Game Hub contract for game studio development
-///
-/// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
-#[contract]
-pub struct MockGameHub;
+use sgs_common::admin::{self, read_admin, Role};
+pub use sgs_common::hub::{Outcome, Session, SessionStatus};
+use sgs_common::schema;
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, vec, Address, Env,
+    IntoVal, Vec,
+};
+
+mod elo;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    InsufficientBalance = 1,
+    InvalidAmount = 2,
+    SessionNotFound = 3,
+    SessionAlreadyExists = 4,
+    Overflow = 5,
+    GameNotRegistered = 6,
+    SessionNotActive = 7,
+    SessionNotExpired = 8,
+    NotPlayer = 9,
+    InvalidDuration = 10,
+    InvalidFee = 11,
+    NothingToWithdraw = 12,
+    InvalidPlayerCount = 13,
+    DuplicatePlayer = 14,
+    StakeCountMismatch = 15,
+    InvalidShares = 16,
+    SeasonActive = 17,
+    NoActiveSeason = 18,
+    SeasonNotFound = 19,
+    SeasonNotOver = 20,
+    InvalidSeason = 21,
+    FaucetDisabled = 22,
+    FaucetCooldown = 23,
+    FaucetCapReached = 24,
+    HubPaused = 25,
+    GamePaused = 26,
+    StakeTooLow = 27,
+    StakeTooHigh = 28,
+    StakesNotMatched = 29,
+    InvalidStakeLimits = 30,
+    InvalidSeriesLength = 31,
+    SeriesNotFound = 32,
+    SeriesNotActive = 33,
+    SessionStillActive = 34,
+    SeriesPotLocked = 35,
+    GameAlreadyRegistered = 36,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+// Every event carries its schema version as the second topic, e.g.
+// `["game_ended", "v1"]`, so indexers can tell schema changes apart.
 
-#[contractevent]
+#[contractevent(topics = ["game_started", "v1"])]
 pub struct GameStarted {
     pub session_id: u32,
     pub game_id: Address,
@@ -20,78 +157,2470 @@ pub struct GameStarted {
     pub player2_points: i128,
 }
 
-#[contractevent]
+/// A session decided or drawn through `end_game`
+#[contractevent(topics = ["game_ended", "v1"])]
 pub struct GameEnded {
     pub session_id: u32,
-    pub player1_won: bool,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub outcome: Outcome,
+    pub winner: Option<Address>,
+    /// Token the stakes were escrowed in, or `None` for hub points
+    pub token: Option<Address>,
+    /// Total stakes locked for the session
+    pub pot: i128,
+    /// Fee taken from the pot (0 on a draw)
+    pub fee: i128,
 }
 
-#[contractimpl]
-impl MockGameHub {
-    /// Start a game session
-    ///
-    /// # Arguments
-    /// * `game_id` - Address of the game contract calling this method
-    /// * `session_id` - Unique identifier for this game session
-    /// * `player1` - Address of first player
-    /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount for player 1 (ignored in mock)
-    /// * `player2_points` - Points amount for player 2 (ignored in mock)
-    pub fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    ) {
-        // No auth required for mock
-        GameStarted {
-            session_id,
-            game_id,
-            player1,
-            player2,
-            player1_points,
-            player2_points,
+#[contractevent(topics = ["multi_game_started", "v1"])]
+pub struct MultiGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+}
+
+#[contractevent(topics = ["multi_game_ended", "v1"])]
+pub struct MultiGameEnded {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub payouts: Vec<i128>,
+    pub token: Option<Address>,
+    pub pot: i128,
+    pub fee: i128,
+}
+
+#[contractevent(topics = ["fee_collected", "v1"])]
+pub struct FeeCollected {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub token: Option<Address>,
+    pub treasury: Address,
+    pub treasury_amount: i128,
+    pub developer: Option<Address>,
+    pub developer_amount: i128,
+}
+
+/// A session cancelled by its game; every stake was refunded
+#[contractevent(topics = ["session_cancelled", "v1"])]
+pub struct SessionCancelled {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+    pub token: Option<Address>,
+}
+
+/// A session reclaimed after its deadline; every stake was refunded
+#[contractevent(topics = ["session_expired", "v1"])]
+pub struct SessionExpired {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+    pub token: Option<Address>,
+    pub claimed_by: Address,
+}
+
+/// A best-of-N series opened; its stakes are locked in `pot_session`
+#[contractevent(topics = ["series_started", "v1"])]
+pub struct SeriesStarted {
+    pub series_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+    pub best_of: u32,
+    pub pot_session: u32,
+}
+
+/// A series decided or drawn, and its pot settled
+#[contractevent(topics = ["series_ended", "v1"])]
+pub struct SeriesEnded {
+    pub series_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub outcome: Outcome,
+    pub winner: Option<Address>,
+    /// Games won by each player, in seat order
+    pub wins: Vec<u32>,
+    pub token: Option<Address>,
+    pub pot: i128,
+    pub fee: i128,
+}
+
+#[contractevent(topics = ["points_claimed", "v1"])]
+pub struct PointsClaimed {
+    pub player: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["game_registered", "v1"])]
+pub struct GameRegistered {
+    pub game_id: Address,
+    pub max_session_ledgers: u32,
+}
+
+/// New sessions stopped for one game, or for every game if `game_id` is `None`
+#[contractevent(topics = ["paused", "v1"])]
+pub struct Paused {
+    pub game_id: Option<Address>,
+}
+
+/// New sessions allowed again for one game, or hub-wide if `game_id` is `None`
+#[contractevent(topics = ["unpaused", "v1"])]
+pub struct Unpaused {
+    pub game_id: Option<Address>,
+}
+
+#[contractevent(topics = ["season_started", "v1"])]
+pub struct SeasonStarted {
+    pub season: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[contractevent(topics = ["season_closed", "v1"])]
+pub struct SeasonClosed {
+    pub season: u32,
+    pub players: u32,
+    pub rewards_paid: i128,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// A best-of-N series between two players. Its stakes are held by the pot
+/// session, whose status is also the series status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Series {
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    /// Session escrowing the series stakes, settled when the series ends
+    pub pot_session: u32,
+    pub best_of: u32,
+    /// Games won by each player, in seat order
+    pub wins: Vec<u32>,
+    /// Games decided or drawn so far (cancelled and expired games do not count)
+    pub games_played: u32,
+    /// Most recent game started in the series
+    pub last_session: Option<u32>,
+}
+
+/// Per-game settings held in the registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub max_session_ledgers: u32,
+    /// SEP-41 token used for stakes, or `None` for hub points
+    pub token: Option<Address>,
+    /// Receives the developer share of this game's fees
+    pub developer: Option<Address>,
+    /// New sessions are rejected while set
+    pub paused: bool,
+}
+
+/// How the stakes within one session must compare
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StakeMatching {
+    /// Any stakes within the limits
+    Any,
+    /// Every player stakes the same amount
+    Equal,
+    /// The largest stake may be at most this many basis points of the
+    /// smallest (20,000 allows up to double)
+    MaxRatioBps(u32),
+}
+
+/// Stake rules for a registered game's sessions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeLimits {
+    pub min_stake: i128,
+    /// `None` for no upper limit
+    pub max_stake: Option<i128>,
+    pub matching: StakeMatching,
+}
+
+/// Hub-wide fee settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Fee taken from each decided pot
+    pub fee_bps: u32,
+    /// Portion of the fee paid to the game's developer
+    pub developer_share_bps: u32,
+    pub treasury: Address,
+}
+
+/// Aggregated results for a player, either for one game or across all games.
+/// Only decided and drawn sessions are counted.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Consecutive wins up to the latest game
+    pub current_streak: u32,
+    pub best_streak: u32,
+    /// Points won minus points lost
+    pub net_points: i128,
+}
+
+/// Free points allowance for `claim_daily_points`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FaucetConfig {
+    /// Points credited per claim
+    pub allowance: i128,
+    /// Ledgers a player must wait between claims
+    pub cooldown_ledgers: u32,
+    /// Claims never raise a balance above this
+    pub balance_cap: i128,
+}
+
+/// Ranking used by `get_leaderboard`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeaderboardSort {
+    Wins,
+    NetPoints,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub stats: PlayerStats,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SeasonStatus {
+    Active,
+    /// Standings archived and rewards paid
+    Closed,
+}
+
+/// A leaderboard season. Results settled between `start_ledger` and
+/// `end_ledger` (inclusive) count toward it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub status: SeasonStatus,
+    /// Ranking used for the final standings
+    pub ranking: LeaderboardSort,
+    /// Token the reward pot is held in, or `None` for hub points
+    pub reward_token: Option<Address>,
+    pub reward_pot: i128,
+    /// Share of the pot for each final place (1st, 2nd, ...), in basis points
+    pub reward_split_bps: Vec<u32>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game(Address),
+    /// Stake rules for a game; unset allows any non-negative stakes
+    StakeLimits(Address),
+    Balance(Address),
+    Session(u32),
+    Rating(Address, Address),
+    Stats(Address, Address),
+    PlayerStats(Address),
+    /// Top players of a game by one ranking, best first
+    Leaderboard(Address, LeaderboardSort),
+    FeeConfig,
+    FaucetConfig,
+    /// Set while the whole hub is paused
+    Paused,
+    /// Ledger of a player's last faucet claim
+    LastClaim(Address),
+    NextSessionId,
+    Series(u32),
+    NextSeriesId,
+    /// Series a session belongs to, as its pot or as one of its games
+    SessionSeries(u32),
+    /// Fees owed to (recipient, token), with `None` for hub points
+    Fees(Address, Option<Address>),
+    /// Number of the latest season started (0 before the first)
+    CurrentSeason,
+    Season(u32),
+    /// Season rating for (season, game, player)
+    SeasonRating(u32, Address, Address),
+    /// Hub-wide stats for (season, player)
+    SeasonStats(u32, Address),
+    /// Number of players with a result in the season
+    SeasonPlayerCount(u32),
+    /// Top players of the season by its ranking, best first; final once the
+    /// season's end ledger has passed
+    SeasonStandings(u32),
+}
+
+// ============================================================================
+// Storage Schema
+// ============================================================================
+// Version 1: the first versioned layout
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 1;
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// Balances and session records hold value, so they live in persistent storage
+// and are bumped whenever they are touched.
+
+/// Extend TTL once it drops below ~1 day (17,280 ledgers at ~5 seconds per ledger)
+const TTL_THRESHOLD_LEDGERS: u32 = 17_280;
+
+/// Extend TTL to 30 days (518,400 ledgers)
+const TTL_EXTEND_LEDGERS: u32 = 518_400;
+
+/// Default maximum session duration for newly registered games (~1 day)
+const DEFAULT_MAX_SESSION_LEDGERS: u32 = 17_280;
+
+/// 100% in basis points
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Highest fee the admin can set (10%)
+const MAX_FEE_BPS: u32 = 1_000;
+
+/// Seats allowed in a session
+const MIN_PLAYERS: u32 = 2;
+const MAX_PLAYERS: u32 = 8;
+
+/// Longest series allowed (best of 9)
+const MAX_SERIES_GAMES: u32 = 9;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn read_game_config(env: &Env, game_id: &Address) -> Option<GameConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Game(game_id.clone()))
+}
+
+fn write_game_config(env: &Env, game_id: &Address, config: &GameConfig) {
+    let key = DataKey::Game(game_id.clone());
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_stake_limits(env: &Env, game_id: &Address) -> StakeLimits {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakeLimits(game_id.clone()))
+        .unwrap_or(StakeLimits {
+            min_stake: 0,
+            max_stake: None,
+            matching: StakeMatching::Any,
+        })
+}
+
+/// Check a session's stakes against its game's limits
+fn check_stakes(limits: &StakeLimits, stakes: &Vec<i128>) -> Result<(), Error> {
+    let mut smallest = i128::MAX;
+    let mut largest = 0;
+    for stake in stakes.iter() {
+        if stake < limits.min_stake {
+            return Err(Error::StakeTooLow);
         }
-        .publish(&env);
-        // bump instance ttl if required
-        env.storage().instance().extend_ttl(17_280, 518_400);
+        if limits.max_stake.is_some_and(|max| stake > max) {
+            return Err(Error::StakeTooHigh);
+        }
+        smallest = smallest.min(stake);
+        largest = largest.max(stake);
     }
 
-    /// End a game session and declare winner
-    ///
-    /// # Arguments
-    /// * `session_id` - The game session being ended
-    /// * `player1_won` - True if player1 won, false if player2 won
-    pub fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool,
-    ) {
-        // No auth required for mock
-        GameEnded {
+    let matched = match limits.matching {
+        StakeMatching::Any => true,
+        StakeMatching::Equal => smallest == largest,
+        StakeMatching::MaxRatioBps(ratio_bps) => {
+            let largest_bps = largest
+                .checked_mul(BPS_DENOMINATOR)
+                .ok_or(Error::Overflow)?;
+            let allowed_bps = smallest
+                .checked_mul(ratio_bps as i128)
+                .ok_or(Error::Overflow)?;
+            largest_bps <= allowed_bps
+        }
+    };
+    if !matched {
+        return Err(Error::StakesNotMatched);
+    }
+    Ok(())
+}
+
+fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+fn set_game_paused(
+    env: &Env,
+    caller: &Address,
+    game_id: Address,
+    paused: bool,
+) -> Result<(), Error> {
+    admin::require_role(env, Role::Pauser, caller);
+
+    let mut config = read_game_config(env, &game_id).ok_or(Error::GameNotRegistered)?;
+    config.paused = paused;
+    write_game_config(env, &game_id, &config);
+
+    let game_id = Some(game_id);
+    if paused {
+        Paused { game_id }.publish(env);
+    } else {
+        Unpaused { game_id }.publish(env);
+    }
+    Ok(())
+}
+
+fn write_session(env: &Env, session_id: u32, session: &Session) {
+    let key = DataKey::Session(session_id);
+    env.storage().persistent().set(&key, session);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_balance(env: &Env, player: &Address) -> i128 {
+    let key = DataKey::Balance(player.clone());
+    let balance = env.storage().persistent().get(&key).unwrap_or(0);
+    if balance != 0 {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+    }
+    balance
+}
+
+fn write_balance(env: &Env, player: &Address, balance: i128) {
+    let key = DataKey::Balance(player.clone());
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Debit a stake from a player's balance, failing if they cannot cover it
+fn lock_points(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, player);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    write_balance(env, player, balance - amount);
+    Ok(())
+}
+
+fn credit_points(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, player)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    write_balance(env, player, balance);
+    Ok(())
+}
+
+/// Take a stake into escrow: hub points are locked, tokens are transferred in
+fn escrow(env: &Env, token: &Option<Address>, player: &Address, amount: i128) -> Result<(), Error> {
+    match token {
+        Some(token) => {
+            if amount > 0 {
+                token::TokenClient::new(env, token).transfer(
+                    player,
+                    env.current_contract_address(),
+                    &amount,
+                );
+            }
+            Ok(())
+        }
+        None => lock_points(env, player, amount),
+    }
+}
+
+/// Pay out of escrow: hub points are credited, tokens are transferred out
+fn release(env: &Env, token: &Option<Address>, player: &Address, amount: i128) -> Result<(), Error> {
+    match token {
+        Some(token) => {
+            if amount > 0 {
+                token::TokenClient::new(env, token).transfer(
+                    &env.current_contract_address(),
+                    player,
+                    &amount,
+                );
+            }
+            Ok(())
+        }
+        None => credit_points(env, player, amount),
+    }
+}
+
+fn read_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&DataKey::FeeConfig)
+}
+
+fn read_fees(env: &Env, recipient: &Address, token: &Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Fees(recipient.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+fn accrue_fees(
+    env: &Env,
+    recipient: &Address,
+    token: &Option<Address>,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let owed = read_fees(env, recipient, token)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    let key = DataKey::Fees(recipient.clone(), token.clone());
+    env.storage().persistent().set(&key, &owed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+    Ok(())
+}
+
+/// Take the session's fee out of a decided pot and return the winner's payout
+fn collect_fee(env: &Env, session_id: u32, session: &Session, pot: i128) -> Result<i128, Error> {
+    let fee_config = match read_fee_config(env) {
+        Some(config) if session.fee_bps > 0 => config,
+        _ => return Ok(pot),
+    };
+    let fee = pot
+        .checked_mul(session.fee_bps as i128)
+        .ok_or(Error::Overflow)?
+        / BPS_DENOMINATOR;
+
+    // Games removed from the registry keep no developer; the treasury takes it all
+    let developer = read_game_config(env, &session.game_id).and_then(|config| config.developer);
+    let developer_amount = match developer {
+        Some(_) => fee * fee_config.developer_share_bps as i128 / BPS_DENOMINATOR,
+        None => 0,
+    };
+    let treasury_amount = fee - developer_amount;
+
+    accrue_fees(env, &fee_config.treasury, &session.token, treasury_amount)?;
+    if let Some(developer) = &developer {
+        accrue_fees(env, developer, &session.token, developer_amount)?;
+    }
+
+    if fee > 0 {
+        FeeCollected {
             session_id,
-            player1_won,
+            game_id: session.game_id.clone(),
+            token: session.token.clone(),
+            treasury: fee_config.treasury,
+            treasury_amount,
+            developer,
+            developer_amount,
         }
-        .publish(&env);
+        .publish(env);
     }
+    Ok(pot - fee)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    #[test]
-    fn test_start_and_end_game() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
-        let client = MockGameHubClient::new(&env, &contract_id);
-        let game_id = Address::generate(&env);
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
-        client.end_game(&1, &true);
+fn read_rating(env: &Env, key: &DataKey) -> u32 {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or(elo::INITIAL_RATING)
+}
+
+fn write_rating(env: &Env, key: &DataKey, rating: u32) {
+    env.storage().persistent().set(key, &rating);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Update the session's ratings, and its season ratings if a season is running
+fn update_ratings(env: &Env, session: &Session, shares: &Vec<u32>, season: Option<u32>) {
+    let game_id = &session.game_id;
+    rate_players(env, &session.players, shares, |player| {
+        DataKey::Rating(game_id.clone(), player.clone())
+    });
+    if let Some(season) = season {
+        rate_players(env, &session.players, shares, |player| {
+            DataKey::SeasonRating(season, game_id.clone(), player.clone())
+        });
+    }
+}
+
+/// Update the rating stored under `key(player)` for every player. Each pair of
+/// players is rated as one game (higher share wins), averaged over the opponents.
+fn rate_players(
+    env: &Env,
+    players: &Vec<Address>,
+    shares: &Vec<u32>,
+    key: impl Fn(&Address) -> DataKey,
+) {
+    let n = players.len();
+    let mut ratings = [0u32; MAX_PLAYERS as usize];
+    let mut deltas = [0i64; MAX_PLAYERS as usize];
+    for i in 0..n {
+        ratings[i as usize] = read_rating(env, &key(&players.get_unchecked(i)));
+    }
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (share_i, share_j) = (shares.get_unchecked(i), shares.get_unchecked(j));
+            let score_i = if share_i > share_j {
+                elo::SCORE_WIN
+            } else if share_i == share_j {
+                elo::SCORE_DRAW
+            } else {
+                elo::SCORE_LOSS
+            };
+            let delta = elo::delta(ratings[i as usize], ratings[j as usize], score_i);
+            deltas[i as usize] += delta;
+            deltas[j as usize] -= delta;
+        }
+    }
+    for i in 0..n {
+        let rating = elo::apply(ratings[i as usize], deltas[i as usize] / (n as i64 - 1));
+        write_rating(env, &key(&players.get_unchecked(i)), rating);
+    }
+}
+
+/// Largest page `get_leaderboard` will return
+const MAX_LEADERBOARD_PAGE: u32 = 50;
+
+/// Places a season reward split can pay
+const MAX_REWARD_PLACES: u32 = 10;
+
+/// Players kept on each game leaderboard
+const MAX_LEADERBOARD_SIZE: u32 = 100;
+
+/// Players kept in a season's standings
+const MAX_SEASON_STANDINGS: u32 = 100;
+
+fn read_stats(env: &Env, key: &DataKey) -> PlayerStats {
+    env.storage().persistent().get(key).unwrap_or_default()
+}
+
+fn write_stats(env: &Env, key: &DataKey, stats: &PlayerStats) {
+    env.storage().persistent().set(key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_ranked(env: &Env, key: &DataKey) -> Vec<LeaderboardEntry> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+fn write_ranked(env: &Env, key: &DataKey, ranked: &Vec<LeaderboardEntry>) {
+    env.storage().persistent().set(key, ranked);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn count_season_player(env: &Env, season: u32) {
+    let key = DataKey::SeasonPlayerCount(season);
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Apply one result to a stats record. `score` is 1 for a win, 0 for a draw
+/// and -1 for a loss; `net` is the points won (or lost, if negative).
+fn apply_result(stats: &mut PlayerStats, score: i32, net: i128) -> Result<(), Error> {
+    stats.games_played += 1;
+    match score {
+        1 => {
+            stats.wins += 1;
+            stats.current_streak += 1;
+            stats.best_streak = stats.best_streak.max(stats.current_streak);
+        }
+        0 => {
+            stats.draws += 1;
+            stats.current_streak = 0;
+        }
+        _ => {
+            stats.losses += 1;
+            stats.current_streak = 0;
+        }
+    }
+    stats.net_points = stats.net_points.checked_add(net).ok_or(Error::Overflow)?;
+    Ok(())
+}
+
+/// Record one player's result in their game, total and season stats.
+/// Returns the updated game stats and season stats.
+fn record_result(
+    env: &Env,
+    game_id: &Address,
+    player: &Address,
+    score: i32,
+    net: i128,
+    season: Option<u32>,
+) -> Result<(PlayerStats, Option<PlayerStats>), Error> {
+    let game_key = DataKey::Stats(game_id.clone(), player.clone());
+    let mut game_stats = read_stats(env, &game_key);
+    apply_result(&mut game_stats, score, net)?;
+    write_stats(env, &game_key, &game_stats);
+
+    let total_key = DataKey::PlayerStats(player.clone());
+    let mut total_stats = read_stats(env, &total_key);
+    apply_result(&mut total_stats, score, net)?;
+    write_stats(env, &total_key, &total_stats);
+
+    let Some(season) = season else {
+        return Ok((game_stats, None));
+    };
+    let season_key = DataKey::SeasonStats(season, player.clone());
+    let mut season_stats = read_stats(env, &season_key);
+    if season_stats.games_played == 0 {
+        count_season_player(env, season);
+    }
+    apply_result(&mut season_stats, score, net)?;
+    write_stats(env, &season_key, &season_stats);
+    Ok((game_stats, Some(season_stats)))
+}
+
+/// Update every player's stats for a decided or drawn session, and their
+/// places on the game leaderboards and season standings. Players with the
+/// largest share win and the rest lose, unless all shares are equal (a draw).
+fn update_stats(
+    env: &Env,
+    session: &Session,
+    shares: &Vec<u32>,
+    payouts: &Vec<i128>,
+    season: Option<u32>,
+) -> Result<(), Error> {
+    let wins_key = DataKey::Leaderboard(session.game_id.clone(), LeaderboardSort::Wins);
+    let points_key = DataKey::Leaderboard(session.game_id.clone(), LeaderboardSort::NetPoints);
+    let mut by_wins = read_ranked(env, &wins_key);
+    let mut by_points = read_ranked(env, &points_key);
+    let mut standings = match season {
+        Some(number) => Some((
+            read_season(env, number)?.ranking,
+            read_ranked(env, &DataKey::SeasonStandings(number)),
+        )),
+        None => None,
+    };
+
+    let top_share = shares.iter().max().unwrap_or(0);
+    let is_draw = shares.iter().all(|share| share == top_share);
+    for i in 0..session.players.len() {
+        let player = session.players.get_unchecked(i);
+        let score = if is_draw {
+            0
+        } else if shares.get_unchecked(i) == top_share {
+            1
+        } else {
+            -1
+        };
+        let net = payouts.get_unchecked(i) - session.stakes.get_unchecked(i);
+        let (game_stats, season_stats) =
+            record_result(env, &session.game_id, &player, score, net, season)?;
+
+        rank_player(
+            &mut by_wins,
+            &player,
+            &game_stats,
+            LeaderboardSort::Wins,
+            MAX_LEADERBOARD_SIZE,
+        );
+        rank_player(
+            &mut by_points,
+            &player,
+            &game_stats,
+            LeaderboardSort::NetPoints,
+            MAX_LEADERBOARD_SIZE,
+        );
+        if let (Some((ranking, standings)), Some(stats)) = (standings.as_mut(), season_stats) {
+            rank_player(standings, &player, &stats, *ranking, MAX_SEASON_STANDINGS);
+        }
+    }
+
+    write_ranked(env, &wins_key, &by_wins);
+    write_ranked(env, &points_key, &by_points);
+    if let (Some(number), Some((_, standings))) = (season, standings) {
+        write_ranked(env, &DataKey::SeasonStandings(number), &standings);
+    }
+    Ok(())
+}
+
+/// Check that a registered, unpaused game is opening a session for itself
+fn check_game_open(env: &Env, game_id: &Address) -> Result<GameConfig, Error> {
+    // Only registered game contracts can open sessions, and only for themselves
+    game_id.require_auth();
+    let config = read_game_config(env, game_id).ok_or(Error::GameNotRegistered)?;
+    if is_paused(env) {
+        return Err(Error::HubPaused);
+    }
+    if config.paused {
+        return Err(Error::GamePaused);
+    }
+    Ok(config)
+}
+
+/// Check the seats and stakes of a new session against the game's stake limits
+fn check_seats(
+    env: &Env,
+    game_id: &Address,
+    players: &Vec<Address>,
+    stakes: &Vec<i128>,
+) -> Result<(), Error> {
+    if players.len() < MIN_PLAYERS || players.len() > MAX_PLAYERS {
+        return Err(Error::InvalidPlayerCount);
+    }
+    if stakes.len() != players.len() {
+        return Err(Error::StakeCountMismatch);
+    }
+    if stakes.iter().any(|stake| stake < 0) {
+        return Err(Error::InvalidAmount);
+    }
+    check_stakes(&read_stake_limits(env, game_id), stakes)?;
+    for i in 1..players.len() {
+        if players.slice(..i).contains(players.get_unchecked(i)) {
+            return Err(Error::DuplicatePlayer);
+        }
+    }
+    Ok(())
+}
+
+/// Validate a new session, then open it
+fn open_session(
+    env: &Env,
+    game_id: &Address,
+    players: &Vec<Address>,
+    stakes: &Vec<i128>,
+) -> Result<u32, Error> {
+    let config = check_game_open(env, game_id)?;
+    check_seats(env, game_id, players, stakes)?;
+    create_session(env, game_id, config, players, stakes)
+}
+
+/// Allocate a session ID, escrow every stake and record the session
+fn create_session(
+    env: &Env,
+    game_id: &Address,
+    config: GameConfig,
+    players: &Vec<Address>,
+    stakes: &Vec<i128>,
+) -> Result<u32, Error> {
+    // Allocate the next session ID; IDs start at 1 and are never reused
+    let session_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSessionId)
+        .unwrap_or(1);
+    let next_session_id = session_id.checked_add(1).ok_or(Error::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSessionId, &next_session_id);
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Session(session_id))
+    {
+        return Err(Error::SessionAlreadyExists);
+    }
+
+    // Escrow stakes (fails the whole call if any player is short)
+    for (player, stake) in players.iter().zip(stakes.iter()) {
+        escrow(env, &config.token, &player, stake)?;
+    }
+
+    let session = Session {
+        game_id: game_id.clone(),
+        players: players.clone(),
+        stakes: stakes.clone(),
+        status: SessionStatus::Active,
+        start_ledger: env.ledger().sequence(),
+        expires_ledger: env
+            .ledger()
+            .sequence()
+            .saturating_add(config.max_session_ledgers),
+        token: config.token,
+        fee_bps: read_fee_config(env).map_or(0, |fees| fees.fee_bps),
+    };
+    write_session(env, session_id, &session);
+
+    // bump instance ttl if required
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+
+    Ok(session_id)
+}
+
+/// Load an active session for settlement by the game that opened it
+fn read_active_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+    let session: Session = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+
+    // Only the game that opened the session can settle it
+    session.game_id.require_auth();
+
+    if session.status != SessionStatus::Active {
+        return Err(Error::SessionNotActive);
+    }
+
+    // A series pot is settled by its series, never directly
+    if let Some(series_id) = read_session_series(env, session_id) {
+        if read_series(env, series_id)?.pot_session == session_id {
+            return Err(Error::SeriesPotLocked);
+        }
+    }
+    Ok(session)
+}
+
+/// Checked sum of stakes or payouts
+fn total(amounts: &Vec<i128>) -> Result<i128, Error> {
+    let mut sum: i128 = 0;
+    for amount in amounts.iter() {
+        sum = sum.checked_add(amount).ok_or(Error::Overflow)?;
+    }
+    Ok(sum)
+}
+
+/// Return every stake to its player
+fn refund_stakes(env: &Env, session: &Session) -> Result<(), Error> {
+    for (player, stake) in session.players.iter().zip(session.stakes.iter()) {
+        release(env, &session.token, &player, stake)?;
+    }
+    Ok(())
+}
+
+/// Check there is one share per seat and not all are zero
+///
+/// # Returns
+/// * `i128` - The sum of the shares
+fn check_shares(session: &Session, shares: &Vec<u32>) -> Result<i128, Error> {
+    if shares.len() != session.players.len() {
+        return Err(Error::InvalidShares);
+    }
+    let total_shares: i128 = shares.iter().map(|share| share as i128).sum();
+    if total_shares == 0 {
+        return Err(Error::InvalidShares);
+    }
+    Ok(total_shares)
+}
+
+/// The seat holding the single largest share, or `None` if the top is shared
+fn winning_seat(shares: &Vec<u32>) -> Option<u32> {
+    let top_share = shares.iter().max()?;
+    if shares.iter().filter(|share| *share == top_share).count() > 1 {
+        return None;
     }
+    shares.first_index_of(top_share)
 }
+
+/// Pay out a session by share and update ratings and stats.
+/// Equal shares are a draw and refund each stake; otherwise the fee is taken
+/// from the pot and the rest split in proportion to `shares`, with any
+/// rounding remainder going to the first player with the largest share.
+///
+/// # Returns
+/// * `Vec<i128>` - What each player received
+fn settle_by_shares(
+    env: &Env,
+    session_id: u32,
+    session: &Session,
+    shares: &Vec<u32>,
+) -> Result<Vec<i128>, Error> {
+    let total_shares = check_shares(session, shares)?;
+
+    let top_share = shares.iter().max().unwrap_or(0);
+    let payouts = if shares.iter().all(|share| share == top_share) {
+        refund_stakes(env, session)?;
+        session.stakes.clone()
+    } else {
+        let pot = total(&session.stakes)?;
+        let distributable = collect_fee(env, session_id, session, pot)?;
+
+        let mut payouts = Vec::new(env);
+        let mut paid: i128 = 0;
+        for share in shares.iter() {
+            let payout = distributable
+                .checked_mul(share as i128)
+                .ok_or(Error::Overflow)?
+                / total_shares;
+            paid += payout;
+            payouts.push_back(payout);
+        }
+        let top = shares.first_index_of(top_share).unwrap_or(0);
+        payouts.set(top, payouts.get_unchecked(top) + distributable - paid);
+
+        for (player, payout) in session.players.iter().zip(payouts.iter()) {
+            release(env, &session.token, &player, payout)?;
+        }
+        payouts
+    };
+
+    let season = running_season(env);
+    update_ratings(env, session, shares, season);
+    update_stats(env, session, shares, &payouts, season)?;
+    Ok(payouts)
+}
+
+/// Settle a decided or drawn session. Games in a series stake nothing and are
+/// not rated on their own; the series pot is settled when the series ends.
+fn settle_game(
+    env: &Env,
+    session_id: u32,
+    session: &Session,
+    shares: &Vec<u32>,
+    series_id: Option<u32>,
+) -> Result<Vec<i128>, Error> {
+    match series_id {
+        Some(_) => {
+            check_shares(session, shares)?;
+            Ok(session.stakes.clone())
+        }
+        None => settle_by_shares(env, session_id, session, shares),
+    }
+}
+
+fn read_series(env: &Env, series_id: u32) -> Result<Series, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Series(series_id))
+        .ok_or(Error::SeriesNotFound)
+}
+
+fn write_series(env: &Env, series_id: u32, series: &Series) {
+    let key = DataKey::Series(series_id);
+    env.storage().persistent().set(&key, series);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_session_series(env: &Env, session_id: u32) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionSeries(session_id))
+}
+
+fn write_session_series(env: &Env, session_id: u32, series_id: u32) {
+    let key = DataKey::SessionSeries(session_id);
+    env.storage().persistent().set(&key, &series_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Count a finished game towards its series, and settle the pot once a player
+/// has won a majority of the games or every game has been played
+fn record_series_game(env: &Env, series_id: u32, winner_seat: Option<u32>) -> Result<(), Error> {
+    let mut series = read_series(env, series_id)?;
+    let mut pot: Session = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(series.pot_session))
+        .ok_or(Error::SessionNotFound)?;
+
+    // A pot already reclaimed after its deadline no longer counts games
+    if pot.status != SessionStatus::Active {
+        return Ok(());
+    }
+
+    series.games_played += 1;
+    if let Some(seat) = winner_seat {
+        series.wins.set(seat, series.wins.get_unchecked(seat) + 1);
+    }
+    write_series(env, series_id, &series);
+
+    let player1_wins = series.wins.get_unchecked(0);
+    let player2_wins = series.wins.get_unchecked(1);
+    let needed = series.best_of / 2 + 1;
+    if player1_wins < needed && player2_wins < needed && series.games_played < series.best_of {
+        return Ok(());
+    }
+
+    // The player with more wins takes the pot; a level series is refunded
+    let (outcome, shares) = if player1_wins > player2_wins {
+        (Outcome::Player1, vec![env, 1u32, 0])
+    } else if player2_wins > player1_wins {
+        (Outcome::Player2, vec![env, 0u32, 1])
+    } else {
+        (Outcome::Draw, vec![env, 1u32, 1])
+    };
+    let payouts = settle_by_shares(env, series.pot_session, &pot, &shares)?;
+
+    pot.status = SessionStatus::Ended(outcome);
+    write_session(env, series.pot_session, &pot);
+
+    let winner = winning_seat(&shares).map(|seat| series.players.get_unchecked(seat));
+    let pot_total = total(&pot.stakes)?;
+    SeriesEnded {
+        series_id,
+        game_id: series.game_id,
+        players: series.players,
+        outcome,
+        winner,
+        wins: series.wins,
+        token: pot.token,
+        pot: pot_total,
+        fee: pot_total - total(&payouts)?,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Whether `a` ranks above `b`; ties fall back to the other metric
+fn ranks_above(a: &PlayerStats, b: &PlayerStats, sort: LeaderboardSort) -> bool {
+    match sort {
+        LeaderboardSort::Wins => (a.wins, a.net_points) > (b.wins, b.net_points),
+        LeaderboardSort::NetPoints => (a.net_points, a.wins) > (b.net_points, b.wins),
+    }
+}
+
+/// Move a player to their place in a ranked list after a result, keeping at
+/// most `max_len` entries. Ties go below the players who reached that record
+/// first. Only players with a new result move, so a listed player whose record
+/// falls can stay ahead of an unlisted one until that player's next result.
+fn rank_player(
+    ranked: &mut Vec<LeaderboardEntry>,
+    player: &Address,
+    stats: &PlayerStats,
+    sort: LeaderboardSort,
+    max_len: u32,
+) {
+    if let Some(index) = ranked.iter().position(|entry| entry.player == *player) {
+        ranked.remove(index as u32);
+    }
+    let mut pos = ranked.len();
+    while pos > 0 && ranks_above(stats, &ranked.get_unchecked(pos - 1).stats, sort) {
+        pos -= 1;
+    }
+    if pos >= max_len {
+        return;
+    }
+    ranked.insert(
+        pos,
+        LeaderboardEntry {
+            player: player.clone(),
+            stats: stats.clone(),
+        },
+    );
+    if ranked.len() > max_len {
+        ranked.pop_back();
+    }
+}
+
+/// Entries from `offset` onward, at most `limit` (capped at the page size)
+fn page(
+    env: &Env,
+    ranked: &Vec<LeaderboardEntry>,
+    offset: u32,
+    limit: u32,
+) -> Vec<LeaderboardEntry> {
+    let end = offset
+        .saturating_add(limit.min(MAX_LEADERBOARD_PAGE))
+        .min(ranked.len());
+    if offset >= end {
+        return Vec::new(env);
+    }
+    ranked.slice(offset..end)
+}
+
+fn read_season(env: &Env, season: u32) -> Result<Season, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Season(season))
+        .ok_or(Error::SeasonNotFound)
+}
+
+fn write_season(env: &Env, season: u32, record: &Season) {
+    let key = DataKey::Season(season);
+    env.storage().persistent().set(&key, record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn current_season(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CurrentSeason)
+        .unwrap_or(0)
+}
+
+/// The season that results settled now count toward, if any
+fn running_season(env: &Env) -> Option<u32> {
+    let number = current_season(env);
+    let season = read_season(env, number).ok()?;
+    let ledger = env.ledger().sequence();
+    (season.status == SeasonStatus::Active && ledger <= season.end_ledger).then_some(number)
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    /// Initialize the hub with an admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (manages the game registry and can credit points)
+    pub fn __constructor(env: Env, admin: Address) {
+        admin::write_admin(&env, &admin);
+        schema::init(&env, SCHEMA_VERSION);
+    }
+
+    /// Start a game session and lock both players' stakes
+    ///
+    /// **Auth:** Requires authorization from `game_id`, which must be a registered game.
+    /// For games staking a token, each player must also authorize the transfer of
+    /// their stake into the hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points locked from player 1
+    /// * `player2_points` - Points locked from player 2
+    ///
+    /// # Returns
+    /// * `u32` - The newly allocated session ID
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error> {
+        let session_id = open_session(
+            &env,
+            &game_id,
+            &vec![&env, player1.clone(), player2.clone()],
+            &vec![&env, player1_points, player2_points],
+        )?;
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Start a session with 2-8 players and lock each player's stake
+    ///
+    /// **Auth:** Requires authorization from `game_id`, which must be a registered game.
+    /// For games staking a token, each player must also authorize the transfer of
+    /// their stake into the hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `players` - Seated players, in seat order
+    /// * `stakes` - Points locked from each player, in the same order
+    ///
+    /// # Returns
+    /// * `u32` - The newly allocated session ID
+    pub fn start_game_multi(
+        env: Env,
+        game_id: Address,
+        players: Vec<Address>,
+        stakes: Vec<i128>,
+    ) -> Result<u32, Error> {
+        let session_id = open_session(&env, &game_id, &players, &stakes)?;
+
+        MultiGameStarted {
+            session_id,
+            game_id,
+            players,
+            stakes,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// End a game session and settle the locked stakes
+    ///
+    /// - `Player1` / `Player2`: the winner receives the whole pot
+    /// - `Draw`: each player gets their own stake back
+    /// - `Cancelled`: stakes are refunded (published as `SessionCancelled` rather than `GameEnded`)
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `outcome` - Result of the session
+    pub fn end_game(env: Env, session_id: u32, outcome: Outcome) -> Result<(), Error> {
+        let mut session = read_active_session(&env, session_id)?;
+        let series_id = read_session_series(&env, session_id);
+
+        let winner_seat = match outcome {
+            Outcome::Player1 => Some(0),
+            Outcome::Player2 => Some(1),
+            Outcome::Draw | Outcome::Cancelled => None,
+        };
+        let payouts = match outcome {
+            Outcome::Cancelled => {
+                refund_stakes(&env, &session)?;
+                session.stakes.clone()
+            }
+            _ => {
+                // A win is the whole pot to one seat; a draw is equal shares
+                let mut shares = Vec::new(&env);
+                for seat in 0..session.players.len() {
+                    let share = match winner_seat {
+                        Some(winner) if winner != seat => 0,
+                        _ => 1,
+                    };
+                    shares.push_back(share);
+                }
+                settle_game(&env, session_id, &session, &shares, series_id)?
+            }
+        };
+
+        // Mark the session settled so it cannot be paid out twice
+        session.status = SessionStatus::Ended(outcome);
+        write_session(&env, session_id, &session);
+
+        if outcome == Outcome::Cancelled {
+            SessionCancelled {
+                session_id,
+                game_id: session.game_id,
+                players: session.players,
+                stakes: session.stakes,
+                token: session.token,
+            }
+            .publish(&env);
+        } else {
+            let pot = total(&session.stakes)?;
+            GameEnded {
+                session_id,
+                game_id: session.game_id.clone(),
+                players: session.players.clone(),
+                outcome,
+                winner: winner_seat.map(|seat| session.players.get_unchecked(seat)),
+                token: session.token,
+                pot,
+                fee: pot - total(&payouts)?,
+            }
+            .publish(&env);
+
+            if let Some(series_id) = series_id {
+                record_series_game(&env, series_id, winner_seat)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// End a session by splitting the pot between its players.
+    ///
+    /// `shares` gives each player's relative share of the pot (after fees), in
+    /// seat order: `[1, 0, 0]` is winner-takes-all, `[2, 1, 0]` splits the pot
+    /// two-thirds / one-third. Equal shares are a draw and refund every stake.
+    /// To cancel a session, use `end_game` with `Outcome::Cancelled`.
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `shares` - Relative payout share per player (one per seat, not all zero)
+    ///
+    /// # Returns
+    /// * `Vec<i128>` - What each player received
+    pub fn end_game_multi(
+        env: Env,
+        session_id: u32,
+        shares: Vec<u32>,
+    ) -> Result<Vec<i128>, Error> {
+        let mut session = read_active_session(&env, session_id)?;
+        let series_id = read_session_series(&env, session_id);
+
+        let payouts = settle_game(&env, session_id, &session, &shares, series_id)?;
+
+        session.status = SessionStatus::Settled;
+        write_session(&env, session_id, &session);
+
+        let pot = total(&session.stakes)?;
+        MultiGameEnded {
+            session_id,
+            game_id: session.game_id,
+            players: session.players,
+            payouts: payouts.clone(),
+            token: session.token,
+            pot,
+            fee: pot - total(&payouts)?,
+        }
+        .publish(&env);
+
+        if let Some(series_id) = series_id {
+            record_series_game(&env, series_id, winning_seat(&shares))?;
+        }
+
+        Ok(payouts)
+    }
+
+    /// Refund a session that its game never ended before the deadline.
+    /// Any player in the session can claim once the current ledger is past `expires_ledger`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The expired session
+    /// * `player` - Address of the claiming player (must be in the session)
+    pub fn claim_expired_session(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut session = Self::get_session(env.clone(), session_id)?;
+        if !session.players.contains(&player) {
+            return Err(Error::NotPlayer);
+        }
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionNotActive);
+        }
+        if env.ledger().sequence() <= session.expires_ledger {
+            return Err(Error::SessionNotExpired);
+        }
+
+        refund_stakes(&env, &session)?;
+
+        session.status = SessionStatus::Expired;
+        write_session(&env, session_id, &session);
+
+        SessionExpired {
+            session_id,
+            game_id: session.game_id,
+            players: session.players,
+            stakes: session.stakes,
+            token: session.token,
+            claimed_by: player,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a session record.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID
+    ///
+    /// # Returns
+    /// * `Session` - The session, including its game, players, stakes and status
+    pub fn get_session(env: Env, session_id: u32) -> Result<Session, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    /// Get just the status of a session, for contracts that track results
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID
+    ///
+    /// # Returns
+    /// * `SessionStatus` - Whether the session is active, ended (and how) or expired
+    pub fn get_session_status(env: Env, session_id: u32) -> Result<SessionStatus, Error> {
+        Ok(Self::get_session(env, session_id)?.status)
+    }
+
+    /// Get a player's Elo rating for a game.
+    /// Players who have not finished a rated session have the initial rating (1200).
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `u32` - The player's rating
+    pub fn get_rating(env: Env, game_id: Address, player: Address) -> u32 {
+        read_rating(&env, &DataKey::Rating(game_id, player))
+    }
+
+    /// Get a player's stats for one game
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `PlayerStats` - The player's results in that game (all zero if none)
+    pub fn get_stats(env: Env, game_id: Address, player: Address) -> PlayerStats {
+        read_stats(&env, &DataKey::Stats(game_id, player))
+    }
+
+    /// Get a player's stats across all games
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `PlayerStats` - The player's combined results (all zero if none)
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        read_stats(&env, &DataKey::PlayerStats(player))
+    }
+
+    /// Get a page of a game's leaderboard. Only the top 100 players are kept;
+    /// players who tie on both metrics are listed in the order they reached
+    /// that record.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `sort` - Rank by wins or by net points
+    /// * `offset` - Number of ranked entries to skip
+    /// * `limit` - Maximum entries to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `Vec<LeaderboardEntry>` - Entries from rank `offset + 1` onward
+    pub fn get_leaderboard(
+        env: Env,
+        game_id: Address,
+        sort: LeaderboardSort,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        let ranked = read_ranked(&env, &DataKey::Leaderboard(game_id, sort));
+        page(&env, &ranked, offset, limit)
+    }
+
+    /// Get a player's available (unlocked) points balance
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `i128` - The player's balance
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        read_balance(&env, &player)
+    }
+
+    /// Credit points to a player (admin only)
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player to credit
+    /// * `amount` - Points to add to the player's balance
+    pub fn add_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        credit_points(&env, &player, amount)
+    }
+
+    /// Move the session ID counter past IDs already in use (admin only).
+    /// Games that chose their own session IDs before the hub allocated them
+    /// still hold games under those IDs and reject them when reallocated, so
+    /// after such an upgrade the admin reserves every ID up to the highest
+    /// legacy one. The counter never moves backwards.
+    ///
+    /// # Arguments
+    /// * `last_id` - Highest session ID already in use
+    pub fn reserve_session_ids(env: Env, last_id: u32) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        let next_session_id = last_id.checked_add(1).ok_or(Error::Overflow)?;
+        let current: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(1);
+        if next_session_id > current {
+            env.storage()
+                .instance()
+                .set(&DataKey::NextSessionId, &next_session_id);
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Series and Rematches
+    // ========================================================================
+
+    /// Start a best-of-N series between two players and lock the series stakes.
+    /// The stakes are checked against the game's stake limits and held in a pot
+    /// session that stays open for `best_of` times the game's maximum session
+    /// duration; if the series is not decided by then, either player can
+    /// reclaim their stake with `claim_expired_session` on the pot session.
+    ///
+    /// **Auth:** Requires authorization from `game_id`, which must be a registered game.
+    /// For games staking a token, each player must also authorize the transfer of
+    /// their stake into the hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points locked from player 1 for the whole series
+    /// * `player2_points` - Points locked from player 2 for the whole series
+    /// * `best_of` - Number of games in the series (odd, at most 9)
+    ///
+    /// # Returns
+    /// * `u32` - The newly allocated series ID
+    pub fn start_series(
+        env: Env,
+        game_id: Address,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        best_of: u32,
+    ) -> Result<u32, Error> {
+        if best_of == 0 || best_of.is_multiple_of(2) || best_of > MAX_SERIES_GAMES {
+            return Err(Error::InvalidSeriesLength);
+        }
+
+        let mut config = check_game_open(&env, &game_id)?;
+        let players = vec![&env, player1, player2];
+        let stakes = vec![&env, player1_points, player2_points];
+        check_seats(&env, &game_id, &players, &stakes)?;
+
+        // Leave time for every game in the series to run to its own deadline
+        config.max_session_ledgers = config.max_session_ledgers.saturating_mul(best_of);
+        let pot_session = create_session(&env, &game_id, config, &players, &stakes)?;
+
+        // Series IDs are allocated from their own counter, starting at 1
+        let series_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSeriesId)
+            .unwrap_or(1);
+        let next_series_id = series_id.checked_add(1).ok_or(Error::Overflow)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSeriesId, &next_series_id);
+
+        let series = Series {
+            game_id: game_id.clone(),
+            players: players.clone(),
+            pot_session,
+            best_of,
+            wins: vec![&env, 0u32, 0],
+            games_played: 0,
+            last_session: None,
+        };
+        write_series(&env, series_id, &series);
+        write_session_series(&env, pot_session, series_id);
+
+        SeriesStarted {
+            series_id,
+            game_id,
+            players,
+            stakes,
+            best_of,
+            pot_session,
+        }
+        .publish(&env);
+
+        Ok(series_id)
+    }
+
+    /// Start the next game of a series as an unstaked session between the
+    /// series players. The game is ended with `end_game` as usual, and the
+    /// hub settles the series pot once the series is decided.
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the series.
+    ///
+    /// # Arguments
+    /// * `series_id` - The series to continue
+    ///
+    /// # Returns
+    /// * `u32` - The newly allocated session ID
+    pub fn start_series_game(env: Env, series_id: u32) -> Result<u32, Error> {
+        let mut series = read_series(&env, series_id)?;
+        let config = check_game_open(&env, &series.game_id)?;
+
+        let pot = Self::get_session(env.clone(), series.pot_session)?;
+        if pot.status != SessionStatus::Active || env.ledger().sequence() > pot.expires_ledger {
+            return Err(Error::SeriesNotActive);
+        }
+        // One game at a time
+        if let Some(last_session) = series.last_session {
+            if Self::get_session(env.clone(), last_session)?.status == SessionStatus::Active {
+                return Err(Error::SessionStillActive);
+            }
+        }
+
+        let session_id = create_session(
+            &env,
+            &series.game_id,
+            config,
+            &series.players,
+            &vec![&env, 0i128, 0],
+        )?;
+        series.last_session = Some(session_id);
+        write_series(&env, series_id, &series);
+        write_session_series(&env, session_id, series_id);
+
+        GameStarted {
+            session_id,
+            game_id: series.game_id,
+            player1: series.players.get_unchecked(0),
+            player2: series.players.get_unchecked(1),
+            player1_points: 0,
+            player2_points: 0,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Start a new session with the same players and stakes as a finished one.
+    /// For a game in a series this starts the next game of the series instead.
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session,
+    /// and from each player for `(session_id, stake)` outside a series, since the
+    /// rematch locks their stake again. For games staking a token, each player must
+    /// also authorize the transfer of their stake into the hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The finished session to replay
+    ///
+    /// # Returns
+    /// * `u32` - The newly allocated session ID
+    pub fn rematch(env: Env, session_id: u32) -> Result<u32, Error> {
+        let session = Self::get_session(env.clone(), session_id)?;
+        if session.status == SessionStatus::Active {
+            return Err(Error::SessionStillActive);
+        }
+        if let Some(series_id) = read_session_series(&env, session_id) {
+            return Self::start_series_game(env, series_id);
+        }
+
+        // Each player consents to staking again, as they did for the first session
+        for (player, stake) in session.players.iter().zip(session.stakes.iter()) {
+            player.require_auth_for_args(vec![
+                &env,
+                session_id.into_val(&env),
+                stake.into_val(&env),
+            ]);
+        }
+
+        let new_session_id =
+            open_session(&env, &session.game_id, &session.players, &session.stakes)?;
+
+        if session.players.len() == 2 {
+            GameStarted {
+                session_id: new_session_id,
+                game_id: session.game_id,
+                player1: session.players.get_unchecked(0),
+                player2: session.players.get_unchecked(1),
+                player1_points: session.stakes.get_unchecked(0),
+                player2_points: session.stakes.get_unchecked(1),
+            }
+            .publish(&env);
+        } else {
+            MultiGameStarted {
+                session_id: new_session_id,
+                game_id: session.game_id,
+                players: session.players,
+                stakes: session.stakes,
+            }
+            .publish(&env);
+        }
+
+        Ok(new_session_id)
+    }
+
+    /// Get a series record. The series status is the status of its pot session.
+    ///
+    /// # Arguments
+    /// * `series_id` - The series ID
+    ///
+    /// # Returns
+    /// * `Series` - The series, including its players, pot session and wins so far
+    pub fn get_series(env: Env, series_id: u32) -> Result<Series, Error> {
+        read_series(&env, series_id)
+    }
+
+    /// Get the series a session belongs to, as its pot or as one of its games
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The series ID, or `None` for a standalone session
+    pub fn get_session_series(env: Env, session_id: u32) -> Option<u32> {
+        read_session_series(&env, session_id)
+    }
+
+    // ========================================================================
+    // Faucet
+    // ========================================================================
+
+    /// Configure the points faucet (admin only)
+    ///
+    /// # Arguments
+    /// * `allowance` - Points credited per claim
+    /// * `cooldown_ledgers` - Ledgers between claims by the same player (17,280 is ~1 day)
+    /// * `balance_cap` - Balance a claim may top a player up to (at least `allowance`)
+    pub fn set_faucet_config(
+        env: Env,
+        allowance: i128,
+        cooldown_ledgers: u32,
+        balance_cap: i128,
+    ) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if allowance <= 0 || balance_cap < allowance {
+            return Err(Error::InvalidAmount);
+        }
+        if cooldown_ledgers == 0 {
+            return Err(Error::InvalidDuration);
+        }
+        let config = FaucetConfig {
+            allowance,
+            cooldown_ledgers,
+            balance_cap,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::FaucetConfig, &config);
+        Ok(())
+    }
+
+    /// Turn the faucet off (admin only)
+    pub fn disable_faucet(env: Env) {
+        read_admin(&env).require_auth();
+
+        env.storage().instance().remove(&DataKey::FaucetConfig);
+    }
+
+    /// Get the faucet settings
+    ///
+    /// # Returns
+    /// * `Option<FaucetConfig>` - Current settings, or `None` if the faucet is off
+    pub fn get_faucet_config(env: Env) -> Option<FaucetConfig> {
+        env.storage().instance().get(&DataKey::FaucetConfig)
+    }
+
+    /// Claim the faucet allowance. A claim is allowed once per cooldown window
+    /// and is reduced so the balance does not exceed the cap.
+    ///
+    /// **Auth:** Requires authorization from `player`.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the claiming player
+    ///
+    /// # Returns
+    /// * `i128` - Points credited
+    pub fn claim_daily_points(env: Env, player: Address) -> Result<i128, Error> {
+        player.require_auth();
+
+        let config = Self::get_faucet_config(env.clone()).ok_or(Error::FaucetDisabled)?;
+        let ledger = env.ledger().sequence();
+        if ledger < Self::get_next_claim_ledger(env.clone(), player.clone()) {
+            return Err(Error::FaucetCooldown);
+        }
+        let amount = config
+            .allowance
+            .min(config.balance_cap - read_balance(&env, &player));
+        if amount <= 0 {
+            return Err(Error::FaucetCapReached);
+        }
+
+        credit_points(&env, &player, amount)?;
+        let key = DataKey::LastClaim(player.clone());
+        env.storage().persistent().set(&key, &ledger);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+
+        PointsClaimed { player, amount }.publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Get the first ledger at which a player may claim from the faucet
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `u32` - The ledger (0 if the player has never claimed)
+    pub fn get_next_claim_ledger(env: Env, player: Address) -> u32 {
+        let last_claim: Option<u32> = env.storage().persistent().get(&DataKey::LastClaim(player));
+        match (last_claim, Self::get_faucet_config(env)) {
+            (Some(last_claim), Some(config)) => last_claim.saturating_add(config.cooldown_ledgers),
+            _ => 0,
+        }
+    }
+
+    // ========================================================================
+    // Game Registry
+    // ========================================================================
+
+    /// Register a game contract so it can start sessions (admin only)
+    ///
+    /// The game starts with the default maximum session duration and stakes hub points.
+    /// Registering a game that is already registered fails, so its pause flag,
+    /// token and developer are only changed through their own setters.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn add_game(env: Env, game_id: Address) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if read_game_config(&env, &game_id).is_some() {
+            return Err(Error::GameAlreadyRegistered);
+        }
+        let config = GameConfig {
+            max_session_ledgers: DEFAULT_MAX_SESSION_LEDGERS,
+            token: None,
+            developer: None,
+            paused: false,
+        };
+        write_game_config(&env, &game_id, &config);
+
+        GameRegistered {
+            game_id,
+            max_session_ledgers: config.max_session_ledgers,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set how many ledgers a session of this game may run before players
+    /// can reclaim their stakes (admin only). Applies to new sessions.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `max_session_ledgers` - Maximum session duration in ledgers
+    pub fn set_max_session_ledgers(
+        env: Env,
+        game_id: Address,
+        max_session_ledgers: u32,
+    ) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if max_session_ledgers == 0 {
+            return Err(Error::InvalidDuration);
+        }
+        let mut config = read_game_config(&env, &game_id).ok_or(Error::GameNotRegistered)?;
+        config.max_session_ledgers = max_session_ledgers;
+        write_game_config(&env, &game_id, &config);
+        Ok(())
+    }
+
+    /// Set the token a game's sessions stake (admin only). Applies to new
+    /// sessions; sessions already running settle in the token they started with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `token` - SEP-41 token contract, or `None` to stake hub points
+    pub fn set_game_token(env: Env, game_id: Address, token: Option<Address>) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        let mut config = read_game_config(&env, &game_id).ok_or(Error::GameNotRegistered)?;
+        config.token = token;
+        write_game_config(&env, &game_id, &config);
+        Ok(())
+    }
+
+    /// Set the address that receives the developer share of a game's fees (admin only)
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `developer` - Developer address, or `None` to send the whole fee to the treasury
+    pub fn set_game_developer(
+        env: Env,
+        game_id: Address,
+        developer: Option<Address>,
+    ) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        let mut config = read_game_config(&env, &game_id).ok_or(Error::GameNotRegistered)?;
+        config.developer = developer;
+        write_game_config(&env, &game_id, &config);
+        Ok(())
+    }
+
+    /// Set the stake rules for a game's new sessions (admin only)
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `min_stake` - Smallest stake a player may commit
+    /// * `max_stake` - Largest stake a player may commit, or `None` for no limit
+    /// * `matching` - How the stakes within one session must compare
+    pub fn set_stake_limits(
+        env: Env,
+        game_id: Address,
+        min_stake: i128,
+        max_stake: Option<i128>,
+        matching: StakeMatching,
+    ) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if read_game_config(&env, &game_id).is_none() {
+            return Err(Error::GameNotRegistered);
+        }
+        let ratio_too_low = matches!(
+            matching,
+            StakeMatching::MaxRatioBps(ratio_bps) if (ratio_bps as i128) < BPS_DENOMINATOR
+        );
+        if min_stake < 0 || max_stake.is_some_and(|max| max < min_stake) || ratio_too_low {
+            return Err(Error::InvalidStakeLimits);
+        }
+
+        let key = DataKey::StakeLimits(game_id);
+        let limits = StakeLimits {
+            min_stake,
+            max_stake,
+            matching,
+        };
+        env.storage().persistent().set(&key, &limits);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+        Ok(())
+    }
+
+    /// Get the stake rules for a game
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    ///
+    /// # Returns
+    /// * `StakeLimits` - The game's rules; any non-negative stakes if never set
+    pub fn get_stake_limits(env: Env, game_id: Address) -> StakeLimits {
+        read_stake_limits(&env, &game_id)
+    }
+
+    /// Remove a game contract from the registry (admin only)
+    ///
+    /// Sessions already started by the game can still be ended.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn remove_game(env: Env, game_id: Address) {
+        read_admin(&env).require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StakeLimits(game_id.clone()));
+        env.storage().persistent().remove(&DataKey::Game(game_id));
+    }
+
+    /// Check whether a game contract is registered
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    ///
+    /// # Returns
+    /// * `bool` - True if the game can start sessions
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        read_game_config(&env, &game_id).is_some()
+    }
+
+    /// Get the registry settings for a game contract
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    ///
+    /// # Returns
+    /// * `GameConfig` - The game's settings
+    pub fn get_game_config(env: Env, game_id: Address) -> Result<GameConfig, Error> {
+        read_game_config(&env, &game_id).ok_or(Error::GameNotRegistered)
+    }
+
+    // ========================================================================
+    // Emergency Pause
+    // ========================================================================
+
+    /// Stop new sessions for every game (admin or pauser).
+    /// Sessions already running can still be settled or reclaimed.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    pub fn pause_all(env: Env, caller: Address) {
+        admin::require_role(&env, Role::Pauser, &caller);
+
+        env.storage().instance().set(&DataKey::Paused, &true);
+        Paused { game_id: None }.publish(&env);
+    }
+
+    /// Allow new sessions again after `pause_all` (admin or pauser).
+    /// Games paused individually stay paused.
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    pub fn unpause_all(env: Env, caller: Address) {
+        admin::require_role(&env, Role::Pauser, &caller);
+
+        env.storage().instance().remove(&DataKey::Paused);
+        Unpaused { game_id: None }.publish(&env);
+    }
+
+    /// Stop new sessions for one game (admin or pauser)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    /// * `game_id` - Address of a registered game contract
+    pub fn pause_game(env: Env, caller: Address, game_id: Address) -> Result<(), Error> {
+        set_game_paused(&env, &caller, game_id, true)
+    }
+
+    /// Allow new sessions again for a paused game (admin or pauser)
+    ///
+    /// # Arguments
+    /// * `caller` - The admin or an account holding `Pauser`
+    /// * `game_id` - Address of a registered game contract
+    pub fn unpause_game(env: Env, caller: Address, game_id: Address) -> Result<(), Error> {
+        set_game_paused(&env, &caller, game_id, false)
+    }
+
+    /// Check whether the whole hub is paused
+    ///
+    /// # Returns
+    /// * `bool` - True after `pause_all` until `unpause_all`
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    // ========================================================================
+    // Fees
+    // ========================================================================
+
+    /// Configure the hub fee (admin only). The rate applies to sessions started
+    /// after the change; the split and treasury apply at settlement.
+    ///
+    /// # Arguments
+    /// * `fee_bps` - Fee taken from each decided pot, in basis points (max 1000)
+    /// * `developer_share_bps` - Portion of the fee paid to the game's developer
+    /// * `treasury` - Address that receives the hub's share
+    pub fn set_fee_config(
+        env: Env,
+        fee_bps: u32,
+        developer_share_bps: u32,
+        treasury: Address,
+    ) -> Result<(), Error> {
+        read_admin(&env).require_auth();
+
+        if fee_bps > MAX_FEE_BPS || developer_share_bps as i128 > BPS_DENOMINATOR {
+            return Err(Error::InvalidFee);
+        }
+        let config = FeeConfig {
+            fee_bps,
+            developer_share_bps,
+            treasury,
+        };
+        env.storage().instance().set(&DataKey::FeeConfig, &config);
+        Ok(())
+    }
+
+    /// Get the hub fee settings
+    ///
+    /// # Returns
+    /// * `Option<FeeConfig>` - Current fee settings, or `None` if fees were never configured
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        read_fee_config(&env)
+    }
+
+    /// Get the fees owed to a recipient
+    ///
+    /// # Arguments
+    /// * `recipient` - Treasury or developer address
+    /// * `token` - Token the fees were taken in, or `None` for hub points
+    ///
+    /// # Returns
+    /// * `i128` - Amount available to withdraw
+    pub fn get_fees(env: Env, recipient: Address, token: Option<Address>) -> i128 {
+        read_fees(&env, &recipient, &token)
+    }
+
+    /// Withdraw accrued fees. Token fees are transferred to the recipient;
+    /// point fees are credited to the recipient's points balance.
+    ///
+    /// **Auth:** Requires authorization from `recipient`.
+    ///
+    /// # Arguments
+    /// * `recipient` - Treasury or developer address
+    /// * `token` - Token to withdraw, or `None` for hub points
+    ///
+    /// # Returns
+    /// * `i128` - Amount withdrawn
+    pub fn withdraw_fees(
+        env: Env,
+        recipient: Address,
+        token: Option<Address>,
+    ) -> Result<i128, Error> {
+        recipient.require_auth();
+
+        let amount = read_fees(&env, &recipient, &token);
+        if amount == 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Fees(recipient.clone(), token.clone()));
+        release(&env, &token, &recipient, amount)?;
+        Ok(amount)
+    }
+
+    // ========================================================================
+    // Seasons
+    // ========================================================================
+
+    /// Start a new season running from the current ledger to `end_ledger` (admin only).
+    /// The previous season must be closed first.
+    ///
+    /// # Arguments
+    /// * `end_ledger` - Last ledger whose results count toward the season
+    /// * `ranking` - Metric the final standings are ranked by
+    /// * `reward_token` - Token the reward pot is held in, or `None` for hub points
+    /// * `reward_split_bps` - Share of the pot for each place (1-10 places, summing to 10000)
+    ///
+    /// # Returns
+    /// * `u32` - The season number (seasons are numbered from 1)
+    pub fn start_season(
+        env: Env,
+        end_ledger: u32,
+        ranking: LeaderboardSort,
+        reward_token: Option<Address>,
+        reward_split_bps: Vec<u32>,
+    ) -> Result<u32, Error> {
+        read_admin(&env).require_auth();
+
+        let previous = current_season(&env);
+        if let Ok(season) = read_season(&env, previous) {
+            if season.status == SeasonStatus::Active {
+                return Err(Error::SeasonActive);
+            }
+        }
+
+        let start_ledger = env.ledger().sequence();
+        let total_bps: u32 = reward_split_bps.iter().sum();
+        if end_ledger <= start_ledger
+            || reward_split_bps.is_empty()
+            || reward_split_bps.len() > MAX_REWARD_PLACES
+            || total_bps as i128 != BPS_DENOMINATOR
+        {
+            return Err(Error::InvalidSeason);
+        }
+
+        let number = previous.checked_add(1).ok_or(Error::Overflow)?;
+        let season = Season {
+            start_ledger,
+            end_ledger,
+            status: SeasonStatus::Active,
+            ranking,
+            reward_token,
+            reward_pot: 0,
+            reward_split_bps,
+        };
+        write_season(&env, number, &season);
+        env.storage()
+            .instance()
+            .set(&DataKey::CurrentSeason, &number);
+
+        SeasonStarted {
+            season: number,
+            start_ledger,
+            end_ledger,
+        }
+        .publish(&env);
+
+        Ok(number)
+    }
+
+    /// Add to the current season's reward pot. The pot is escrowed in the
+    /// season's reward token, or locked from the funder's points balance.
+    ///
+    /// **Auth:** Requires authorization from `funder`.
+    ///
+    /// # Arguments
+    /// * `funder` - Address paying into the pot
+    /// * `amount` - Amount to add (must be positive)
+    pub fn fund_season(env: Env, funder: Address, amount: i128) -> Result<(), Error> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let number = current_season(&env);
+        let mut season = read_season(&env, number).map_err(|_| Error::NoActiveSeason)?;
+        if season.status != SeasonStatus::Active {
+            return Err(Error::NoActiveSeason);
+        }
+
+        escrow(&env, &season.reward_token, &funder, amount)?;
+        season.reward_pot = season
+            .reward_pot
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        write_season(&env, number, &season);
+        Ok(())
+    }
+
+    /// Close the current season once its end ledger has passed. Anyone can call this.
+    ///
+    /// The reward pot is paid by place from the final standings (top 100).
+    /// Rounding and places nobody finished in are returned to the admin.
+    pub fn close_season(env: Env) -> Result<(), Error> {
+        let number = current_season(&env);
+        let mut season = read_season(&env, number).map_err(|_| Error::NoActiveSeason)?;
+        if season.status != SeasonStatus::Active {
+            return Err(Error::NoActiveSeason);
+        }
+        if env.ledger().sequence() <= season.end_ledger {
+            return Err(Error::SeasonNotOver);
+        }
+
+        let standings = read_ranked(&env, &DataKey::SeasonStandings(number));
+        let players: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SeasonPlayerCount(number))
+            .unwrap_or(0);
+
+        let mut paid: i128 = 0;
+        for (entry, share) in standings.iter().zip(season.reward_split_bps.iter()) {
+            let reward = season
+                .reward_pot
+                .checked_mul(share as i128)
+                .ok_or(Error::Overflow)?
+                / BPS_DENOMINATOR;
+            release(&env, &season.reward_token, &entry.player, reward)?;
+            paid += reward;
+        }
+        let unpaid = season.reward_pot - paid;
+        if unpaid > 0 {
+            release(&env, &season.reward_token, &read_admin(&env), unpaid)?;
+        }
+
+        season.status = SeasonStatus::Closed;
+        write_season(&env, number, &season);
+
+        SeasonClosed {
+            season: number,
+            players,
+            rewards_paid: paid,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the number of the latest season started
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The season number, or `None` if no season has started
+    pub fn get_current_season(env: Env) -> Option<u32> {
+        match current_season(&env) {
+            0 => None,
+            number => Some(number),
+        }
+    }
+
+    /// Get a season by number
+    ///
+    /// # Arguments
+    /// * `season` - The season number
+    ///
+    /// # Returns
+    /// * `Season` - The season record
+    pub fn get_season(env: Env, season: u32) -> Result<Season, Error> {
+        read_season(&env, season)
+    }
+
+    /// Get a player's stats across all games for one season
+    ///
+    /// # Arguments
+    /// * `season` - The season number
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `PlayerStats` - The player's results that season (all zero if none)
+    pub fn get_season_stats(env: Env, season: u32, player: Address) -> PlayerStats {
+        read_stats(&env, &DataKey::SeasonStats(season, player))
+    }
+
+    /// Get a player's rating in one game for one season
+    ///
+    /// # Arguments
+    /// * `season` - The season number
+    /// * `game_id` - Address of the game contract
+    /// * `player` - Address of the player
+    ///
+    /// # Returns
+    /// * `u32` - The player's season rating (the initial rating if unrated)
+    pub fn get_season_rating(env: Env, season: u32, game_id: Address, player: Address) -> u32 {
+        read_rating(&env, &DataKey::SeasonRating(season, game_id, player))
+    }
+
+    /// Get a page of a season's standings (top 100), ranked by the season's
+    /// metric. The standings are live until the season's end ledger, then final.
+    ///
+    /// # Arguments
+    /// * `season` - The season number
+    /// * `offset` - Number of ranked entries to skip
+    /// * `limit` - Maximum entries to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `Vec<LeaderboardEntry>` - Entries from rank `offset + 1` onward
+    pub fn get_season_standings(
+        env: Env,
+        season: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        read_season(&env, season)?;
+        let ranked = read_ranked(&env, &DataKey::SeasonStandings(season));
+        Ok(page(&env, &ranked, offset, limit))
+    }
+
+    // ========================================================================
+    // Storage Schema
+    // ========================================================================
+
+    /// Get the schema version of the stored data
+    ///
+    /// # Returns
+    /// * `u32` - Schema version; lags this build's version until data is migrated after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
+    }
+}
+
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_common::impl_admin!(MockGameHub);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,2204 @@
+#![cfg(test)]
+
+// Unit tests for the mock Game Hub: points ledger, token escrow, fees, game
+// registry, two-player and multi-player sessions, ratings, player statistics,
+// series and rematches, seasons, the points faucet, stake limits, emergency
+// pause and pauser roles.
+
+extern crate std;
+
+use crate::{
+    Error, FeeCollected, GameEnded, GameRegistered, GameStarted, LeaderboardSort, MockGameHub,
+    MockGameHubClient, Outcome, Paused, PlayerStats, PointsClaimed, SeasonClosed, SeasonStatus, SeriesEnded,
+    SessionCancelled, SessionExpired, SessionStatus, StakeLimits, StakeMatching, Unpaused,
+};
+use sgs_common::admin::Role;
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Events as _, Ledger as _, MockAuth,
+    MockAuthInvoke,
+};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, xdr, Address, Env, Event, IntoVal, Symbol, Vec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, MockGameHubClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    let game_id = Address::generate(&env);
+    client.add_game(&game_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    client.add_points(&player1, &1000);
+    client.add_points(&player2, &1000);
+
+    (env, client, game_id, player1, player2)
+}
+
+/// Assert that a Result contains a specific hub error
+fn assert_hub_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Points Ledger Tests
+// ============================================================================
+
+#[test]
+fn test_start_and_end_game() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &1000, &1000);
+    client.end_game(&1, &Outcome::Player1);
+}
+
+#[test]
+fn test_start_game_locks_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+
+    assert_eq!(client.get_balance(&player1), 700);
+    assert_eq!(client.get_balance(&player2), 800);
+}
+
+#[test]
+fn test_winner_receives_pot() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Player2);
+
+    assert_eq!(client.get_balance(&player1), 700);
+    assert_eq!(client.get_balance(&player2), 1300);
+}
+
+#[test]
+fn test_draw_returns_each_stake() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(Outcome::Draw)
+    );
+}
+
+#[test]
+fn test_cancel_refunds_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Cancelled);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(Outcome::Cancelled)
+    );
+
+    let result = client.try_end_game(&1, &Outcome::Player1);
+    assert_hub_error(&result, Error::SessionNotActive);
+}
+
+#[test]
+fn test_start_rejected_when_balance_too_low() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &player1, &player2, &1000, &1001);
+    assert_hub_error(&result, Error::InsufficientBalance);
+
+    // Nothing was locked
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+}
+
+#[test]
+fn test_start_rejects_negative_stake() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &player1, &player2, &-1, &100);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_session_ids_are_allocated_in_order() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    assert_eq!(client.start_game(&game_id, &player1, &player2, &100, &100), 1);
+    assert_eq!(client.start_game(&other_game, &player1, &player2, &100, &100), 2);
+    assert_eq!(client.get_session(&1).game_id, game_id);
+    assert_eq!(client.get_session(&2).game_id, other_game);
+}
+
+#[test]
+fn test_session_ids_are_not_reused() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Cancelled);
+
+    // A failed start does not consume an ID either
+    let result = client.try_start_game(&game_id, &player1, &player2, &5000, &100);
+    assert_hub_error(&result, Error::InsufficientBalance);
+
+    assert_eq!(client.start_game(&game_id, &player1, &player2, &100, &100), 2);
+}
+
+#[test]
+fn test_reserve_session_ids() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.reserve_session_ids(&41);
+    assert_eq!(client.start_game(&game_id, &player1, &player2, &100, &100), 42);
+
+    // Reserving IDs below the counter leaves it where it is
+    client.reserve_session_ids(&10);
+    assert_eq!(client.start_game(&game_id, &player1, &player2, &100, &100), 43);
+}
+
+#[test]
+#[should_panic]
+fn test_reserve_session_ids_requires_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    // No auths mocked - admin has not signed
+    client.reserve_session_ids(&41);
+}
+
+#[test]
+fn test_cannot_end_session_twice() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    let result = client.try_end_game(&1, &Outcome::Player1);
+    assert_hub_error(&result, Error::SessionNotActive);
+    assert_eq!(client.get_balance(&player1), 1100);
+}
+
+#[test]
+fn test_add_points_rejects_non_positive_amount() {
+    let (_env, client, _game_id, player1, _player2) = setup_test();
+
+    let result = client.try_add_points(&player1, &0);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+// ============================================================================
+// Faucet Tests
+// ============================================================================
+
+/// Allowance 100 every 1,000 ledgers, up to a balance of 250
+fn setup_faucet(env: &Env, client: &MockGameHubClient) {
+    client.set_faucet_config(&100, &1000, &250);
+    env.ledger().set_sequence_number(5000);
+}
+
+#[test]
+fn test_claim_daily_points() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    setup_faucet(&env, &client);
+    let newcomer = Address::generate(&env);
+
+    assert_eq!(client.claim_daily_points(&newcomer), 100);
+    let expected = PointsClaimed {
+        player: newcomer.clone(),
+        amount: 100,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+
+    assert_eq!(client.get_balance(&newcomer), 100);
+    assert_eq!(client.get_next_claim_ledger(&newcomer), 6000);
+}
+
+#[test]
+fn test_claim_cooldown() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    setup_faucet(&env, &client);
+    let newcomer = Address::generate(&env);
+
+    client.claim_daily_points(&newcomer);
+    env.ledger().set_sequence_number(5999);
+    assert_hub_error(
+        &client.try_claim_daily_points(&newcomer),
+        Error::FaucetCooldown,
+    );
+
+    env.ledger().set_sequence_number(6000);
+    client.claim_daily_points(&newcomer);
+    assert_eq!(client.get_balance(&newcomer), 200);
+}
+
+#[test]
+fn test_claim_stops_at_balance_cap() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    setup_faucet(&env, &client);
+    let newcomer = Address::generate(&env);
+
+    client.claim_daily_points(&newcomer);
+    env.ledger().set_sequence_number(6000);
+    client.claim_daily_points(&newcomer);
+
+    // Only 50 more fits under the cap
+    env.ledger().set_sequence_number(7000);
+    assert_eq!(client.claim_daily_points(&newcomer), 50);
+
+    env.ledger().set_sequence_number(8000);
+    assert_hub_error(
+        &client.try_claim_daily_points(&newcomer),
+        Error::FaucetCapReached,
+    );
+}
+
+#[test]
+fn test_faucet_points_can_be_staked() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    setup_faucet(&env, &client);
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    client.claim_daily_points(&alice);
+    client.claim_daily_points(&bob);
+
+    let session_id = client.start_game(&game_id, &alice, &bob, &100, &100);
+    client.end_game(&session_id, &Outcome::Player1);
+    assert_eq!(client.get_balance(&alice), 200);
+    assert_eq!(client.get_balance(&bob), 0);
+}
+
+#[test]
+fn test_faucet_disabled() {
+    let (env, client, _game_id, player1, _player2) = setup_test();
+
+    assert_hub_error(
+        &client.try_claim_daily_points(&player1),
+        Error::FaucetDisabled,
+    );
+
+    setup_faucet(&env, &client);
+    assert!(client.get_faucet_config().is_some());
+    client.disable_faucet();
+    assert_eq!(client.get_faucet_config(), None);
+    assert_hub_error(
+        &client.try_claim_daily_points(&player1),
+        Error::FaucetDisabled,
+    );
+}
+
+#[test]
+fn test_set_faucet_config_validation() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    assert_hub_error(
+        &client.try_set_faucet_config(&0, &1000, &250),
+        Error::InvalidAmount,
+    );
+    assert_hub_error(
+        &client.try_set_faucet_config(&100, &1000, &50),
+        Error::InvalidAmount,
+    );
+    assert_hub_error(
+        &client.try_set_faucet_config(&100, &0, &250),
+        Error::InvalidDuration,
+    );
+}
+
+// ============================================================================
+// Game Registry Tests
+// ============================================================================
+
+#[test]
+fn test_add_and_remove_game() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let other_game = Address::generate(&env);
+
+    assert!(client.is_game(&game_id));
+    assert!(!client.is_game(&other_game));
+
+    client.remove_game(&game_id);
+    assert!(!client.is_game(&game_id));
+}
+
+#[test]
+fn test_game_registered_event() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let other_game = Address::generate(&env);
+
+    client.add_game(&other_game);
+
+    let expected = GameRegistered {
+        game_id: other_game,
+        max_session_ledgers: 17_280,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_unregistered_game_cannot_start() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let rogue_game = Address::generate(&env);
+
+    let result = client.try_start_game(&rogue_game, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+#[test]
+fn test_removed_game_can_still_end_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.remove_game(&game_id);
+
+    let result = client.try_start_game(&game_id, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+
+    client.end_game(&1, &Outcome::Player1);
+    assert_eq!(client.get_balance(&player1), 1100);
+}
+
+#[test]
+fn test_start_game_requires_game_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            game_id.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "start_game"),
+                    (&game_id, &player1, &player2, 100i128, 100i128).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_add_game_requires_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    // No auths mocked - admin has not signed
+    client.add_game(&Address::generate(&env));
+}
+
+#[test]
+fn test_add_game_rejects_registered_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let developer = Address::generate(&env);
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+    client.set_game_developer(&game_id, &Some(developer.clone()));
+    client.pause_game(&admin, &game_id);
+
+    assert_hub_error(&client.try_add_game(&game_id), Error::GameAlreadyRegistered);
+
+    let config = client.get_game_config(&game_id);
+    assert!(config.paused);
+    assert_eq!(config.token, Some(token.address));
+    assert_eq!(config.developer, Some(developer));
+}
+
+// ============================================================================
+// Stake Limit Tests
+// ============================================================================
+
+#[test]
+fn test_default_stake_limits_allow_any_non_negative_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    assert_eq!(
+        client.get_stake_limits(&game_id),
+        StakeLimits {
+            min_stake: 0,
+            max_stake: None,
+            matching: StakeMatching::Any,
+        }
+    );
+    client.start_game(&game_id, &player1, &player2, &0, &900);
+}
+
+#[test]
+fn test_stakes_outside_limits_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_stake_limits(&game_id, &10, &Some(500), &StakeMatching::Any);
+
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &9, &100),
+        Error::StakeTooLow,
+    );
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &501),
+        Error::StakeTooHigh,
+    );
+    client.start_game(&game_id, &player1, &player2, &10, &500);
+}
+
+#[test]
+fn test_equal_stake_matching() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1000);
+    client.set_stake_limits(&game_id, &0, &None, &StakeMatching::Equal);
+
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &50),
+        Error::StakesNotMatched,
+    );
+    assert_hub_error(
+        &client.try_start_game_multi(
+            &game_id,
+            &vec![&env, player1.clone(), player2.clone(), player3.clone()],
+            &vec![&env, 30i128, 30, 31],
+        ),
+        Error::StakesNotMatched,
+    );
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+}
+
+#[test]
+fn test_ratio_stake_matching() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    // The larger stake may be at most double the smaller
+    client.set_stake_limits(&game_id, &0, &None, &StakeMatching::MaxRatioBps(20_000));
+
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &201),
+        Error::StakesNotMatched,
+    );
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &0, &1),
+        Error::StakesNotMatched,
+    );
+    client.start_game(&game_id, &player1, &player2, &200, &100);
+    client.start_game(&game_id, &player1, &player2, &0, &0);
+}
+
+#[test]
+fn test_invalid_stake_limits_rejected() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+
+    assert_hub_error(
+        &client.try_set_stake_limits(&game_id, &-1, &None, &StakeMatching::Any),
+        Error::InvalidStakeLimits,
+    );
+    assert_hub_error(
+        &client.try_set_stake_limits(&game_id, &100, &Some(99), &StakeMatching::Any),
+        Error::InvalidStakeLimits,
+    );
+    assert_hub_error(
+        &client.try_set_stake_limits(&game_id, &0, &None, &StakeMatching::MaxRatioBps(9_999)),
+        Error::InvalidStakeLimits,
+    );
+    assert_hub_error(
+        &client.try_set_stake_limits(&Address::generate(&env), &0, &None, &StakeMatching::Equal),
+        Error::GameNotRegistered,
+    );
+}
+
+#[test]
+fn test_remove_game_clears_stake_limits() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_stake_limits(&game_id, &50, &None, &StakeMatching::Equal);
+
+    client.remove_game(&game_id);
+    client.add_game(&game_id);
+
+    assert_eq!(
+        client.get_stake_limits(&game_id).matching,
+        StakeMatching::Any
+    );
+    client.start_game(&game_id, &player1, &player2, &10, &20);
+}
+
+// ============================================================================
+// Emergency Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_all_blocks_new_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+
+    client.pause_all(&admin);
+    assert!(client.is_paused());
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &100),
+        Error::HubPaused,
+    );
+
+    client.unpause_all(&admin);
+    assert!(!client.is_paused());
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+}
+
+#[test]
+fn test_pause_game_blocks_only_that_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.pause_game(&admin, &game_id);
+    assert!(client.get_game_config(&game_id).paused);
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &100),
+        Error::GamePaused,
+    );
+    client.start_game(&other_game, &player1, &player2, &100, &100);
+
+    client.unpause_game(&admin, &game_id);
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+}
+
+#[test]
+fn test_unpause_all_keeps_paused_games() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+
+    client.pause_game(&admin, &game_id);
+    client.pause_all(&admin);
+    client.unpause_all(&admin);
+
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player2, &100, &100),
+        Error::GamePaused,
+    );
+}
+
+#[test]
+fn test_in_flight_sessions_settle_while_paused() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let ended = client.start_game(&game_id, &player1, &player2, &100, &100);
+    let expired = client.start_game(&game_id, &player1, &player2, &50, &50);
+
+    client.pause_all(&admin);
+    client.pause_game(&admin, &game_id);
+
+    client.end_game(&ended, &Outcome::Player1);
+    let expires = client.get_session(&expired).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+    client.claim_expired_session(&expired, &player2);
+
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player2), 900);
+}
+
+#[test]
+fn test_pause_events() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+
+    client.pause_all(&admin);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [Paused { game_id: None }.to_xdr(&env, &client.address)]
+    );
+
+    client.unpause_game(&admin, &game_id);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [Unpaused {
+            game_id: Some(game_id)
+        }
+        .to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_pauser_role_can_pause() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let ops = Address::generate(&env);
+    assert!(client.try_pause_all(&ops).is_err());
+
+    client.grant_role(&Role::Pauser, &ops);
+    assert!(client.has_role(&Role::Pauser, &ops));
+    client.pause_all(&ops);
+    client.pause_game(&ops, &game_id);
+    assert!(client.is_paused());
+
+    client.revoke_role(&Role::Pauser, &ops);
+    assert!(client.try_unpause_all(&ops).is_err());
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_pause_game_requires_registered_game() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let unknown_game = Address::generate(&env);
+
+    assert_hub_error(
+        &client.try_pause_game(&admin, &unknown_game),
+        Error::GameNotRegistered,
+    );
+}
+
+// ============================================================================
+// Session Tests
+// ============================================================================
+
+#[test]
+fn test_session_record() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.ledger().set_sequence_number(42);
+
+    let session_id = client.start_game(&game_id, &player1, &player2, &300, &200);
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.game_id, game_id);
+    assert_eq!(session.players, vec![&env, player1.clone(), player2.clone()]);
+    assert_eq!(session.stakes, vec![&env, 300i128, 200]);
+    assert_eq!(session.status, SessionStatus::Active);
+    assert_eq!(session.start_ledger, 42);
+    assert_eq!(session.expires_ledger, 42 + 17_280);
+
+    client.end_game(&session_id, &Outcome::Player1);
+    assert_eq!(
+        client.get_session(&session_id).status,
+        SessionStatus::Ended(Outcome::Player1)
+    );
+}
+
+#[test]
+fn test_get_unknown_session() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_get_session(&99);
+    assert_hub_error(&result, Error::SessionNotFound);
+}
+
+#[test]
+fn test_cannot_end_session_that_never_started() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_end_game(&99, &Outcome::Player1);
+    assert_hub_error(&result, Error::SessionNotFound);
+}
+
+#[test]
+fn test_end_game_requires_originating_game_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            game_id.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "end_game"),
+                    (1u32, Outcome::Player1).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+fn test_other_game_cannot_end_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+
+    // Only the other (registered) game signs the call
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &other_game,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "end_game",
+                args: (1u32, Outcome::Player1).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_end_game(&1, &Outcome::Player1);
+    assert!(result.is_err());
+
+    assert_eq!(client.get_session(&1).status, SessionStatus::Active);
+    assert_eq!(client.get_balance(&player1), 900);
+}
+
+// ============================================================================
+// Session Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_max_session_ledgers_is_per_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+    env.ledger().set_sequence_number(100);
+
+    client.set_max_session_ledgers(&game_id, &50);
+    assert_eq!(client.get_game_config(&game_id).max_session_ledgers, 50);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.start_game(&other_game, &player1, &player2, &100, &100);
+
+    assert_eq!(client.get_session(&1).expires_ledger, 150);
+    assert_eq!(client.get_session(&2).expires_ledger, 100 + 17_280);
+}
+
+#[test]
+fn test_claim_expired_session_refunds_stakes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_max_session_ledgers(&game_id, &10);
+    env.ledger().set_sequence_number(100);
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+
+    env.ledger().set_sequence_number(111);
+    client.claim_expired_session(&1, &player2);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_session(&1).status, SessionStatus::Expired);
+
+    // The game can no longer settle the session
+    let result = client.try_end_game(&1, &Outcome::Player1);
+    assert_hub_error(&result, Error::SessionNotActive);
+}
+
+#[test]
+fn test_cannot_claim_before_deadline() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_max_session_ledgers(&game_id, &10);
+    env.ledger().set_sequence_number(100);
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+
+    env.ledger().set_sequence_number(110);
+    let result = client.try_claim_expired_session(&1, &player1);
+    assert_hub_error(&result, Error::SessionNotExpired);
+}
+
+#[test]
+fn test_non_player_cannot_claim_expired_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_max_session_ledgers(&game_id, &10);
+    env.ledger().set_sequence_number(100);
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+
+    env.ledger().set_sequence_number(200);
+    let result = client.try_claim_expired_session(&1, &Address::generate(&env));
+    assert_hub_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_cannot_claim_ended_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_max_session_ledgers(&game_id, &10);
+    env.ledger().set_sequence_number(100);
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Player1);
+
+    env.ledger().set_sequence_number(200);
+    let result = client.try_claim_expired_session(&1, &player2);
+    assert_hub_error(&result, Error::SessionNotActive);
+    assert_eq!(client.get_balance(&player1), 1200);
+}
+
+#[test]
+fn test_set_max_session_ledgers_validation() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_set_max_session_ledgers(&game_id, &0);
+    assert_hub_error(&result, Error::InvalidDuration);
+
+    let result = client.try_set_max_session_ledgers(&Address::generate(&env), &10);
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+// ============================================================================
+// Series and Rematch Tests
+// ============================================================================
+
+#[test]
+fn test_series_winner_takes_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let series_id = client.start_series(&game_id, &player1, &player2, &100, &100, &3);
+    let pot_session = client.get_series(&series_id).pot_session;
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 900);
+
+    // Games in the series stake nothing of their own
+    let game1 = client.start_series_game(&series_id);
+    assert_eq!(client.get_session(&game1).stakes, vec![&env, 0i128, 0]);
+    assert_eq!(client.get_session_series(&game1), Some(series_id));
+    client.end_game(&game1, &Outcome::Player1);
+    assert_eq!(
+        client.get_session_status(&pot_session),
+        SessionStatus::Active
+    );
+
+    let game2 = client.rematch(&game1);
+    client.end_game(&game2, &Outcome::Player1);
+    let expected = SeriesEnded {
+        series_id,
+        game_id: game_id.clone(),
+        players: vec![&env, player1.clone(), player2.clone()],
+        outcome: Outcome::Player1,
+        winner: Some(player1.clone()),
+        wins: vec![&env, 2u32, 0],
+        token: None,
+        pot: 200,
+        fee: 0,
+    };
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert_eq!(
+        events.events().last().unwrap(),
+        &expected.to_xdr(&env, &client.address)
+    );
+
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player2), 900);
+    assert_eq!(
+        client.get_session_status(&pot_session),
+        SessionStatus::Ended(Outcome::Player1)
+    );
+    assert_hub_error(
+        &client.try_start_series_game(&series_id),
+        Error::SeriesNotActive,
+    );
+}
+
+#[test]
+fn test_only_series_result_is_rated() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let series_id = client.start_series(&game_id, &player1, &player2, &50, &50, &3);
+
+    let game1 = client.start_series_game(&series_id);
+    client.end_game(&game1, &Outcome::Player2);
+    assert_eq!(client.get_rating(&game_id, &player2), 1200);
+
+    let game2 = client.start_series_game(&series_id);
+    client.end_game(&game2, &Outcome::Player1);
+    let game3 = client.start_series_game(&series_id);
+    client.end_game(&game3, &Outcome::Player2);
+
+    assert!(client.get_rating(&game_id, &player2) > 1200);
+    assert_eq!(client.get_stats(&game_id, &player2).games_played, 1);
+    assert_eq!(client.get_series(&series_id).wins, vec![&env, 1u32, 2]);
+}
+
+#[test]
+fn test_series_plays_one_game_at_a_time() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let series_id = client.start_series(&game_id, &player1, &player2, &100, &100, &3);
+
+    let game1 = client.start_series_game(&series_id);
+    assert_hub_error(
+        &client.try_start_series_game(&series_id),
+        Error::SessionStillActive,
+    );
+    assert_hub_error(&client.try_rematch(&game1), Error::SessionStillActive);
+
+    // A cancelled game does not count towards the series
+    client.end_game(&game1, &Outcome::Cancelled);
+    client.start_series_game(&series_id);
+    assert_eq!(client.get_series(&series_id).games_played, 0);
+}
+
+#[test]
+fn test_level_series_is_refunded() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let series_id = client.start_series(&game_id, &player1, &player2, &300, &200, &1);
+    let pot_session = client.get_series(&series_id).pot_session;
+
+    let game = client.start_series_game(&series_id);
+    client.end_game(&game, &Outcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(
+        client.get_session_status(&pot_session),
+        SessionStatus::Ended(Outcome::Draw)
+    );
+}
+
+#[test]
+fn test_series_validation() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    for best_of in [0u32, 2, 11] {
+        assert_hub_error(
+            &client.try_start_series(&game_id, &player1, &player2, &100, &100, &best_of),
+            Error::InvalidSeriesLength,
+        );
+    }
+    client.set_stake_limits(&game_id, &0, &None, &StakeMatching::Equal);
+    assert_hub_error(
+        &client.try_start_series(&game_id, &player1, &player2, &100, &50, &3),
+        Error::StakesNotMatched,
+    );
+    assert_hub_error(&client.try_start_series_game(&1), Error::SeriesNotFound);
+
+    // The pot is only settled through the series
+    let series_id = client.start_series(&game_id, &player1, &player2, &100, &100, &3);
+    let pot_session = client.get_series(&series_id).pot_session;
+    assert_eq!(client.get_session_series(&pot_session), Some(series_id));
+    assert_hub_error(
+        &client.try_end_game(&pot_session, &Outcome::Player1),
+        Error::SeriesPotLocked,
+    );
+    assert_hub_error(
+        &client.try_end_game_multi(&pot_session, &vec![&env, 1u32, 0]),
+        Error::SeriesPotLocked,
+    );
+}
+
+#[test]
+fn test_expired_series_refunds_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_max_session_ledgers(&game_id, &10);
+    env.ledger().set_sequence_number(100);
+
+    let series_id = client.start_series(&game_id, &player1, &player2, &100, &100, &3);
+    let pot_session = client.get_series(&series_id).pot_session;
+    assert_eq!(client.get_session(&pot_session).expires_ledger, 130);
+    let game = client.start_series_game(&series_id);
+
+    env.ledger().set_sequence_number(131);
+    assert_hub_error(
+        &client.try_start_series_game(&series_id),
+        Error::SeriesNotActive,
+    );
+    client.claim_expired_session(&pot_session, &player1);
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+
+    // A game finishing late no longer counts
+    client.end_game(&game, &Outcome::Player1);
+    assert_eq!(client.get_series(&series_id).games_played, 0);
+    assert_eq!(
+        client.get_session_status(&pot_session),
+        SessionStatus::Expired
+    );
+}
+
+#[test]
+fn test_rematch_reuses_players_and_stakes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1000);
+
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Player2);
+
+    let rematch = client.rematch(&1);
+    let expected = GameStarted {
+        session_id: rematch,
+        game_id: game_id.clone(),
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 300,
+        player2_points: 200,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+    assert_eq!(client.get_balance(&player1), 400);
+    assert_eq!(client.get_balance(&player2), 1100);
+    assert_eq!(client.get_session_series(&rematch), None);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let stakes = vec![&env, 10i128, 20, 30];
+    let table = client.start_game_multi(&game_id, &players, &stakes);
+    client.end_game_multi(&table, &vec![&env, 1u32, 0, 0]);
+    let table_rematch = client.rematch(&table);
+    assert_eq!(client.get_session(&table_rematch).players, players);
+    assert_eq!(client.get_session(&table_rematch).stakes, stakes);
+}
+
+#[test]
+fn test_rematch_requires_player_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &player1, &player2, &300, &200);
+    client.end_game(&1, &Outcome::Player2);
+
+    client.rematch(&1);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == game_id));
+    for (player, stake) in [(&player1, 300i128), (&player2, 200)] {
+        assert!(auths.iter().any(|(addr, invocation)| {
+            *addr == *player
+                && invocation.function
+                    == AuthorizedFunction::Contract((
+                        client.address.clone(),
+                        Symbol::new(&env, "rematch"),
+                        (1u32, stake).into_val(&env),
+                    ))
+        }));
+    }
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_initial_rating() {
+    let (_env, client, game_id, player1, _player2) = setup_test();
+
+    assert_eq!(client.get_rating(&game_id, &player1), 1200);
+}
+
+#[test]
+fn test_win_between_equal_players() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    // Expected score 0.5, K = 32
+    assert_eq!(client.get_rating(&game_id, &player1), 1216);
+    assert_eq!(client.get_rating(&game_id, &player2), 1184);
+}
+
+#[test]
+fn test_draw_moves_ratings_toward_each_other() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player2);
+    assert_eq!(client.get_rating(&game_id, &player1), 1184);
+    assert_eq!(client.get_rating(&game_id, &player2), 1216);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&2, &Outcome::Draw);
+
+    // 32-point gap: player1 expected ~0.454, so a draw gains ~1.5 points
+    let rating1 = client.get_rating(&game_id, &player1);
+    let rating2 = client.get_rating(&game_id, &player2);
+    assert!(rating1 > 1184);
+    assert!(rating2 < 1216);
+    assert_eq!(rating1 + rating2, 2400);
+}
+
+#[test]
+fn test_upset_moves_more_points() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1000);
+
+    // player1 beats player2 twice, then loses to newcomer player3
+    client.start_game(&game_id, &player1, &player2, &10, &10);
+    client.end_game(&1, &Outcome::Player1);
+    client.start_game(&game_id, &player1, &player2, &10, &10);
+    client.end_game(&2, &Outcome::Player1);
+    let favourite = client.get_rating(&game_id, &player1);
+
+    client.start_game(&game_id, &player1, &player3, &10, &10);
+    client.end_game(&3, &Outcome::Player2);
+
+    let gain = client.get_rating(&game_id, &player3) - 1200;
+    assert!(gain > 16);
+    assert_eq!(client.get_rating(&game_id, &player1), favourite - gain);
+}
+
+#[test]
+fn test_ratings_are_per_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    assert_eq!(client.get_rating(&game_id, &player1), 1216);
+    assert_eq!(client.get_rating(&other_game, &player1), 1200);
+}
+
+#[test]
+fn test_cancelled_session_is_not_rated() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Cancelled);
+
+    assert_eq!(client.get_rating(&game_id, &player1), 1200);
+    assert_eq!(client.get_rating(&game_id, &player2), 1200);
+}
+
+// ============================================================================
+// Statistics Tests
+// ============================================================================
+
+/// Play one session between two players and report its outcome
+fn play(
+    client: &MockGameHubClient,
+    game_id: &Address,
+    player1: &Address,
+    player2: &Address,
+    stake: i128,
+    outcome: Outcome,
+) {
+    let session_id = client.start_game(game_id, player1, player2, &stake, &stake);
+    client.end_game(&session_id, &outcome);
+}
+
+#[test]
+fn test_stats_start_empty() {
+    let (_env, client, game_id, player1, _player2) = setup_test();
+
+    assert_eq!(client.get_stats(&game_id, &player1), PlayerStats::default());
+    assert_eq!(client.get_player_stats(&player1), PlayerStats::default());
+}
+
+#[test]
+fn test_stats_track_results_and_net_points() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    play(&client, &game_id, &player1, &player2, 100, Outcome::Player1);
+    play(&client, &game_id, &player1, &player2, 50, Outcome::Draw);
+    play(&client, &game_id, &player1, &player2, 30, Outcome::Player2);
+
+    let stats1 = client.get_stats(&game_id, &player1);
+    assert_eq!(stats1.games_played, 3);
+    assert_eq!(stats1.wins, 1);
+    assert_eq!(stats1.draws, 1);
+    assert_eq!(stats1.losses, 1);
+    assert_eq!(stats1.net_points, 70);
+
+    let stats2 = client.get_stats(&game_id, &player2);
+    assert_eq!(stats2.wins, 1);
+    assert_eq!(stats2.losses, 1);
+    assert_eq!(stats2.net_points, -70);
+    assert_eq!(client.get_balance(&player2), 930);
+}
+
+#[test]
+fn test_win_streaks() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    let stats = client.get_stats(&game_id, &player1);
+    assert_eq!(stats.current_streak, 3);
+    assert_eq!(stats.best_streak, 3);
+
+    // A draw breaks the streak but keeps the best
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Draw);
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    let stats = client.get_stats(&game_id, &player1);
+    assert_eq!(stats.current_streak, 1);
+    assert_eq!(stats.best_streak, 3);
+
+    let stats = client.get_stats(&game_id, &player2);
+    assert_eq!(stats.current_streak, 0);
+    assert_eq!(stats.best_streak, 0);
+}
+
+#[test]
+fn test_cancelled_and_expired_sessions_not_counted() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Cancelled);
+
+    client.start_game(&game_id, &player1, &player2, &10, &10);
+    let expires = client.get_session(&2).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+    client.claim_expired_session(&2, &player1);
+
+    assert_eq!(client.get_stats(&game_id, &player1), PlayerStats::default());
+    assert_eq!(client.get_player_stats(&player2), PlayerStats::default());
+    assert_eq!(
+        client.get_leaderboard(&game_id, &LeaderboardSort::Wins, &0, &10).len(),
+        0
+    );
+}
+
+#[test]
+fn test_player_stats_combine_games() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    play(&client, &game_id, &player1, &player2, 100, Outcome::Player1);
+    play(&client, &other_game, &player1, &player2, 40, Outcome::Player2);
+
+    assert_eq!(client.get_stats(&game_id, &player1).net_points, 100);
+    assert_eq!(client.get_stats(&other_game, &player1).net_points, -40);
+
+    let total = client.get_player_stats(&player1);
+    assert_eq!(total.games_played, 2);
+    assert_eq!(total.wins, 1);
+    assert_eq!(total.losses, 1);
+    assert_eq!(total.net_points, 60);
+}
+
+#[test]
+fn test_leaderboard_sorting() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1000);
+
+    // player1: 2 wins, +20; player2: 1 win, +300 overall; player3: 0 wins
+    play(&client, &game_id, &player1, &player3, 10, Outcome::Player1);
+    play(&client, &game_id, &player1, &player3, 10, Outcome::Player1);
+    play(&client, &game_id, &player2, &player1, 300, Outcome::Player1);
+
+    let by_wins = client.get_leaderboard(&game_id, &LeaderboardSort::Wins, &0, &10);
+    assert_eq!(by_wins.len(), 3);
+    assert_eq!(by_wins.get(0).unwrap().player, player1);
+    assert_eq!(by_wins.get(1).unwrap().player, player2);
+    assert_eq!(by_wins.get(2).unwrap().player, player3);
+
+    let by_points = client.get_leaderboard(&game_id, &LeaderboardSort::NetPoints, &0, &10);
+    assert_eq!(by_points.get(0).unwrap().player, player2);
+    assert_eq!(by_points.get(0).unwrap().stats.net_points, 300);
+    assert_eq!(by_points.get(1).unwrap().player, player3);
+    assert_eq!(by_points.get(2).unwrap().player, player1);
+}
+
+#[test]
+fn test_leaderboard_pagination() {
+    let (env, client, game_id, player1, _player2) = setup_test();
+
+    // player1 beats five newcomers; each newcomer has one loss
+    for _ in 0..5 {
+        let opponent = Address::generate(&env);
+        client.add_points(&opponent, &100);
+        play(&client, &game_id, &player1, &opponent, 10, Outcome::Player1);
+    }
+
+    let first = client.get_leaderboard(&game_id, &LeaderboardSort::Wins, &0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().player, player1);
+
+    let last = client.get_leaderboard(&game_id, &LeaderboardSort::Wins, &4, &10);
+    assert_eq!(last.len(), 2);
+
+    let past_end = client.get_leaderboard(&game_id, &LeaderboardSort::Wins, &6, &10);
+    assert_eq!(past_end.len(), 0);
+}
+
+#[test]
+fn test_game_ended_event_names_winner() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player2);
+
+    let expected = GameEnded {
+        session_id: 1,
+        game_id,
+        players: vec![&env, player1, player2.clone()],
+        outcome: Outcome::Player2,
+        winner: Some(player2),
+        token: None,
+        pot: 200,
+        fee: 0,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_events_carry_schema_version() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+
+    let events = env.events().all().filter_by_contract(&client.address);
+    let xdr::ContractEventBody::V0(body) = &events.events()[0].body;
+    let expected: [xdr::ScVal; 2] = [
+        xdr::ScVal::Symbol("game_started".try_into().unwrap()),
+        xdr::ScVal::Symbol("v1".try_into().unwrap()),
+    ];
+    assert_eq!(body.topics.as_slice(), &expected);
+}
+
+#[test]
+fn test_session_cancelled_event() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    client.end_game(&1, &Outcome::Cancelled);
+
+    let expected = SessionCancelled {
+        session_id: 1,
+        game_id,
+        players: vec![&env, player1, player2],
+        stakes: vec![&env, 100i128, 50],
+        token: None,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+}
+
+#[test]
+fn test_session_expired_event() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    let expires = client.get_session(&1).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+    client.claim_expired_session(&1, &player2);
+
+    let expected = SessionExpired {
+        session_id: 1,
+        game_id,
+        players: vec![&env, player1, player2.clone()],
+        stakes: vec![&env, 100i128, 50],
+        token: None,
+        claimed_by: player2,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [expected.to_xdr(&env, &client.address)]
+    );
+}
+
+// ============================================================================
+// Token Escrow Tests
+// ============================================================================
+
+/// Create a Stellar Asset Contract, make it `game_id`'s stake token and mint
+/// 1000 to each player
+fn setup_token<'a>(
+    env: &Env,
+    client: &MockGameHubClient,
+    game_id: &Address,
+    players: &[&Address],
+) -> TokenClient<'a> {
+    // Player transfers are authorized beneath start_game, as they would be
+    // beneath the game contract's own call in a real transaction
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    let asset = StellarAssetClient::new(env, &sac.address());
+    for player in players {
+        asset.mint(player, &1000);
+    }
+    client.set_game_token(game_id, &Some(sac.address()));
+    TokenClient::new(env, &sac.address())
+}
+
+#[test]
+fn test_token_stakes_are_escrowed() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player2), 950);
+    assert_eq!(token.balance(&client.address), 150);
+    assert_eq!(client.get_session(&1).token, Some(token.address));
+    // Hub points are untouched
+    assert_eq!(client.get_balance(&player1), 1000);
+}
+
+#[test]
+fn test_token_winner_receives_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    client.end_game(&1, &Outcome::Player2);
+
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player2), 1100);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_draw_and_expiry_refund_stakes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    client.end_game(&1, &Outcome::Draw);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(token.balance(&player2), 1000);
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    let expires = client.get_session(&2).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+    client.claim_expired_session(&2, &player2);
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(token.balance(&player2), 1000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_start_requires_player_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == player1));
+    assert!(auths.iter().any(|(addr, _)| *addr == player2));
+}
+
+#[test]
+#[should_panic]
+fn test_token_start_fails_when_player_short() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &1001, &50);
+}
+
+#[test]
+fn test_token_change_applies_to_new_sessions() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.set_game_token(&game_id, &None);
+
+    // The running session still settles in tokens
+    client.end_game(&1, &Outcome::Player1);
+    assert_eq!(token.balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player1), 1000);
+
+    // New sessions stake points again
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    assert_eq!(client.get_session(&2).token, None);
+    assert_eq!(client.get_balance(&player1), 900);
+}
+
+#[test]
+fn test_set_game_token_requires_registered_game() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let unknown_game = Address::generate(&env);
+
+    assert_hub_error(
+        &client.try_set_game_token(&unknown_game, &None),
+        Error::GameNotRegistered,
+    );
+}
+
+// ============================================================================
+// Fee Tests
+// ============================================================================
+
+/// Set a 5% fee with 20% going to a developer registered for `game_id`.
+/// Returns (treasury, developer).
+fn setup_fees(env: &Env, client: &MockGameHubClient, game_id: &Address) -> (Address, Address) {
+    let treasury = Address::generate(env);
+    let developer = Address::generate(env);
+    client.set_fee_config(&500, &2000, &treasury);
+    client.set_game_developer(game_id, &Some(developer.clone()));
+    (treasury, developer)
+}
+
+#[test]
+fn test_fee_split_between_treasury_and_developer() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    // 5% of 200 = 10: 2 to the developer, 8 to the treasury
+    assert_eq!(client.get_balance(&player1), 1090);
+    assert_eq!(client.get_fees(&treasury, &None), 8);
+    assert_eq!(client.get_fees(&developer, &None), 2);
+    assert_eq!(client.get_stats(&game_id, &player1).net_points, 90);
+}
+
+#[test]
+fn test_fee_collected_event() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player2);
+
+    let expected = FeeCollected {
+        session_id: 1,
+        game_id,
+        token: None,
+        treasury,
+        treasury_amount: 8,
+        developer: Some(developer),
+        developer_amount: 2,
+    };
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert_eq!(events.events()[0], expected.to_xdr(&env, &client.address));
+
+    // The game result reports the same fee
+    let ended = GameEnded {
+        session_id: 1,
+        game_id: client.get_session(&1).game_id,
+        players: vec![&env, player1, player2.clone()],
+        outcome: Outcome::Player2,
+        winner: Some(player2),
+        token: None,
+        pot: 200,
+        fee: 10,
+    };
+    assert_eq!(events.events()[1], ended.to_xdr(&env, &client.address));
+}
+
+#[test]
+fn test_treasury_takes_whole_fee_without_developer() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, developer) = setup_fees(&env, &client, &game_id);
+    client.set_game_developer(&game_id, &None);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    assert_eq!(client.get_fees(&treasury, &None), 10);
+    assert_eq!(client.get_fees(&developer, &None), 0);
+}
+
+#[test]
+fn test_no_fee_on_draw_or_cancel() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, _developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Draw);
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&2, &Outcome::Cancelled);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_fees(&treasury, &None), 0);
+}
+
+#[test]
+fn test_fee_rate_fixed_at_session_start() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, _developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.set_fee_config(&1000, &2000, &treasury);
+    client.end_game(&1, &Outcome::Player1);
+
+    assert_eq!(client.get_session(&1).fee_bps, 500);
+    assert_eq!(client.get_balance(&player1), 1090);
+}
+
+#[test]
+fn test_withdraw_point_fees() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+
+    assert_eq!(client.withdraw_fees(&treasury, &None), 8);
+    assert_eq!(client.withdraw_fees(&developer, &None), 2);
+    assert_eq!(client.get_balance(&treasury), 8);
+    assert_eq!(client.get_balance(&developer), 2);
+    assert_eq!(client.get_fees(&treasury, &None), 0);
+
+    assert_hub_error(
+        &client.try_withdraw_fees(&treasury, &None),
+        Error::NothingToWithdraw,
+    );
+}
+
+#[test]
+fn test_withdraw_token_fees() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2]);
+    let (treasury, developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+    assert_eq!(token.balance(&player1), 1090);
+    assert_eq!(token.balance(&client.address), 10);
+
+    let token_id = Some(token.address.clone());
+    assert_eq!(client.get_fees(&treasury, &None), 0);
+    assert_eq!(client.withdraw_fees(&treasury, &token_id), 8);
+    assert_eq!(client.withdraw_fees(&developer, &token_id), 2);
+    assert_eq!(token.balance(&treasury), 8);
+    assert_eq!(token.balance(&developer), 2);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_withdraw_fees_requires_recipient_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (treasury, _developer) = setup_fees(&env, &client, &game_id);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &Outcome::Player1);
+    client.withdraw_fees(&treasury, &None);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            treasury.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "withdraw_fees"),
+                    (treasury.clone(), None::<Address>).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+fn test_set_fee_config_validation() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let treasury = Address::generate(&env);
+
+    assert_hub_error(
+        &client.try_set_fee_config(&1001, &0, &treasury),
+        Error::InvalidFee,
+    );
+    assert_hub_error(
+        &client.try_set_fee_config(&500, &10_001, &treasury),
+        Error::InvalidFee,
+    );
+    assert_eq!(client.get_fee_config(), None);
+
+    let unknown_game = Address::generate(&env);
+    assert_hub_error(
+        &client.try_set_game_developer(&unknown_game, &None),
+        Error::GameNotRegistered,
+    );
+    client.set_game_developer(&game_id, &Some(treasury.clone()));
+    assert_eq!(client.get_game_config(&game_id).developer, Some(treasury));
+}
+
+// ============================================================================
+// Multi-Player Session Tests
+// ============================================================================
+
+/// Two extra funded players for table games
+fn extra_players(env: &Env, client: &MockGameHubClient) -> (Address, Address) {
+    let player3 = Address::generate(env);
+    let player4 = Address::generate(env);
+    client.add_points(&player3, &1000);
+    client.add_points(&player4, &1000);
+    (player3, player4)
+}
+
+#[test]
+fn test_start_game_multi_locks_every_stake() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let stakes = vec![&env, 100i128, 200, 300];
+    let session_id = client.start_game_multi(&game_id, &players, &stakes);
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 800);
+    assert_eq!(client.get_balance(&player3), 700);
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.players, players);
+    assert_eq!(session.stakes, stakes);
+    assert_eq!(session.status, SessionStatus::Active);
+}
+
+#[test]
+fn test_start_game_multi_validation() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+
+    let one = vec![&env, player1.clone()];
+    assert_hub_error(
+        &client.try_start_game_multi(&game_id, &one, &vec![&env, 100i128]),
+        Error::InvalidPlayerCount,
+    );
+
+    let mut nine = Vec::new(&env);
+    for _ in 0..9 {
+        nine.push_back(Address::generate(&env));
+    }
+    let stakes = Vec::from_array(&env, [0i128; 9]);
+    assert_hub_error(
+        &client.try_start_game_multi(&game_id, &nine, &stakes),
+        Error::InvalidPlayerCount,
+    );
+
+    let three = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    assert_hub_error(
+        &client.try_start_game_multi(&game_id, &three, &vec![&env, 100i128, 100]),
+        Error::StakeCountMismatch,
+    );
+    assert_hub_error(
+        &client.try_start_game_multi(&game_id, &three, &vec![&env, 100i128, -1, 100]),
+        Error::InvalidAmount,
+    );
+
+    let repeated = vec![&env, player1.clone(), player2.clone(), player1.clone()];
+    assert_hub_error(
+        &client.try_start_game_multi(&game_id, &repeated, &vec![&env, 100i128, 100, 100]),
+        Error::DuplicatePlayer,
+    );
+    assert_hub_error(
+        &client.try_start_game(&game_id, &player1, &player1, &100, &100),
+        Error::DuplicatePlayer,
+    );
+}
+
+#[test]
+fn test_end_game_multi_splits_pot_by_share() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let session_id = client.start_game_multi(&game_id, &players, &vec![&env, 100i128, 100, 100]);
+
+    let payouts = client.end_game_multi(&session_id, &vec![&env, 2u32, 1, 0]);
+
+    assert_eq!(payouts, vec![&env, 200i128, 100, 0]);
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_balance(&player3), 900);
+    assert_eq!(client.get_session(&session_id).status, SessionStatus::Settled);
+}
+
+#[test]
+fn test_end_game_multi_rounding_goes_to_top_share() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, player4) = extra_players(&env, &client);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone(), player4.clone()];
+    let session_id =
+        client.start_game_multi(&game_id, &players, &vec![&env, 100i128, 100, 100, 100]);
+
+    // 400 split three ways: 133 each with 1 left over for the first top share
+    let payouts = client.end_game_multi(&session_id, &vec![&env, 0u32, 1, 1, 1]);
+    assert_eq!(payouts, vec![&env, 0i128, 134, 133, 133]);
+}
+
+#[test]
+fn test_end_game_multi_equal_shares_refund() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+    let treasury = Address::generate(&env);
+    client.set_fee_config(&500, &0, &treasury);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let session_id = client.start_game_multi(&game_id, &players, &vec![&env, 50i128, 100, 150]);
+
+    let payouts = client.end_game_multi(&session_id, &vec![&env, 1u32, 1, 1]);
+
+    assert_eq!(payouts, vec![&env, 50i128, 100, 150]);
+    assert_eq!(client.get_balance(&player3), 1000);
+    assert_eq!(client.get_fees(&treasury, &None), 0);
+    assert_eq!(client.get_stats(&game_id, &player1).draws, 1);
+}
+
+#[test]
+fn test_end_game_multi_takes_fee() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+    let treasury = Address::generate(&env);
+    client.set_fee_config(&500, &0, &treasury);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let session_id = client.start_game_multi(&game_id, &players, &vec![&env, 100i128, 100, 100]);
+
+    // 5% of 300 = 15; the winner takes the remaining 285
+    let payouts = client.end_game_multi(&session_id, &vec![&env, 0u32, 0, 1]);
+    assert_eq!(payouts, vec![&env, 0i128, 0, 285]);
+    assert_eq!(client.get_fees(&treasury, &None), 15);
+}
+
+#[test]
+fn test_end_game_multi_rejects_bad_shares() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let session_id = client.start_game_multi(&game_id, &players, &vec![&env, 100i128, 100, 100]);
+
+    assert_hub_error(
+        &client.try_end_game_multi(&session_id, &vec![&env, 1u32, 0]),
+        Error::InvalidShares,
+    );
+    assert_hub_error(
+        &client.try_end_game_multi(&session_id, &vec![&env, 0u32, 0, 0]),
+        Error::InvalidShares,
+    );
+
+    client.end_game_multi(&session_id, &vec![&env, 1u32, 0, 0]);
+    assert_hub_error(
+        &client.try_end_game_multi(&session_id, &vec![&env, 1u32, 0, 0]),
+        Error::SessionNotActive,
+    );
+}
+
+#[test]
+fn test_end_game_multi_updates_ratings_and_stats() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let session_id = client.start_game_multi(&game_id, &players, &vec![&env, 100i128, 100, 100]);
+    client.end_game_multi(&session_id, &vec![&env, 1u32, 0, 0]);
+
+    // Winner beats both (+16 each, averaged); losers draw with each other
+    assert_eq!(client.get_rating(&game_id, &player1), 1216);
+    assert_eq!(client.get_rating(&game_id, &player2), 1192);
+    assert_eq!(client.get_rating(&game_id, &player3), 1192);
+
+    let winner = client.get_stats(&game_id, &player1);
+    assert_eq!(winner.wins, 1);
+    assert_eq!(winner.net_points, 200);
+    let loser = client.get_stats(&game_id, &player3);
+    assert_eq!(loser.losses, 1);
+    assert_eq!(loser.net_points, -100);
+}
+
+#[test]
+fn test_multi_session_cancel_and_expiry() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    let stakes = vec![&env, 100i128, 100, 100];
+
+    let cancelled = client.start_game_multi(&game_id, &players, &stakes);
+    client.end_game(&cancelled, &Outcome::Cancelled);
+    assert_eq!(client.get_balance(&player3), 1000);
+
+    let expired = client.start_game_multi(&game_id, &players, &stakes);
+    let expires = client.get_session(&expired).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+
+    // Any seated player can reclaim, not just the first two
+    client.claim_expired_session(&expired, &player3);
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_balance(&player3), 1000);
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+/// Start a season ending at ledger 100, ranked by wins, paying 70/30 in hub points
+fn start_season(env: &Env, client: &MockGameHubClient) -> u32 {
+    client.start_season(&100, &LeaderboardSort::Wins, &None, &vec![env, 7000u32, 3000])
+}
+
+#[test]
+fn test_start_season_validation() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    env.ledger().set_sequence_number(50);
+
+    let split = vec![&env, 10_000u32];
+    assert_hub_error(
+        &client.try_start_season(&50, &LeaderboardSort::Wins, &None, &split),
+        Error::InvalidSeason,
+    );
+    assert_hub_error(
+        &client.try_start_season(&100, &LeaderboardSort::Wins, &None, &vec![&env, 5000u32]),
+        Error::InvalidSeason,
+    );
+    assert_hub_error(
+        &client.try_start_season(&100, &LeaderboardSort::Wins, &None, &Vec::new(&env)),
+        Error::InvalidSeason,
+    );
+
+    assert_eq!(client.get_current_season(), None);
+    assert_eq!(client.start_season(&100, &LeaderboardSort::Wins, &None, &split), 1);
+    assert_eq!(client.get_current_season(), Some(1));
+
+    let season = client.get_season(&1);
+    assert_eq!(season.start_ledger, 50);
+    assert_eq!(season.end_ledger, 100);
+    assert_eq!(season.status, SeasonStatus::Active);
+
+    assert_hub_error(
+        &client.try_start_season(&200, &LeaderboardSort::Wins, &None, &split),
+        Error::SeasonActive,
+    );
+    assert_hub_error(&client.try_get_season(&2), Error::SeasonNotFound);
+}
+
+#[test]
+fn test_season_records_stats_and_ratings() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    // Played before the season: counts toward all-time results only
+    play(&client, &game_id, &player1, &player2, 100, Outcome::Player1);
+    start_season(&env, &client);
+    play(&client, &game_id, &player1, &player2, 100, Outcome::Player2);
+
+    let season_stats = client.get_season_stats(&1, &player2);
+    assert_eq!(season_stats.games_played, 1);
+    assert_eq!(season_stats.wins, 1);
+    assert_eq!(season_stats.net_points, 100);
+    assert_eq!(client.get_player_stats(&player2).games_played, 2);
+
+    // Season ratings start from the initial rating
+    assert_eq!(client.get_season_rating(&1, &game_id, &player2), 1216);
+    assert_eq!(client.get_season_rating(&1, &game_id, &player1), 1184);
+    // All-time: 1184 after the first loss, then an upset win
+    assert_eq!(client.get_rating(&game_id, &player2), 1201);
+}
+
+#[test]
+fn test_results_after_end_ledger_not_counted() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    start_season(&env, &client);
+
+    env.ledger().set_sequence_number(101);
+    play(&client, &game_id, &player1, &player2, 100, Outcome::Player1);
+
+    assert_eq!(client.get_season_stats(&1, &player1), PlayerStats::default());
+    assert_eq!(client.get_season_rating(&1, &game_id, &player1), 1200);
+    assert_eq!(client.get_stats(&game_id, &player1).wins, 1);
+}
+
+#[test]
+fn test_close_season_archives_standings_and_pays_rewards() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let (player3, _player4) = extra_players(&env, &client);
+    let admin = client.get_admin();
+    client.add_points(&admin, &1000);
+
+    start_season(&env, &client);
+    client.fund_season(&admin, &1000);
+    assert_eq!(client.get_balance(&admin), 0);
+    assert_eq!(client.get_season(&1).reward_pot, 1000);
+
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    play(&client, &game_id, &player3, &player2, 10, Outcome::Player1);
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+
+    assert_hub_error(&client.try_close_season(), Error::SeasonNotOver);
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+
+    let standings = client.get_season_standings(&1, &0, &10);
+    assert_eq!(standings.len(), 3);
+    assert_eq!(standings.get(0).unwrap().player, player1);
+    assert_eq!(standings.get(0).unwrap().stats.wins, 2);
+    assert_eq!(standings.get(1).unwrap().player, player3);
+    assert_eq!(standings.get(2).unwrap().player, player2);
+
+    assert_eq!(client.get_balance(&player1), 1000 + 20 + 700);
+    assert_eq!(client.get_balance(&player3), 1000 + 10 + 300);
+    assert_eq!(client.get_balance(&player2), 1000 - 30);
+    assert_eq!(client.get_season(&1).status, SeasonStatus::Closed);
+    assert_hub_error(&client.try_close_season(), Error::NoActiveSeason);
+}
+
+#[test]
+fn test_past_seasons_stay_queryable() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    start_season(&env, &client);
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+
+    assert_eq!(
+        client.start_season(&200, &LeaderboardSort::Wins, &None, &vec![&env, 10_000u32]),
+        2
+    );
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player2);
+
+    // The new season starts from empty standings
+    let current = client.get_season_standings(&2, &0, &10);
+    assert_eq!(current.get(0).unwrap().player, player2);
+    assert_eq!(client.get_season_stats(&2, &player1).losses, 1);
+
+    // Season 1 keeps its archive
+    let past = client.get_season_standings(&1, &0, &10);
+    assert_eq!(past.get(0).unwrap().player, player1);
+    assert_eq!(client.get_season_stats(&1, &player1).wins, 1);
+    assert_eq!(client.get_season_rating(&1, &game_id, &player1), 1216);
+    assert_hub_error(&client.try_get_season_standings(&3, &0, &10), Error::SeasonNotFound);
+}
+
+#[test]
+fn test_season_with_many_players_closes() {
+    let (env, client, game_id, _player1, player2) = setup_test();
+    let admin = client.get_admin();
+    client.add_points(&admin, &1000);
+    client.add_points(&player2, &10_000);
+
+    start_season(&env, &client);
+    client.fund_season(&admin, &1000);
+
+    // 120 newcomers each beat player2 once; later newcomers win bigger stakes
+    let mut newcomers: std::vec::Vec<Address> = std::vec::Vec::new();
+    for stake in 1..=120 {
+        let newcomer = Address::generate(&env);
+        client.add_points(&newcomer, &stake);
+        play(
+            &client,
+            &game_id,
+            &newcomer,
+            &player2,
+            stake,
+            Outcome::Player1,
+        );
+        newcomers.push(newcomer);
+    }
+
+    // The leaderboard only keeps the top 100
+    let top = client.get_leaderboard(&game_id, &LeaderboardSort::NetPoints, &0, &50);
+    assert_eq!(top.get(0).unwrap().player, newcomers[119]);
+    assert_eq!(top.get(49).unwrap().player, newcomers[70]);
+    let tail = client.get_leaderboard(&game_id, &LeaderboardSort::NetPoints, &50, &50);
+    assert_eq!(tail.len(), 50);
+    assert_eq!(tail.get(49).unwrap().player, newcomers[20]);
+    assert_eq!(
+        client
+            .get_leaderboard(&game_id, &LeaderboardSort::NetPoints, &100, &50)
+            .len(),
+        0
+    );
+
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+    let closed = SeasonClosed {
+        season: 1,
+        players: 121,
+        rewards_paid: 1000,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [closed.to_xdr(&env, &client.address)]
+    );
+
+    let standings = client.get_season_standings(&1, &0, &50);
+    assert_eq!(standings.get(0).unwrap().player, newcomers[119]);
+    assert_eq!(standings.get(1).unwrap().player, newcomers[118]);
+    assert_eq!(client.get_season_standings(&1, &50, &50).len(), 50);
+    assert_eq!(client.get_season_standings(&1, &100, &50).len(), 0);
+    assert_eq!(client.get_balance(&newcomers[119]), 240 + 700);
+    assert_eq!(client.get_balance(&newcomers[118]), 238 + 300);
+}
+
+#[test]
+fn test_token_reward_pot_returns_unpaid_places_to_admin() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.mock_all_auths_allowing_non_root_auth();
+    let admin = client.get_admin();
+    let funder = Address::generate(&env);
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    StellarAssetClient::new(&env, &sac.address()).mint(&funder, &1000);
+    let token = TokenClient::new(&env, &sac.address());
+
+    client.start_season(
+        &100,
+        &LeaderboardSort::Wins,
+        &Some(sac.address()),
+        &vec![&env, 5000u32, 3000, 2000],
+    );
+    client.fund_season(&funder, &1000);
+    assert_eq!(token.balance(&client.address), 1000);
+
+    play(&client, &game_id, &player1, &player2, 10, Outcome::Player1);
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+
+    // Only two places were filled, so the 3rd place share goes back to the admin
+    assert_eq!(token.balance(&player1), 500);
+    assert_eq!(token.balance(&player2), 300);
+    assert_eq!(token.balance(&admin), 200);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_fund_season_requires_active_season() {
+    let (env, client, _game_id, player1, _player2) = setup_test();
+
+    assert_hub_error(&client.try_fund_season(&player1, &100), Error::NoActiveSeason);
+
+    start_season(&env, &client);
+    assert_hub_error(&client.try_fund_season(&player1, &0), Error::InvalidAmount);
+    assert_hub_error(&client.try_fund_season(&player1, &2000), Error::InsufficientBalance);
+
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+    assert_hub_error(&client.try_fund_season(&player1, &100), Error::NoActiveSeason);
+}
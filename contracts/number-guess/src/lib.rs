@@ -4,35 +4,31 @@
 //!
 //! A simple two-player guessing game where players guess a number between 1 and 10.
 //! The player whose guess is closest to the randomly generated number wins.
+//! Equal distances are a draw and both stakes are returned by the Game Hub.
+//!
+//! **Sealed Guesses:**
+//! Each player submits `keccak256(session_id || player || guess || salt)`,
+//! with the session ID and guess as 4 big-endian bytes, the player as its XDR
+//! encoding and a 32-byte salt of their choosing, then reveals the guess and
+//! salt once both have guessed. A commitment only opens for the player and
+//! session it was made for, so copying the opponent's commitment cannot force
+//! a draw. Sealed guesses and reveals are
+//! passed on to the randomness beacon, whose output seeds the winning number,
+//! so nobody can know it, or the other player's guess, while guessing. A
+//! player who does not reveal within the reveal window forfeits the game
+//! (`claim_forfeit`).
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror, contractimpl,
+    contracttype, vec
 };
-
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+use soroban_sdk::xdr::ToXdr;
+use sgs_common::admin::{self, Role};
+use sgs_common::{beacon, hub, schema, ttl, Outcome};
 
 // ============================================================================
 // Errors
@@ -47,6 +43,13 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    SessionAlreadyExists = 6,
+    InvalidReveal = 7,
+    AlreadyRevealed = 8,
+    RevealWindowClosed = 9,
+    RevealWindowOpen = 10,
+    SessionExpiring = 11,
+    InvalidGuess = 12,
 }
 
 // ============================================================================
@@ -60,29 +63,131 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    /// `keccak256(session_id || player || guess || salt)` sealed by player 1
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Player 1's guess, once revealed
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
+    /// Player 1's salt, once revealed
+    pub player1_salt: Option<BytesN<32>>,
+    pub player2_salt: Option<BytesN<32>>,
+    /// Last ledger on which guesses can be revealed, set once both players have guessed
+    pub reveal_deadline: Option<u32>,
+    /// Beacon output the winning number was drawn from
+    pub seed: Option<BytesN<32>>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    pub is_draw: bool,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Storage Schema
+// ============================================================================
+// Version 1: the original layout
+// Version 2: `Game` gains `is_draw`; guesses are sealed with commitments and
+//            salts, and the winning number comes from the randomness beacon
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 2;
+
+/// `Game` as stored at schema version 1
+#[contracttype(export = false)]
+#[derive(Clone)]
+struct GameV1 {
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    player1_guess: Option<u32>,
+    player2_guess: Option<u32>,
+    winning_number: Option<u32>,
+    winner: Option<Address>,
+}
+
+impl From<GameV1> for Game {
+    fn from(old: GameV1) -> Self {
+        // Version 1 guesses were stored in plain view, so unfinished games go
+        // back to the guess phase; finished games keep their guesses and
+        // result. Version 1 had no draws; ties went to player 1.
+        let finished = old.winner.is_some();
+        Game {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_guess: old.player1_guess.filter(|_| finished),
+            player2_guess: old.player2_guess.filter(|_| finished),
+            player1_salt: None,
+            player2_salt: None,
+            reveal_deadline: None,
+            seed: None,
+            winning_number: old.winning_number,
+            winner: old.winner,
+            is_draw: false,
+        }
+    }
+}
+
+// ============================================================================
+// Constants
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Ledgers both players have to reveal once both have guessed (~1 hour)
+const REVEAL_WINDOW_LEDGERS: u32 = 720;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// The sealed form of a guess: `session_id || player || guess || salt`, with
+/// the session ID and guess as 4 big-endian bytes and the player as its XDR
+/// `ScVal` encoding. Its hash is the player's commitment and it is their
+/// secret for the beacon.
+fn seal_guess(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    guess: u32,
+    salt: &BytesN<32>,
+) -> Bytes {
+    let mut sealed = Bytes::from_array(env, &session_id.to_be_bytes());
+    sealed.append(&player.clone().to_xdr(env));
+    sealed.append(&Bytes::from_array(env, &guess.to_be_bytes()));
+    sealed.append(&Bytes::from(salt.clone()));
+    sealed
+}
+
+/// Record the result of a game and end its hub session
+fn settle(env: &Env, session_id: u32, game: &mut Game, outcome: Outcome) -> Option<Address> {
+    game.winner = match outcome {
+        Outcome::Player1 => Some(game.player1.clone()),
+        Outcome::Player2 => Some(game.player2.clone()),
+        Outcome::Draw | Outcome::Cancelled => None,
+    };
+    game.is_draw = outcome == Outcome::Draw;
+    env.storage()
+        .temporary()
+        .set(&DataKey::Game(session_id), game);
+
+    // Call GameHub to end the session
+    // This unlocks points and updates standings
+    // Event emitted by the Game Hub contract (GameEnded)
+    // Games started before the hub recorded sessions have nothing to settle
+    if hub::find_session(env, session_id).is_some() {
+        hub::client(env).end_game(&session_id, &outcome);
+    }
+
+    game.winner.clone()
+}
 
 // ============================================================================
 // Contract Definition
@@ -99,11 +204,8 @@ impl NumberGuessContract {
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
+        schema::init(&env, SCHEMA_VERSION);
     }
 
     /// Start a new game between two players with points.
@@ -113,90 +215,100 @@ impl NumberGuessContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    ///
+    /// # Returns
+    /// * `u32` - Session ID allocated by the Game Hub
     pub fn start_game(
         env: Env,
-        session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Require authentication from both players (each consents to the opponent and their stake)
+        player1.require_auth_for_args(vec![&env, player2.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player1.into_val(&env), player2_points.into_val(&env)]);
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub::client(&env);
 
         // Call Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
+        let session_id = game_hub.start_game(
             &env.current_contract_address(),
-            &session_id,
             &player1,
             &player2,
             &player1_points,
             &player2_points,
         );
 
-        // Create game (winning_number not set yet - will be generated in reveal_winner)
+        // The hub never reissues an ID, but never overwrite a game in progress
+        let game_key = DataKey::Game(session_id);
+        if env.storage().temporary().has(&game_key) {
+            return Err(Error::SessionAlreadyExists);
+        }
+
+        // Both players seal their guesses with the beacon, which seeds the
+        // winning number once both reveal
+        beacon::request_session(&env, session_id, &player1, &player2, REVEAL_WINDOW_LEDGERS);
+
+        // Create game (winning_number not set yet - derived once both guesses are revealed)
         let game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
+            player1_commitment: None,
+            player2_commitment: None,
             player1_guess: None,
             player2_guess: None,
+            player1_salt: None,
+            player2_salt: None,
+            reveal_deadline: None,
+            seed: None,
             winning_number: None,
             winner: None,
+            is_draw: false,
         };
 
         // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
 
         // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        ttl::extend_temporary(&env, &game_key);
 
         // Event emitted by the Game Hub contract (GameStarted)
 
-        Ok(())
+        Ok(session_id)
     }
 
-    /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Seal a guess for the current game.
+    /// The guess stays hidden until both players have guessed and reveal it
+    /// with `reveal_guess`. The sealed guess cannot be checked here, so the
+    /// 1-10 range is enforced at reveal: a player who sealed a guess outside
+    /// it cannot reveal, and loses to `claim_forfeit` once the window closes.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player making the guess
-    /// * `guess` - The guessed number (1-10)
-    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(session_id || player || guess || salt)` for a guess
+    ///   between 1 and 10
+    pub fn make_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
-        // Validate guess is in range
-        if guess < 1 || guess > 10 {
-            panic!("Guess must be between 1 and 10");
-        }
-
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -205,25 +317,47 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.is_draw {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update guess for the appropriate player
+        // The reveal window must close before the hub session expires, or a
+        // player could reclaim their stake from the hub instead of revealing
+        let reveal_deadline = env
+            .ledger()
+            .sequence()
+            .saturating_add(REVEAL_WINDOW_LEDGERS);
+        let session = hub::find_session(&env, session_id);
+        if session.is_some_and(|session| reveal_deadline >= session.expires_ledger) {
+            return Err(Error::SessionExpiring);
+        }
+
+        // Record the commitment for the appropriate player
         if player == game.player1 {
-            if game.player1_guess.is_some() {
+            if game.player1_commitment.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.player1_guess = Some(guess);
+            game.player1_commitment = Some(commitment.clone());
         } else if player == game.player2 {
-            if game.player2_guess.is_some() {
+            if game.player2_commitment.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.player2_guess = Some(guess);
+            game.player2_commitment = Some(commitment.clone());
         } else {
             return Err(Error::NotPlayer);
         }
+        beacon::client(&env).commit(
+            &env.current_contract_address(),
+            &session_id,
+            &player,
+            &commitment,
+        );
+
+        // The reveal window opens once both players have guessed
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.reveal_deadline = Some(reveal_deadline);
+        }
 
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
@@ -233,17 +367,30 @@ impl NumberGuessContract {
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have made their guesses.
-    /// This generates the winning number, determines the winner, and ends the session.
+    /// Reveal a sealed guess.
+    /// Can only be called after both players have guessed and before the
+    /// reveal deadline. The second reveal generates the winning number,
+    /// determines the winner and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    ///
-    /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
-        // Get game from temporary storage
+    /// * `player` - Address of the revealing player
+    /// * `guess` - The guessed number (1-10)
+    /// * `salt` - The salt sealed with the guess in `make_guess`
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Validate guess is in range
+        if !(1..=10).contains(&guess) {
+            return Err(Error::InvalidGuess);
+        }
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -251,88 +398,114 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotGuessed)?;
+        if env.ledger().sequence() > deadline {
+            return Err(Error::RevealWindowClosed);
         }
 
-        // Check both players have guessed
-        let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
-        let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
-
-        // Generate random winning number between 1 and 10 using seeded PRNG
-        // This is done AFTER both players have committed their guesses
-        //
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game, same between simulation and submission
-        // 2. Player addresses - both players contribute, same between sim/submit
-        // 3. Guesses - committed before reveal, same between sim/submit
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        //
-        // This ensures:
-        // - Same result between simulation and submission (fully deterministic)
-        // - Cannot be easily gamed (both players contribute to randomness)
-
-        // Build seed more efficiently using native arrays where possible
-        // Total: 12 bytes of fixed data (session_id + 2 guesses)
-        let mut fixed_data = [0u8; 12];
-        fixed_data[0..4].copy_from_slice(&session_id.to_be_bytes());
-        fixed_data[4..8].copy_from_slice(&guess1.to_be_bytes());
-        fixed_data[8..12].copy_from_slice(&guess2.to_be_bytes());
-
-        // Only use Bytes for the final concatenation with player addresses
-        let mut seed_bytes = Bytes::from_array(&env, &fixed_data);
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-
-        let seed = env.crypto().keccak256(&seed_bytes);
-        env.prng().seed(seed.into());
-        let winning_number = env.prng().gen_range::<u64>(1..=10) as u32;
-        game.winning_number = Some(winning_number);
-
-        // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
+        let (commitment, revealed_guess, revealed_salt) = if player == game.player1 {
+            (
+                &game.player1_commitment,
+                &mut game.player1_guess,
+                &mut game.player1_salt,
+            )
+        } else if player == game.player2 {
+            (
+                &game.player2_commitment,
+                &mut game.player2_guess,
+                &mut game.player2_salt,
+            )
         } else {
-            winning_number - guess1
+            return Err(Error::NotPlayer);
         };
+        if revealed_salt.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        let sealed = seal_guess(&env, session_id, &player, guess, &salt);
+        let hash: BytesN<32> = env.crypto().keccak256(&sealed).into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed_guess = Some(guess);
+        *revealed_salt = Some(salt);
 
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
+        let beacon = beacon::client(&env);
+        beacon.reveal(
+            &env.current_contract_address(),
+            &session_id,
+            &player,
+            &sealed,
+        );
+
+        // Wait for the other player's reveal
+        let (Some(guess1), Some(guess2)) = (game.player1_guess, game.player2_guess) else {
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
         };
 
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
-            game.player1.clone()
+        // Both reveals are in, so the beacon has finalized the seed from both
+        // salts. Each salt stayed sealed until both guesses were in, so nobody
+        // could predict the number while guessing.
+        let seed = beacon
+            .get_output(&env.current_contract_address(), &session_id)
+            .expect("Beacon output not finalized");
+        game.seed = Some(seed.clone());
+        env.prng().seed(seed.into());
+        let winning_number = env.prng().gen_range::<u64>(1..=10) as u32;
+        game.winning_number = Some(winning_number);
+
+        // Calculate distances
+        let distance1 = guess1.abs_diff(winning_number);
+        let distance2 = guess2.abs_diff(winning_number);
+
+        // Determine winner (equal distance is a draw)
+        let outcome = if distance1 < distance2 {
+            Outcome::Player1
+        } else if distance2 < distance1 {
+            Outcome::Player2
         } else {
-            game.player2.clone()
+            Outcome::Draw
         };
+        settle(&env, session_id, &mut game, outcome);
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+        Ok(())
+    }
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
+    /// End a game whose reveal deadline has passed without both reveals.
+    /// A player who revealed wins against one who did not; if neither
+    /// revealed, the game is a draw. Anyone can call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn claim_forfeit(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Call GameHub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        // Before both players guess, the hub's session deadline refunds the stakes
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotGuessed)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::RevealWindowOpen);
+        }
 
-        Ok(winner)
+        let outcome = match (game.player1_salt.is_some(), game.player2_salt.is_some()) {
+            (true, false) => Outcome::Player1,
+            (false, true) => Outcome::Player2,
+            _ => Outcome::Draw,
+        };
+        Ok(settle(&env, session_id, &mut game, outcome))
     }
 
     /// Get game information.
@@ -351,78 +524,69 @@ impl NumberGuessContract {
     }
 
     // ========================================================================
-    // Admin Functions
+    // Schema Migration
     // ========================================================================
 
-    /// Get the current admin address
+    /// Get the schema version of the stored data
     ///
     /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+    /// * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
     }
 
-    /// Set a new admin address
+    /// Convert games stored by an earlier build to the current layout (admin or upgrader).
+    /// Run after `upgrade`, once the beacon is set. Missing or already converted
+    /// games are skipped, so a large backlog can be migrated in batches; the
+    /// version only moves once the last batch is sent. Those builds chose their
+    /// own session IDs and the hub kept no record of them, so their games play
+    /// on without a hub session and end without settling anything at the hub.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
+    /// * `caller` - The admin or an account holding `Upgrader`
+    /// * `session_ids` - Session IDs of in-flight games
+    /// * `complete` - True on the last batch; records this build's schema version
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
-    }
-
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// * `u32` - Number of games converted
+    pub fn migrate(env: Env, caller: Address, session_ids: Vec<u32>, complete: bool) -> u32 {
+        admin::require_role(&env, Role::Upgrader, &caller);
+
+        let mut converted = 0;
+        for session_id in session_ids.iter() {
+            let key = DataKey::Game(session_id);
+            if schema::migrate_temporary(&env, &key, "seed", |old: GameV1| Game::from(old)) {
+                converted += 1;
+
+                // Games sent back to the guess phase seal through the beacon
+                let game: Game = env.storage().temporary().get(&key).unwrap();
+                if game.winner.is_none() && !game.is_draw {
+                    beacon::request_session(
+                        &env,
+                        session_id,
+                        &game.player1,
+                        &game.player2,
+                        REVEAL_WINDOW_LEDGERS,
+                    );
+                }
+            }
+        }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if complete {
+            schema::write_version(&env, SCHEMA_VERSION);
+        }
+        converted
     }
+}
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+// ============================================================================
+// Admin Functions
+// ============================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-}
+sgs_common::impl_admin!(NumberGuessContract);
+sgs_common::impl_hub_config!(NumberGuessContract);
+sgs_common::impl_beacon_config!(NumberGuessContract);
+sgs_common::impl_upgrade!(NumberGuessContract);
 
 // ============================================================================
 // Tests
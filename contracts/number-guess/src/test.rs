@@ -6,9 +6,13 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{DataKey, Error, GameV1, NumberGuessContract, NumberGuessContractClient, Outcome};
+use randomness::{RandomnessContract, RandomnessContractClient};
+use sgs_common::admin::CommonKey;
+use sgs_common::{Session, SessionStatus};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Val};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -20,24 +24,66 @@ pub struct MockGameHub;
 #[contractimpl]
 impl MockGameHub {
     pub fn start_game(
-        _env: Env,
+        env: Env,
         _game_id: Address,
-        _session_id: u32,
         _player1: Address,
         _player2: Address,
         _player1_points: i128,
         _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
+    ) -> u32 {
+        // Allocate sequential session IDs like the real hub
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("next_id"))
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &(session_id + 1));
+        session_id
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    /// Test hook: make the hub hand out an ID it has already issued
+    pub fn set_next_session_id(env: Env, session_id: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &session_id);
+    }
+
+    pub fn get_session(env: Env, _session_id: u32) -> Session {
+        Session {
+            game_id: env.storage().instance().get(&symbol_short!("game")).unwrap(),
+            players: vec![&env],
+            stakes: vec![&env],
+            status: SessionStatus::Active,
+            start_ledger: 0,
+            expires_ledger: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("expires"))
+                .unwrap_or(u32::MAX),
+            token: None,
+            fee_bps: 0,
+        }
+    }
+
+    /// Test hook: make every session expire after `ledger`
+    pub fn set_expires_ledger(env: Env, ledger: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("expires"), &ledger);
     }
 
-    pub fn add_game(_env: Env, _game_address: Address) {
+    pub fn end_game(_env: Env, _session_id: u32, _outcome: Outcome) {
         // Mock implementation - does nothing
     }
+
+    pub fn add_game(env: Env, game_address: Address) {
+        // Sessions are reported as the game's own
+        env.storage()
+            .instance()
+            .set(&symbol_short!("game"), &game_address);
+    }
 }
 
 // ============================================================================
@@ -77,6 +123,10 @@ fn setup_test() -> (
     let contract_id = env.register(NumberGuessContract, (&admin, &hub_addr));
     let client = NumberGuessContractClient::new(&env, &contract_id);
 
+    // Deploy the randomness beacon the winning number is drawn from
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
     // Register number-guess as a whitelisted game (mock does nothing)
     game_hub.add_game(&contract_id);
 
@@ -86,6 +136,23 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Point the game at a real Game Hub with 1000 points for each player
+fn use_real_hub(
+    env: &Env,
+    client: &NumberGuessContractClient<'static>,
+    player1: &Address,
+    player2: &Address,
+) -> mock_game_hub::MockGameHubClient<'static> {
+    let admin = client.get_admin();
+    let hub_addr = env.register(mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(env, &hub_addr);
+    hub.add_game(&client.address);
+    hub.add_points(player1, &1000);
+    hub.add_points(player2, &1000);
+    client.set_hub(&admin, &hub_addr);
+    hub
+}
+
 /// Assert that a Result contains a specific number_guess error
 ///
 /// This helper provides type-safe error assertions following Stellar/Soroban best practices.
@@ -96,7 +163,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
+/// let result = client.try_make_guess(&session_id, &player, &commitment);
 /// assert_number_guess_error(&result, Error::AlreadyGuessed);
 /// ```
 ///
@@ -139,64 +206,111 @@ fn assert_number_guess_error<T, E>(
     }
 }
 
+/// A player's salt and the commitment sealing their guess with it
+fn sealed_guess(
+    env: &Env,
+    session_id: u32,
+    player: &Address,
+    guess: u32,
+    seed: u8,
+) -> (BytesN<32>, BytesN<32>) {
+    let salt = BytesN::from_array(env, &[seed; 32]);
+    let mut sealed = Bytes::from_array(env, &session_id.to_be_bytes());
+    sealed.append(&player.clone().to_xdr(env));
+    sealed.append(&Bytes::from_array(env, &guess.to_be_bytes()));
+    sealed.append(&Bytes::from(salt.clone()));
+    (salt, env.crypto().keccak256(&sealed).into())
+}
+
+/// Seal and reveal both guesses, which ends the game
+fn play_out(
+    env: &Env,
+    client: &NumberGuessContractClient<'static>,
+    session_id: u32,
+    (player1, guess1): (&Address, u32),
+    (player2, guess2): (&Address, u32),
+) -> Option<Address> {
+    let (salt1, commitment1) = sealed_guess(env, session_id, player1, guess1, 1);
+    let (salt2, commitment2) = sealed_guess(env, session_id, player2, guess2, 2);
+    client.make_guess(&session_id, player1, &commitment1);
+    client.make_guess(&session_id, player2, &commitment2);
+    client.reveal_guess(&session_id, player1, &guess1, &salt1);
+    client.reveal_guess(&session_id, player2, &guess2, &salt2);
+    client.get_game(&session_id).winner
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
 
     // Get game to verify state
     let game = client.get_game(&session_id);
     assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
+    assert!(game.winner.is_none() && !game.is_draw); // Game is still active
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Seal guesses
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+    let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 7, 2);
+    client.make_guess(&session_id, &player1, &commitment1);
+    client.make_guess(&session_id, &player2, &commitment2);
+    assert_eq!(client.get_game(&session_id).reveal_deadline, Some(100 + 720));
+
+    // One reveal is not enough to pick the winning number
+    client.reveal_guess(&session_id, &player2, &7, &salt2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player2_guess, Some(7));
+    assert!(game.winning_number.is_none() && game.winner.is_none());
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // The second reveal picks the winning number and ends the game
+    client.reveal_guess(&session_id, &player1, &5, &salt1);
 
     // Verify game is ended and winning number is now set
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
-    assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
+
+    // Closest guess wins, equal distances are a draw
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(7);
+    let expected_winner = if distance1 < distance2 {
+        Some(player1)
+    } else if distance2 < distance1 {
+        Some(player2)
+    } else {
+        None
+    };
+    assert_eq!(final_game.winner, expected_winner);
+    assert_eq!(final_game.is_draw, distance1 == distance2);
 }
 
 #[test]
 fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
-    client.reveal_winner(&session_id);
+    // Seal and reveal guesses to generate winning number
+    play_out(&env, &client, session_id, (&player1, 5), (&player2, 7));
 
     let game = client.get_game(&session_id);
     let winning_number = game
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -207,11 +321,9 @@ fn test_multiple_sessions() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    let session1 = 3u32;
-    let session2 = 4u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    let session1 = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let session2 = client.start_game(&player3, &player4, &50_0000000, &50_0000000);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -227,93 +339,114 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Player1 guesses closer (1 away from any number between 1-10)
-    // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
-    let winner = client.reveal_winner(&session_id);
+    // Player1 guesses the middle, player2 the edge; distances never tie
+    let winner = play_out(&env, &client, session_id, (&player1, 5), (&player2, 10));
 
     // Get the final game state to check the winning number
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = winning_number.abs_diff(5);
+    let distance2 = winning_number.abs_diff(10);
 
-    let expected_winner = if distance1 <= distance2 {
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
     };
     assert_eq!(
-        winner, expected_winner,
+        winner,
+        Some(expected_winner),
         "Player with closer guess should win"
     );
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_tie_game_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    let winner = play_out(&env, &client, session_id, (&player1, 5), (&player2, 5));
+    assert_eq!(winner, None, "A tie should have no winner");
 
-    let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    let game = client.get_game(&session_id);
+    assert!(game.is_draw);
 }
 
 #[test]
-fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+fn test_guesses_stay_sealed_until_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Player1 guesses 5 (middle), player2 guesses 10 (edge)
-    // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_salt1, commitment1) = sealed_guess(&env, session_id, &player1, 3, 1);
+    client.make_guess(&session_id, &player1, &commitment1);
 
-    let winner = client.reveal_winner(&session_id);
+    // Only the commitment is stored, never the guess
     let game = client.get_game(&session_id);
-    let winning_number = game.winning_number.unwrap();
+    assert_eq!(game.player1_commitment, Some(commitment1));
+    assert!(game.player1_guess.is_none() && game.player1_salt.is_none());
+    assert!(game.reveal_deadline.is_none());
+}
 
-    // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
-        player1.clone()
-    } else {
-        player2.clone()
-    };
-    assert_eq!(winner, expected_winner);
+#[test]
+fn test_winning_number_comes_from_both_salts() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    // The same session, players and guesses give different numbers for different salts
+    let mut numbers = vec![&env];
+    for seed in 2..8u8 {
+        let session_id = client.start_game(&player1, &player2, &0, &0);
+        let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+        let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 5, seed);
+        client.make_guess(&session_id, &player1, &commitment1);
+        client.make_guess(&session_id, &player2, &commitment2);
+        client.reveal_guess(&session_id, &player1, &5, &salt1);
+        client.reveal_guess(&session_id, &player2, &5, &salt2);
+
+        numbers.push_back(client.get_game(&session_id).winning_number.unwrap());
+    }
+    let first = numbers.get_unchecked(0);
+    assert!(numbers.iter().any(|number| number != first));
+}
+
+#[test]
+fn test_winning_number_comes_from_beacon_output() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+
+    let session_id = client.start_game(&player1, &player2, &0, &0);
+    let request = beacon.get_request(&client.address, &session_id);
+    assert_eq!(
+        request.participants,
+        vec![&env, player1.clone(), player2.clone()]
+    );
+    assert_eq!(request.bond_token, None);
+
+    // Guesses and reveals reach the beacon, which finalizes on the second reveal
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 3, 1);
+    let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 8, 2);
+    client.make_guess(&session_id, &player1, &commitment1);
+    client.make_guess(&session_id, &player2, &commitment2);
+    let request = beacon.get_request(&client.address, &session_id);
+    assert_eq!(request.commitments.get(player1.clone()), Some(commitment1));
+    assert_eq!(request.commitments.get(player2.clone()), Some(commitment2));
+
+    client.reveal_guess(&session_id, &player1, &3, &salt1);
+    assert_eq!(beacon.get_output(&client.address, &session_id), None);
+    client.reveal_guess(&session_id, &player2, &8, &salt2);
+
+    let game = client.get_game(&session_id);
+    let output = beacon.get_output(&client.address, &session_id);
+    assert!(output.is_some());
+    assert_eq!(game.seed, output);
+    assert!(game.winning_number.is_some());
 }
 
 // ============================================================================
@@ -322,68 +455,103 @@ fn test_exact_guess_wins() {
 
 #[test]
 fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+    let (_salt, commitment) = sealed_guess(&env, session_id, &player1, 5, 1);
+    client.make_guess(&session_id, &player1, &commitment);
 
     // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
+    let (_salt, commitment) = sealed_guess(&env, session_id, &player1, 6, 1);
+    let result = client.try_make_guess(&session_id, &player1, &commitment);
     assert_number_guess_error(&result, Error::AlreadyGuessed);
 }
 
+#[test]
+fn test_cannot_guess_when_session_expires_before_reveals() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_salt, commitment) = sealed_guess(&env, session_id, &player1, 5, 1);
+
+    // A guess at ledger 100 opens a window up to ledger 820
+    hub.set_expires_ledger(&(100 + 720));
+    let result = client.try_make_guess(&session_id, &player1, &commitment);
+    assert_number_guess_error(&result, Error::SessionExpiring);
+
+    hub.set_expires_ledger(&(100 + 721));
+    client.make_guess(&session_id, &player1, &commitment);
+}
+
 #[test]
 fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    let (salt, commitment) = sealed_guess(&env, session_id, &player1, 5, 1);
+    client.make_guess(&session_id, &player1, &commitment);
 
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
+    // Try to reveal - should fail
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt);
     assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+    let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 7, 2);
+    client.make_guess(&session_id, &player1, &commitment1);
+    client.make_guess(&session_id, &player2, &commitment2);
+
+    // Neither a different guess nor a different salt opens the commitment
+    let result = client.try_reveal_guess(&session_id, &player1, &6, &salt1);
+    assert_number_guess_error(&result, Error::InvalidReveal);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt2);
+    assert_number_guess_error(&result, Error::InvalidReveal);
+
+    client.reveal_guess(&session_id, &player1, &5, &salt1);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt1);
+    assert_number_guess_error(&result, Error::AlreadyRevealed);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_guess(&session_id, &non_player, &7, &salt2);
+    assert_number_guess_error(&result, Error::NotPlayer);
+}
+
+#[test]
 fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt, commitment) = sealed_guess(&env, session_id, &player1, 0, 1);
+    client.make_guess(&session_id, &player1, &commitment);
+    let (_salt2, commitment2) = sealed_guess(&env, session_id, &player2, 5, 2);
+    client.make_guess(&session_id, &player2, &commitment2);
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    // Try to reveal 0 (below range)
+    let result = client.try_reveal_guess(&session_id, &player1, &0, &salt);
+    assert_number_guess_error(&result, Error::InvalidGuess);
 }
 
 #[test]
-#[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt, commitment) = sealed_guess(&env, session_id, &player1, 11, 1);
+    client.make_guess(&session_id, &player1, &commitment);
+    let (_salt2, commitment2) = sealed_guess(&env, session_id, &player2, 5, 2);
+    client.make_guess(&session_id, &player2, &commitment2);
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    // Try to reveal 11 (above range)
+    let result = client.try_reveal_guess(&session_id, &player1, &11, &salt);
+    assert_number_guess_error(&result, Error::InvalidGuess);
 }
 
 #[test]
@@ -391,58 +559,143 @@ fn test_non_player_cannot_guess() {
     let (env, client, _hub, player1, player2) = setup_test();
     let non_player = Address::generate(&env);
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
+    let (_salt, commitment) = sealed_guess(&env, session_id, &non_player, 5, 1);
+    let result = client.try_make_guess(&session_id, &non_player, &commitment);
     assert_number_guess_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_reveal_nonexistent_game() {
-    let (_env, client, _hub, _player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
-    let result = client.try_reveal_winner(&999);
+    let (salt, _commitment) = sealed_guess(&env, 999, &player1, 5, 1);
+    let result = client.try_reveal_guess(&999, &player1, &5, &salt);
     assert_number_guess_error(&result, Error::GameNotFound);
 }
 
 #[test]
 fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
-    // Reveal winner - game ends
-    let _winner = client.reveal_winner(&session_id);
+    // Both players guess and reveal - game ends
+    play_out(&env, &client, session_id, (&player1, 5), (&player2, 7));
 
     // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
+    let (_salt, commitment) = sealed_guess(&env, session_id, &player1, 3, 3);
+    let result = client.try_make_guess(&session_id, &player1, &commitment);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_cannot_reveal_after_game_ended() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let winner = play_out(&env, &client, session_id, (&player1, 5), (&player2, 10));
+
+    // The result is final
+    let (salt, _commitment) = sealed_guess(&env, session_id, &player1, 5, 1);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.get_game(&session_id).winner, winner);
+}
+
+// ============================================================================
+// Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_player_who_never_reveals_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+    let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 7, 2);
+    client.make_guess(&session_id, &player1, &commitment1);
+    client.make_guess(&session_id, &player2, &commitment2);
+    client.reveal_guess(&session_id, &player2, &7, &salt2);
+
+    // Player 1 still has until the deadline to reveal
+    env.ledger().set_sequence_number(100 + 720);
+    let result = client.try_claim_forfeit(&session_id);
+    assert_number_guess_error(&result, Error::RevealWindowOpen);
+
+    env.ledger().set_sequence_number(100 + 721);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt1);
+    assert_number_guess_error(&result, Error::RevealWindowClosed);
+
+    assert_eq!(client.claim_forfeit(&session_id), Some(player2.clone()));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2));
+    assert!(game.winning_number.is_none());
+
+    let result = client.try_claim_forfeit(&session_id);
     assert_number_guess_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
-fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_copied_commitment_cannot_be_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+    client.make_guess(&session_id, &player1, &commitment1);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Player 2 copies player 1's commitment to force a draw
+    client.make_guess(&session_id, &player2, &commitment1);
+    client.reveal_guess(&session_id, &player1, &5, &salt1);
 
-    // First reveal succeeds
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // The commitment is bound to player 1, so player 2 cannot open it
+    let result = client.try_reveal_guess(&session_id, &player2, &5, &salt1);
+    assert_number_guess_error(&result, Error::InvalidReveal);
 
-    // Second reveal should return same winner (idempotent)
-    let winner2 = client.reveal_winner(&session_id);
-    assert_eq!(winner, winner2);
+    env.ledger().set_sequence_number(100 + 721);
+    assert_eq!(client.claim_forfeit(&session_id), Some(player1));
+}
+
+#[test]
+fn test_out_of_range_guess_loses_by_forfeit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (salt1, commitment1) = sealed_guess(&env, session_id, &player1, 11, 1);
+    let (salt2, commitment2) = sealed_guess(&env, session_id, &player2, 7, 2);
+    client.make_guess(&session_id, &player1, &commitment1);
+    client.make_guess(&session_id, &player2, &commitment2);
+    client.reveal_guess(&session_id, &player2, &7, &salt2);
+
+    // The sealed 11 is only caught at reveal, so player 1 can never open it
+    let result = client.try_reveal_guess(&session_id, &player1, &11, &salt1);
+    assert_number_guess_error(&result, Error::InvalidGuess);
+
+    env.ledger().set_sequence_number(100 + 721);
+    assert_eq!(client.claim_forfeit(&session_id), Some(player2.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player2));
+}
+
+#[test]
+fn test_forfeit_by_both_players_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_salt1, commitment1) = sealed_guess(&env, session_id, &player1, 5, 1);
+    client.make_guess(&session_id, &player1, &commitment1);
+
+    // Without both guesses there is no reveal window; the hub deadline applies
+    let result = client.try_claim_forfeit(&session_id);
+    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+
+    let (_salt2, commitment2) = sealed_guess(&env, session_id, &player2, 7, 2);
+    client.make_guess(&session_id, &player2, &commitment2);
+    env.ledger().set_sequence_number(100 + 721);
+
+    assert_eq!(client.claim_forfeit(&session_id), None);
+    assert!(client.get_game(&session_id).is_draw);
 }
 
 // ============================================================================
@@ -455,32 +708,24 @@ fn test_multiple_games_independent() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    let session1 = 20u32;
-    let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    let session1 = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let session2 = client.start_game(&player3, &player4, &50_0000000, &50_0000000);
 
     // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
-
-    // Reveal both winners
-    let winner1 = client.reveal_winner(&session1);
-    let winner2 = client.reveal_winner(&session2);
+    let winner1 = play_out(&env, &client, session1, (&player1, 3), (&player2, 7));
+    let winner2 = play_out(&env, &client, session2, (&player3, 8), (&player4, 2));
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    assert!(winner1.is_none() || winner1 == Some(player1) || winner1 == Some(player2));
+    assert!(winner2.is_none() || winner2 == Some(player3) || winner2 == Some(player4));
 
     // Verify both games are independent
     let final_game1 = client.get_game(&session1);
     let final_game2 = client.get_game(&session2);
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    assert!(final_game1.winner.is_some() || final_game1.is_draw); // Game 1 has ended
+    assert!(final_game2.winner.is_some() || final_game2.is_draw); // Game 2 has ended
 
     // Note: winning numbers could be the same by chance, so we just verify they're both set
     assert!(final_game1.winning_number.is_some());
@@ -489,25 +734,35 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 15u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    let session_id = client.start_game(&player1, &player2, &points1, &points2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
-    client.reveal_winner(&session_id);
+    play_out(&env, &client, session_id, (&player1, 5), (&player2, 5));
 
-    // Game completes successfully with asymmetric points
+    // Game completes successfully with asymmetric points (equal guesses draw)
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    assert!(final_game.is_draw);
+    assert!(final_game.winner.is_none());
+}
+
+#[test]
+fn test_reused_session_id_rejected() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    // A hub that hands out the same ID again must not overwrite the live game
+    hub.set_next_session_id(&session_id);
+    let result = client.try_start_game(&player1, &player2, &100_0000000, &100_0000000);
+    assert_number_guess_error(&result, Error::SessionAlreadyExists);
 }
 
 // ============================================================================
@@ -531,9 +786,236 @@ fn test_upgrade_function_exists() {
     // The function will fail with MissingValue because the WASM hash doesn't exist
     // But that's expected - we're just verifying the function signature is correct
     let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
+    let result = client.try_upgrade(&admin, &new_wasm_hash);
 
     // Should fail with MissingValue (WASM doesn't exist) not NotAdmin
     // This confirms the authorization check passed
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Schema Migration Tests
+// ============================================================================
+
+/// A version 1 game in which only player 1 has guessed, in plain view
+fn v1_game(player1: &Address, player2: &Address) -> GameV1 {
+    GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 100_0000000,
+        player1_guess: Some(3),
+        player2_guess: None,
+        winning_number: None,
+        winner: None,
+    }
+}
+
+/// Rewrite a game and the stored version the way an earlier build left them
+fn write_legacy(
+    env: &Env,
+    client: &NumberGuessContractClient<'static>,
+    session_id: u32,
+    game: impl IntoVal<Env, Val>,
+) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, &game.into_val(env));
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
+#[test]
+fn test_new_deployment_uses_current_schema() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+    assert_eq!(client.version(), 2);
+}
+
+#[test]
+fn test_migrate_keeps_in_flight_games_playable() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = 7;
+
+    // Simulate an upgrade from a build that wrote the version 1 layout
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert_eq!(client.version(), 1);
+    assert!(client.try_get_game(&session_id).is_err());
+
+    // A partial batch converts its games but leaves the version alone
+    assert_eq!(
+        client.migrate(&admin, &vec![&env, session_id, 999], &false),
+        1
+    );
+    assert_eq!(client.version(), 1);
+
+    // The version 1 guess was public, so the game is back in the guess phase
+    let game = client.get_game(&session_id);
+    assert!(game.player1_guess.is_none() && game.player1_commitment.is_none());
+    assert!(game.winner.is_none() && !game.is_draw);
+
+    // Already converted games are skipped, and the last batch moves the version
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 0);
+    assert_eq!(client.version(), 2);
+    let winner = play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+    assert_eq!(client.get_game(&session_id).winner, winner);
+}
+
+#[test]
+fn test_migrate_plays_out_games_the_hub_never_recorded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let hub = use_real_hub(&env, &client, &player1, &player2);
+    let session_id = 7;
+
+    // The original build chose session 7 itself, so the hub has no record of it
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert!(hub.try_get_session(&session_id).is_err());
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+
+    // The game plays out; nothing was locked at the hub, so no points move
+    play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_some() || game.is_draw);
+    assert_eq!(hub.get_balance(&player1), 1000);
+    assert_eq!(hub.get_balance(&player2), 1000);
+
+    // Games started after the upgrade still settle through the hub
+    hub.reserve_session_ids(&session_id);
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
+}
+
+#[test]
+fn test_migrate_keeps_version_1_results() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    let finished = GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 100_0000000,
+        player1_guess: Some(4),
+        player2_guess: Some(9),
+        winning_number: Some(3),
+        winner: Some(player1.clone()),
+    };
+    write_legacy(&env, &client, session_id, finished);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.player1_guess, Some(4));
+    assert_eq!(game.player2_guess, Some(9));
+    assert_eq!(game.winning_number, Some(3));
+    assert!(!game.is_draw);
+}
+
+#[test]
+fn test_migrate_moves_version_1_games_to_the_beacon() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+    let session_id = 7;
+
+    // Version 1 guesses were never committed to the beacon
+    write_legacy(&env, &client, session_id, v1_game(&player1, &player2));
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+
+    let game = client.get_game(&session_id);
+    assert!(game.seed.is_none());
+    let request = beacon.get_request(&client.address, &session_id);
+    assert!(request.commitments.is_empty());
+
+    play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+    let game = client.get_game(&session_id);
+    assert!(game.seed.is_some());
+    assert!(game.winner.is_some() || game.is_draw);
+}
+
+// ============================================================================
+// WASM Upgrade Test
+// ============================================================================
+// Games are started on the baseline release, deployed from its WASM with the
+// baseline hub, and finished after upgrading both contracts to this build.
+
+mod baseline {
+    pub mod game {
+        soroban_sdk::contractimport!(file = "fixtures/number_guess_baseline.wasm");
+    }
+
+    pub mod hub {
+        soroban_sdk::contractimport!(file = "../mock-game-hub/fixtures/mock_game_hub_baseline.wasm");
+    }
+}
+
+/// Swap this build's code in under a contract deployed from an older WASM.
+/// Registering it runs the constructor, which an on-chain upgrade does not:
+/// the admin and hub it writes are the ones already stored, and the schema
+/// version it records is removed so the data reads as unmigrated.
+fn upgrade_in_place(env: &Env, contract_id: &Address, admin: &Address, hub: &Address) {
+    env.register_at(contract_id, NumberGuessContract, (admin, hub));
+    env.as_contract(contract_id, || {
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
+#[test]
+fn test_upgrade_from_baseline_wasm() {
+    let (env, _client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+
+    // The baseline release starts three games with IDs it picks itself
+    let hub_addr = env.register(baseline::hub::WASM, ());
+    let contract_id = env.register(baseline::game::WASM, (&admin, &hub_addr));
+    let old = baseline::game::Client::new(&env, &contract_id);
+    for session_id in 1..=3 {
+        old.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    old.make_guess(&1, &player1, &3);
+    old.make_guess(&2, &player1, &3);
+    old.make_guess(&2, &player2, &8);
+    let finished_winner = old.reveal_winner(&2);
+
+    // The baseline hub kept no state, so this build's hub replaces it; its
+    // counter moves past the IDs the games already hold
+    env.register_at(&hub_addr, mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(&env, &hub_addr);
+    hub.add_game(&contract_id);
+    hub.add_points(&player1, &1000);
+    hub.add_points(&player2, &1000);
+    hub.reserve_session_ids(&3);
+
+    upgrade_in_place(&env, &contract_id, &admin, &hub_addr);
+    let client = NumberGuessContractClient::new(&env, &contract_id);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.get_admin(), admin);
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, 1, 2, 3], &true), 3);
+    assert_eq!(client.version(), 2);
+
+    // The finished game keeps its result; the others go back to the guess
+    // phase and play out on the beacon, settling nothing at the hub
+    let finished = client.get_game(&2);
+    assert_eq!(finished.winner, Some(finished_winner));
+    assert_eq!(finished.player1_guess, Some(3));
+    for session_id in [1, 3] {
+        assert!(client.get_game(&session_id).player1_guess.is_none());
+        play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+        let game = client.get_game(&session_id);
+        assert!(game.seed.is_some());
+        assert!(game.winner.is_some() || game.is_draw);
+    }
+    assert_eq!(hub.get_balance(&player1), 1000);
+
+    // New games take hub-allocated IDs past the baseline ones and settle there
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    assert_eq!(session_id, 4);
+    play_out(&env, &client, session_id, (&player1, 3), (&player2, 8));
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
+}
@@ -0,0 +1,511 @@
+#![no_std]
+
+//! # Randomness Beacon
+//!
+//! Multi-party commit-reveal randomness for game sessions.
+//!
+//! **Flow:**
+//! 1. A requester, usually a game contract, opens a request for one of its
+//!    sessions and names the participants
+//! 2. Each participant commits to `keccak256(secret)` before the commit
+//!    deadline, posting the request's bond if it has one
+//! 3. The reveal window opens once every participant has committed, or when
+//!    the commit deadline passes
+//! 4. Participants reveal their secrets; the last reveal finalizes the request,
+//!    and anyone can finalize it once the reveal window has closed
+//!
+//! The output is `keccak256` of the session ID and every revealed secret, so
+//! nobody can predict it while at least one participant keeps their secret to
+//! themselves until everyone has committed.
+//!
+//! **Defaults:**
+//! A participant who does not commit, or commits and never reveals, is listed
+//! in the request's `defaulted` participants and loses their bond to the
+//! participants who revealed. Their secret is missing from the output, which
+//! would leave it to whoever revealed last, so the ledger PRNG is mixed in
+//! instead: withholding a reveal only trades a known output for an unknown one.
+
+pub use sgs_common::beacon::Bond;
+use sgs_common::{admin, schema, ttl};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, Address, Bytes,
+    BytesN, Env, Map, Vec,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    RequestNotFound = 1,
+    RequestAlreadyExists = 2,
+    InvalidParticipants = 3,
+    InvalidWindow = 4,
+    InvalidBond = 5,
+    NotParticipant = 6,
+    AlreadyCommitted = 7,
+    CommitWindowClosed = 8,
+    RevealWindowNotOpen = 9,
+    RevealWindowClosed = 10,
+    NotCommitted = 11,
+    AlreadyRevealed = 12,
+    InvalidReveal = 13,
+    RevealWindowOpen = 14,
+    AlreadyFinalized = 15,
+    Overflow = 16,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+// Versioned like the hub's events, e.g. `["randomness_finalized", "v1"]`.
+
+#[contractevent(topics = ["randomness_requested", "v1"])]
+pub struct RandomnessRequested {
+    pub requester: Address,
+    pub session_id: u32,
+    pub participants: Vec<Address>,
+}
+
+#[contractevent(topics = ["randomness_finalized", "v1"])]
+pub struct RandomnessFinalized {
+    pub requester: Address,
+    pub session_id: u32,
+    pub output: BytesN<32>,
+    pub defaulted: Vec<Address>,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Request {
+    pub participants: Vec<Address>,
+    /// SEP-41 token bonds are posted in, or `None` for no bond
+    pub bond_token: Option<Address>,
+    /// Amount each participant posts when committing
+    pub bond: i128,
+    /// Last ledger on which participants can commit
+    pub commit_deadline: u32,
+    /// Length of the reveal window
+    pub reveal_ledgers: u32,
+    /// `keccak256(secret)` committed by each participant
+    pub commitments: Map<Address, BytesN<32>>,
+    /// Secrets revealed so far
+    pub secrets: Map<Address, Bytes>,
+    /// Last ledger on which secrets can be revealed, set once every participant has committed
+    pub reveal_deadline: Option<u32>,
+    pub output: Option<BytesN<32>>,
+    /// Participants who did not reveal, set when the request is finalized
+    pub defaulted: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// (requester, session_id)
+    Request(Address, u32),
+}
+
+// ============================================================================
+// Storage Schema
+// ============================================================================
+// Version 1: the original layout
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 1;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Most participants a request can name
+const MAX_PARTICIPANTS: u32 = 32;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn read_request(env: &Env, requester: &Address, session_id: u32) -> Result<Request, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Request(requester.clone(), session_id))
+        .ok_or(Error::RequestNotFound)
+}
+
+// Requests can hold bonds, so they live in persistent storage and are bumped
+// whenever they are touched.
+fn write_request(env: &Env, requester: &Address, session_id: u32, request: &Request) {
+    let key = DataKey::Request(requester.clone(), session_id);
+    env.storage().persistent().set(&key, request);
+    ttl::extend_persistent(env, &key);
+}
+
+/// Whether commits are over: everyone has committed or the deadline passed
+fn reveal_window_opened(env: &Env, request: &Request) -> bool {
+    request.reveal_deadline.is_some() || env.ledger().sequence() > request.commit_deadline
+}
+
+/// Last ledger of the reveal window. When the commit deadline opened it, the
+/// window runs from there.
+fn reveal_deadline(request: &Request) -> u32 {
+    request.reveal_deadline.unwrap_or(
+        request
+            .commit_deadline
+            .saturating_add(request.reveal_ledgers),
+    )
+}
+
+fn transfer_out(env: &Env, bond_token: &Address, to: &Address, amount: i128) {
+    if amount > 0 {
+        token::TokenClient::new(env, bond_token).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount,
+        );
+    }
+}
+
+/// Derive the output, record who defaulted and settle the bonds
+fn finalize_request(
+    env: &Env,
+    requester: &Address,
+    session_id: u32,
+    request: &mut Request,
+) -> Result<BytesN<32>, Error> {
+    // Secrets are length-prefixed and taken in participant order
+    let mut seed_bytes = Bytes::from_array(env, &session_id.to_be_bytes());
+    let mut revealed = Vec::new(env);
+    let mut forfeits: u32 = 0;
+    request.defaulted = Vec::new(env);
+    for participant in request.participants.iter() {
+        if let Some(secret) = request.secrets.get(participant.clone()) {
+            seed_bytes.append(&Bytes::from_array(env, &secret.len().to_be_bytes()));
+            seed_bytes.append(&secret);
+            revealed.push_back(participant);
+        } else {
+            if request.commitments.contains_key(participant.clone()) {
+                forfeits += 1;
+            }
+            request.defaulted.push_back(participant);
+        }
+    }
+
+    // A withheld secret would leave the output to the last participant to
+    // reveal, who could see it coming; the ledger PRNG stands in for it
+    if !request.defaulted.is_empty() {
+        let fallback: BytesN<32> = env.prng().gen();
+        seed_bytes.append(&Bytes::from(fallback));
+    }
+    let output: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+    request.output = Some(output.clone());
+
+    // Revealers get their bond back plus an equal share of the forfeited
+    // bonds; the rounding remainder, or everything if nobody revealed, goes to
+    // the requester
+    if let Some(bond_token) = &request.bond_token {
+        let forfeited = request
+            .bond
+            .checked_mul(forfeits as i128)
+            .ok_or(Error::Overflow)?;
+        let share = if revealed.is_empty() {
+            0
+        } else {
+            forfeited / revealed.len() as i128
+        };
+        for participant in revealed.iter() {
+            let refund = request.bond.checked_add(share).ok_or(Error::Overflow)?;
+            transfer_out(env, bond_token, &participant, refund);
+        }
+        transfer_out(
+            env,
+            bond_token,
+            requester,
+            forfeited - share * revealed.len() as i128,
+        );
+    }
+
+    RandomnessFinalized {
+        requester: requester.clone(),
+        session_id,
+        output: output.clone(),
+        defaulted: request.defaulted.clone(),
+    }
+    .publish(env);
+
+    Ok(output)
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct RandomnessContract;
+
+#[contractimpl]
+impl RandomnessContract {
+    /// Initialize the contract with its admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    pub fn __constructor(env: Env, admin: Address) {
+        admin::write_admin(&env, &admin);
+        schema::init(&env, SCHEMA_VERSION);
+    }
+
+    /// Open a randomness request for one of the requester's sessions
+    ///
+    /// # Arguments
+    /// * `requester` - Address opening the request, usually a game contract
+    /// * `session_id` - The requester's session ID; one request per session
+    /// * `participants` - Addresses that commit and reveal (1-32, no repeats)
+    /// * `commit_ledgers` - Ledgers participants have to commit
+    /// * `reveal_ledgers` - Ledgers participants have to reveal once commits are over
+    /// * `bond` - Token and amount each participant posts when committing, or `None`
+    pub fn request(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+        participants: Vec<Address>,
+        commit_ledgers: u32,
+        reveal_ledgers: u32,
+        bond: Option<Bond>,
+    ) -> Result<(), Error> {
+        requester.require_auth();
+
+        if participants.is_empty() || participants.len() > MAX_PARTICIPANTS {
+            return Err(Error::InvalidParticipants);
+        }
+        for (index, participant) in participants.iter().enumerate() {
+            if participants.last_index_of(&participant) != Some(index as u32) {
+                return Err(Error::InvalidParticipants);
+            }
+        }
+        if commit_ledgers == 0 || reveal_ledgers == 0 {
+            return Err(Error::InvalidWindow);
+        }
+        if bond.as_ref().is_some_and(|bond| bond.amount <= 0) {
+            return Err(Error::InvalidBond);
+        }
+
+        let key = DataKey::Request(requester.clone(), session_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::RequestAlreadyExists);
+        }
+
+        let request = Request {
+            participants: participants.clone(),
+            bond_token: bond.as_ref().map(|bond| bond.token.clone()),
+            bond: bond.map_or(0, |bond| bond.amount),
+            commit_deadline: env.ledger().sequence().saturating_add(commit_ledgers),
+            reveal_ledgers,
+            commitments: Map::new(&env),
+            secrets: Map::new(&env),
+            reveal_deadline: None,
+            output: None,
+            defaulted: Vec::new(&env),
+        };
+        write_request(&env, &requester, session_id, &request);
+
+        RandomnessRequested {
+            requester,
+            session_id,
+            participants,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Commit to a secret, posting the request's bond
+    ///
+    /// # Arguments
+    /// * `requester` - Address that opened the request
+    /// * `session_id` - The requester's session ID
+    /// * `participant` - Address of the committing participant
+    /// * `commitment` - `keccak256(secret)`
+    pub fn commit(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+        participant: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+
+        let mut request = read_request(&env, &requester, session_id)?;
+        if request.output.is_some() {
+            return Err(Error::AlreadyFinalized);
+        }
+        if !request.participants.contains(&participant) {
+            return Err(Error::NotParticipant);
+        }
+        if request.commitments.contains_key(participant.clone()) {
+            return Err(Error::AlreadyCommitted);
+        }
+        if reveal_window_opened(&env, &request) {
+            return Err(Error::CommitWindowClosed);
+        }
+
+        if let Some(bond_token) = &request.bond_token {
+            token::TokenClient::new(&env, bond_token).transfer(
+                &participant,
+                env.current_contract_address(),
+                &request.bond,
+            );
+        }
+        request.commitments.set(participant, commitment);
+
+        // The reveal window opens as soon as everyone has committed
+        if request.commitments.len() == request.participants.len() {
+            let deadline = env
+                .ledger()
+                .sequence()
+                .saturating_add(request.reveal_ledgers);
+            request.reveal_deadline = Some(deadline);
+        }
+        write_request(&env, &requester, session_id, &request);
+
+        Ok(())
+    }
+
+    /// Reveal a committed secret. Only the participant can reveal it, so a
+    /// secret that leaks early cannot be revealed ahead of them to make the
+    /// reveal their game passes on fail. The reveal that completes every
+    /// commitment finalizes the request.
+    ///
+    /// # Arguments
+    /// * `requester` - Address that opened the request
+    /// * `session_id` - The requester's session ID
+    /// * `participant` - Address of the participant who committed
+    /// * `secret` - The secret committed to in `commit`
+    pub fn reveal(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+        participant: Address,
+        secret: Bytes,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+
+        let mut request = read_request(&env, &requester, session_id)?;
+        if request.output.is_some() {
+            return Err(Error::AlreadyFinalized);
+        }
+        if !request.participants.contains(&participant) {
+            return Err(Error::NotParticipant);
+        }
+        if !reveal_window_opened(&env, &request) {
+            return Err(Error::RevealWindowNotOpen);
+        }
+        if env.ledger().sequence() > reveal_deadline(&request) {
+            return Err(Error::RevealWindowClosed);
+        }
+        let commitment = request
+            .commitments
+            .get(participant.clone())
+            .ok_or(Error::NotCommitted)?;
+        if request.secrets.contains_key(participant.clone()) {
+            return Err(Error::AlreadyRevealed);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&secret).into();
+        if hash != commitment {
+            return Err(Error::InvalidReveal);
+        }
+        request.secrets.set(participant, secret);
+
+        if request.secrets.len() == request.commitments.len() {
+            finalize_request(&env, &requester, session_id, &mut request)?;
+        }
+        write_request(&env, &requester, session_id, &request);
+
+        Ok(())
+    }
+
+    /// Finalize a request whose reveal window has closed, or whose commits are
+    /// over with every commitment revealed. Anyone can call this.
+    ///
+    /// # Arguments
+    /// * `requester` - Address that opened the request
+    /// * `session_id` - The requester's session ID
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - The output
+    pub fn finalize(env: Env, requester: Address, session_id: u32) -> Result<BytesN<32>, Error> {
+        let mut request = read_request(&env, &requester, session_id)?;
+        if request.output.is_some() {
+            return Err(Error::AlreadyFinalized);
+        }
+        let all_revealed = request.secrets.len() == request.commitments.len();
+        if !reveal_window_opened(&env, &request)
+            || (!all_revealed && env.ledger().sequence() <= reveal_deadline(&request))
+        {
+            return Err(Error::RevealWindowOpen);
+        }
+
+        let output = finalize_request(&env, &requester, session_id, &mut request)?;
+        write_request(&env, &requester, session_id, &request);
+
+        Ok(output)
+    }
+
+    /// Get a request
+    ///
+    /// # Arguments
+    /// * `requester` - Address that opened the request
+    /// * `session_id` - The requester's session ID
+    ///
+    /// # Returns
+    /// * `Request` - Participants, commitments, reveals, deadlines and output
+    pub fn get_request(env: Env, requester: Address, session_id: u32) -> Result<Request, Error> {
+        read_request(&env, &requester, session_id)
+    }
+
+    /// Get the output of a request
+    ///
+    /// # Arguments
+    /// * `requester` - Address that opened the request
+    /// * `session_id` - The requester's session ID
+    ///
+    /// # Returns
+    /// * `Option<BytesN<32>>` - The output, or `None` until the request is finalized
+    pub fn get_output(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+    ) -> Result<Option<BytesN<32>>, Error> {
+        Ok(read_request(&env, &requester, session_id)?.output)
+    }
+
+    // ========================================================================
+    // Storage Schema
+    // ========================================================================
+
+    /// Get the schema version of the stored data
+    ///
+    /// # Returns
+    /// * `u32` - Schema version; lags this build's version until data is migrated after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
+    }
+}
+
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_common::impl_admin!(RandomnessContract);
+sgs_common::impl_upgrade!(RandomnessContract);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
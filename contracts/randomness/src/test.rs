@@ -0,0 +1,519 @@
+#![cfg(test)]
+
+// Unit tests for the randomness beacon: commit and reveal windows, the output,
+// defaults and bonds.
+
+use crate::{Bond, Error, RandomnessContract, RandomnessContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    client: RandomnessContractClient<'static>,
+    requester: Address,
+    participants: Vec<Address>,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RandomnessContract, (&admin,));
+    let client = RandomnessContractClient::new(&env, &contract_id);
+
+    let participants = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    Setup {
+        client,
+        requester: Address::generate(&env),
+        participants,
+        env,
+    }
+}
+
+/// Open session 1 with 10 ledgers to commit and 20 to reveal
+fn open(s: &Setup, bond: &Option<Bond>) {
+    s.client
+        .request(&s.requester, &1, &s.participants, &10, &20, bond);
+}
+
+/// A participant's secret and the commitment to it
+fn secret(env: &Env, seed: u8) -> (Bytes, BytesN<32>) {
+    let secret = Bytes::from_array(env, &[seed; 32]);
+    let commitment = env.crypto().keccak256(&secret).into();
+    (secret, commitment)
+}
+
+/// Commit for every participant, with secrets seeded 1, 2, 3...
+fn commit_all(s: &Setup) {
+    for (index, participant) in s.participants.iter().enumerate() {
+        let (_secret, commitment) = secret(&s.env, index as u8 + 1);
+        s.client.commit(&s.requester, &1, &participant, &commitment);
+    }
+}
+
+fn reveal(s: &Setup, index: u32) {
+    let (secret, _commitment) = secret(&s.env, index as u8 + 1);
+    let participant = s.participants.get_unchecked(index);
+    s.client.reveal(&s.requester, &1, &participant, &secret);
+}
+
+/// Output the beacon derives when every listed secret is revealed
+fn expected_output(env: &Env, session_id: u32, seeds: &[u8]) -> BytesN<32> {
+    let mut seed_bytes = Bytes::from_array(env, &session_id.to_be_bytes());
+    for seed in seeds {
+        let (secret, _commitment) = secret(env, *seed);
+        seed_bytes.append(&Bytes::from_array(env, &secret.len().to_be_bytes()));
+        seed_bytes.append(&secret);
+    }
+    env.crypto().keccak256(&seed_bytes).into()
+}
+
+/// Bond of 100 in a fresh token, with 1000 minted to each participant
+fn setup_bond(s: &Setup) -> (Option<Bond>, TokenClient<'static>) {
+    let issuer = Address::generate(&s.env);
+    let sac = s.env.register_stellar_asset_contract_v2(issuer);
+    let asset = StellarAssetClient::new(&s.env, &sac.address());
+    for participant in s.participants.iter() {
+        asset.mint(&participant, &1000);
+    }
+    let bond = Bond {
+        token: sac.address(),
+        amount: 100,
+    };
+    (Some(bond), TokenClient::new(&s.env, &sac.address()))
+}
+
+/// Assert that a Result contains a specific randomness error
+fn assert_randomness_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Request Tests
+// ============================================================================
+
+#[test]
+fn test_request_rules() {
+    let s = setup_test();
+    let participant = s.participants.get_unchecked(0);
+
+    let empty = Vec::new(&s.env);
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &empty, &10, &20, &None);
+    assert_randomness_error(&result, Error::InvalidParticipants);
+
+    let repeated = vec![&s.env, participant.clone(), participant.clone()];
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &repeated, &10, &20, &None);
+    assert_randomness_error(&result, Error::InvalidParticipants);
+
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &s.participants, &0, &20, &None);
+    assert_randomness_error(&result, Error::InvalidWindow);
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &s.participants, &10, &0, &None);
+    assert_randomness_error(&result, Error::InvalidWindow);
+
+    let bond = Some(Bond {
+        token: Address::generate(&s.env),
+        amount: 0,
+    });
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &s.participants, &10, &20, &bond);
+    assert_randomness_error(&result, Error::InvalidBond);
+
+    open(&s, &None);
+    let request = s.client.get_request(&s.requester, &1);
+    assert_eq!(request.participants, s.participants);
+    assert_eq!(request.commit_deadline, 100 + 10);
+    assert!(request.reveal_deadline.is_none() && request.output.is_none());
+
+    let result = s
+        .client
+        .try_request(&s.requester, &1, &s.participants, &10, &20, &None);
+    assert_randomness_error(&result, Error::RequestAlreadyExists);
+}
+
+#[test]
+fn test_requests_are_kept_per_requester() {
+    let s = setup_test();
+    let other_requester = Address::generate(&s.env);
+
+    // Both requesters may use the same session ID
+    open(&s, &None);
+    s.client
+        .request(&other_requester, &1, &s.participants, &10, &20, &None);
+
+    commit_all(&s);
+    assert!(s
+        .client
+        .get_request(&s.requester, &1)
+        .reveal_deadline
+        .is_some());
+    assert!(s
+        .client
+        .get_request(&other_requester, &1)
+        .reveal_deadline
+        .is_none());
+
+    let result = s.client.try_get_request(&s.requester, &2);
+    assert_randomness_error(&result, Error::RequestNotFound);
+    let result = s.client.try_get_output(&s.requester, &2);
+    assert_randomness_error(&result, Error::RequestNotFound);
+}
+
+// ============================================================================
+// Commit and Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_last_reveal_finalizes_output() {
+    let s = setup_test();
+    open(&s, &None);
+    commit_all(&s);
+
+    // Everyone has committed, so the reveal window opens straight away
+    let request = s.client.get_request(&s.requester, &1);
+    assert_eq!(request.reveal_deadline, Some(100 + 20));
+
+    reveal(&s, 2);
+    reveal(&s, 0);
+    assert_eq!(s.client.get_output(&s.requester, &1), None);
+    reveal(&s, 1);
+
+    // Secrets are taken in participant order, not reveal order
+    let output = s.client.get_output(&s.requester, &1).unwrap();
+    assert_eq!(output, expected_output(&s.env, 1, &[1, 2, 3]));
+    assert!(s.client.get_request(&s.requester, &1).defaulted.is_empty());
+
+    let result = s.client.try_finalize(&s.requester, &1);
+    assert_randomness_error(&result, Error::AlreadyFinalized);
+}
+
+#[test]
+fn test_output_depends_on_every_secret() {
+    let s = setup_test();
+    open(&s, &None);
+
+    // The last participant picks a different secret
+    for index in 0..3u32 {
+        let (_secret, commitment) = secret(&s.env, (index + 1 + index / 2 * 10) as u8);
+        let participant = s.participants.get_unchecked(index);
+        s.client.commit(&s.requester, &1, &participant, &commitment);
+    }
+    reveal(&s, 0);
+    reveal(&s, 1);
+    let (secret3, _commitment) = secret(&s.env, 13);
+    let participant3 = s.participants.get_unchecked(2);
+    s.client.reveal(&s.requester, &1, &participant3, &secret3);
+
+    let output = s.client.get_output(&s.requester, &1).unwrap();
+    assert_eq!(output, expected_output(&s.env, 1, &[1, 2, 13]));
+    assert_ne!(output, expected_output(&s.env, 1, &[1, 2, 3]));
+}
+
+#[test]
+fn test_commit_rules() {
+    let s = setup_test();
+    open(&s, &None);
+    let participant = s.participants.get_unchecked(0);
+    let (_secret, commitment) = secret(&s.env, 1);
+
+    let outsider = Address::generate(&s.env);
+    let result = s
+        .client
+        .try_commit(&s.requester, &1, &outsider, &commitment);
+    assert_randomness_error(&result, Error::NotParticipant);
+
+    s.client.commit(&s.requester, &1, &participant, &commitment);
+    let result = s
+        .client
+        .try_commit(&s.requester, &1, &participant, &commitment);
+    assert_randomness_error(&result, Error::AlreadyCommitted);
+
+    // Commits close at the commit deadline
+    s.env.ledger().set_sequence_number(100 + 11);
+    let late = s.participants.get_unchecked(1);
+    let result = s.client.try_commit(&s.requester, &1, &late, &commitment);
+    assert_randomness_error(&result, Error::CommitWindowClosed);
+}
+
+#[test]
+fn test_reveal_rules() {
+    let s = setup_test();
+    open(&s, &None);
+    let participant = s.participants.get_unchecked(0);
+    let (secret1, commitment1) = secret(&s.env, 1);
+    s.client
+        .commit(&s.requester, &1, &participant, &commitment1);
+
+    // Nobody reveals before every participant has committed
+    let result = s
+        .client
+        .try_reveal(&s.requester, &1, &participant, &secret1);
+    assert_randomness_error(&result, Error::RevealWindowNotOpen);
+
+    for index in 1..3u32 {
+        let (_secret, commitment) = secret(&s.env, index as u8 + 1);
+        let other = s.participants.get_unchecked(index);
+        s.client.commit(&s.requester, &1, &other, &commitment);
+    }
+
+    let (secret2, _commitment2) = secret(&s.env, 2);
+    let result = s
+        .client
+        .try_reveal(&s.requester, &1, &participant, &secret2);
+    assert_randomness_error(&result, Error::InvalidReveal);
+
+    let outsider = Address::generate(&s.env);
+    let result = s.client.try_reveal(&s.requester, &1, &outsider, &secret1);
+    assert_randomness_error(&result, Error::NotParticipant);
+
+    s.client.reveal(&s.requester, &1, &participant, &secret1);
+    let result = s
+        .client
+        .try_reveal(&s.requester, &1, &participant, &secret1);
+    assert_randomness_error(&result, Error::AlreadyRevealed);
+
+    // The window closes at the reveal deadline
+    s.env.ledger().set_sequence_number(100 + 21);
+    let result = s
+        .client
+        .try_reveal(&s.requester, &1, &s.participants.get_unchecked(1), &secret2);
+    assert_randomness_error(&result, Error::RevealWindowClosed);
+}
+
+#[test]
+fn test_reveal_requires_participant_auth() {
+    let s = setup_test();
+    open(&s, &None);
+    commit_all(&s);
+    let participant = s.participants.get_unchecked(0);
+    let (secret1, _commitment1) = secret(&s.env, 1);
+
+    // A leaked secret cannot be revealed by someone else
+    s.env.set_auths(&[]);
+    let result = s
+        .client
+        .try_reveal(&s.requester, &1, &participant, &secret1);
+    assert!(result.is_err());
+    assert!(s.client.get_request(&s.requester, &1).secrets.is_empty());
+
+    s.env.mock_all_auths();
+    s.client.reveal(&s.requester, &1, &participant, &secret1);
+    assert_eq!(s.env.auths()[0].0, participant);
+}
+
+// ============================================================================
+// Default Tests
+// ============================================================================
+
+#[test]
+fn test_defaulter_is_replaced_by_ledger_prng() {
+    let s = setup_test();
+    open(&s, &None);
+    commit_all(&s);
+    reveal(&s, 0);
+    reveal(&s, 1);
+
+    // The last participant still has until the deadline to reveal
+    s.env.ledger().set_sequence_number(100 + 20);
+    let result = s.client.try_finalize(&s.requester, &1);
+    assert_randomness_error(&result, Error::RevealWindowOpen);
+
+    s.env.ledger().set_sequence_number(100 + 21);
+    let output = s.client.finalize(&s.requester, &1);
+
+    // The output is not the one the revealed secrets alone would give
+    assert_ne!(output, expected_output(&s.env, 1, &[1, 2]));
+    let request = s.client.get_request(&s.requester, &1);
+    assert_eq!(request.output, Some(output));
+    assert_eq!(
+        request.defaulted,
+        vec![&s.env, s.participants.get_unchecked(2)]
+    );
+
+    let result = s.client.try_commit(
+        &s.requester,
+        &1,
+        &s.participants.get_unchecked(2),
+        &secret(&s.env, 3).1,
+    );
+    assert_randomness_error(&result, Error::AlreadyFinalized);
+}
+
+#[test]
+fn test_commit_deadline_opens_reveal_window() {
+    let s = setup_test();
+    open(&s, &None);
+    for index in 0..2u32 {
+        let (_secret, commitment) = secret(&s.env, index as u8 + 1);
+        let participant = s.participants.get_unchecked(index);
+        s.client.commit(&s.requester, &1, &participant, &commitment);
+    }
+
+    // The third participant never commits; reveals open after the commit deadline
+    let result = s.client.try_finalize(&s.requester, &1);
+    assert_randomness_error(&result, Error::RevealWindowOpen);
+    s.env.ledger().set_sequence_number(100 + 11);
+
+    let (secret3, _commitment3) = secret(&s.env, 3);
+    let absent = s.participants.get_unchecked(2);
+    let result = s.client.try_reveal(&s.requester, &1, &absent, &secret3);
+    assert_randomness_error(&result, Error::NotCommitted);
+
+    // Revealing every commitment finalizes the request before the reveal deadline
+    reveal(&s, 0);
+    reveal(&s, 1);
+    let request = s.client.get_request(&s.requester, &1);
+    assert!(request.output.is_some());
+    assert_eq!(request.defaulted, vec![&s.env, absent]);
+}
+
+// ============================================================================
+// Bond Tests
+// ============================================================================
+
+#[test]
+fn test_bonds_are_returned_on_reveal() {
+    let s = setup_test();
+    let (bond, token) = setup_bond(&s);
+    open(&s, &bond);
+    commit_all(&s);
+
+    assert_eq!(token.balance(&s.participants.get_unchecked(0)), 900);
+    assert_eq!(token.balance(&s.client.address), 300);
+
+    reveal(&s, 0);
+    reveal(&s, 1);
+    reveal(&s, 2);
+    for participant in s.participants.iter() {
+        assert_eq!(token.balance(&participant), 1000);
+    }
+    assert_eq!(token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_defaulted_bonds_go_to_revealers() {
+    let s = setup_test();
+    let (bond, token) = setup_bond(&s);
+    open(&s, &bond);
+    commit_all(&s);
+    reveal(&s, 0);
+
+    // Two participants default; the one who revealed takes their bonds
+    s.env.ledger().set_sequence_number(100 + 21);
+    s.client.finalize(&s.requester, &1);
+
+    assert_eq!(token.balance(&s.participants.get_unchecked(0)), 1200);
+    assert_eq!(token.balance(&s.participants.get_unchecked(1)), 900);
+    assert_eq!(token.balance(&s.participants.get_unchecked(2)), 900);
+    assert_eq!(token.balance(&s.requester), 0);
+    assert_eq!(token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_bond_remainder_goes_to_requester() {
+    let s = setup_test();
+    let (bond, token) = setup_bond(&s);
+    let bond = bond.map(|bond| Bond {
+        amount: 101,
+        ..bond
+    });
+    open(&s, &bond);
+    commit_all(&s);
+    reveal(&s, 0);
+    reveal(&s, 1);
+
+    // One forfeited bond of 101 split two ways leaves 1 over
+    s.env.ledger().set_sequence_number(100 + 21);
+    s.client.finalize(&s.requester, &1);
+    assert_eq!(token.balance(&s.participants.get_unchecked(0)), 1050);
+    assert_eq!(token.balance(&s.participants.get_unchecked(1)), 1050);
+    assert_eq!(token.balance(&s.participants.get_unchecked(2)), 899);
+    assert_eq!(token.balance(&s.requester), 1);
+
+    // With nobody revealing, every bond goes to the requester
+    s.client
+        .request(&s.requester, &2, &s.participants, &10, &20, &bond);
+    for (index, participant) in s.participants.iter().enumerate() {
+        let (_secret, commitment) = secret(&s.env, index as u8 + 1);
+        s.client.commit(&s.requester, &2, &participant, &commitment);
+    }
+    s.env.ledger().set_sequence_number(100 + 21 + 21);
+    s.client.finalize(&s.requester, &2);
+
+    assert_eq!(token.balance(&s.requester), 1 + 303);
+    assert_eq!(token.balance(&s.client.address), 0);
+    assert_eq!(
+        s.client.get_request(&s.requester, &2).defaulted,
+        s.participants
+    );
+}
+
+// ============================================================================
+// Schema Tests
+// ============================================================================
+
+#[test]
+fn test_version() {
+    let s = setup_test();
+    assert_eq!(s.client.version(), 1);
+}
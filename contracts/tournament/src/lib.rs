@@ -0,0 +1,992 @@
+#![no_std]
+
+//! # Tournament
+//!
+//! Single-elimination tournaments played through the Game Hub.
+//!
+//! **Flow:**
+//! 1. An organizer creates a tournament for one game contract, with a token
+//!    buy-in and a prize split
+//! 2. Players register, paying the buy-in into the prize pool
+//! 3. The organizer starts the tournament, which seeds the bracket in
+//!    registration order; top seeds get byes when the field is not a power of two
+//! 4. Each match is started on the game contract, which opens a hub session,
+//!    and its result is read back from the hub once the game ends the session
+//! 5. When the final is decided the prize pool is paid to the placings
+//!
+//! Drawn, cancelled and expired match sessions are replayed.
+//!
+//! **Deadlines:**
+//! Each match must be decided within `MATCH_LEDGERS` of both players being
+//! known. After that anyone can award a walkover: to the only player who
+//! checked in, or else to the better seed. A tournament that is not started
+//! within `REGISTRATION_LEDGERS` of its creation can be cancelled by anyone,
+//! refunding every buy-in.
+//!
+//! Matches are played for zero stakes, so the game must allow unstaked
+//! sessions on the hub (a `min_stake` of 0).
+//!
+//! **Prizes:**
+//! `prize_split_bps` lists the share of the pool for 1st, 2nd and 3rd place
+//! (the two losing semi-finalists share 3rd). Anything not paid out, including
+//! rounding, goes to the champion.
+
+use sgs_common::{hub, schema, ttl, Outcome, Session, SessionStatus};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, token,
+    Address, Env, Vec,
+};
+
+// Two-player game interface; `start_game` opens the hub session and returns its ID
+#[contractclient(name = "GameClient")]
+pub trait Game {
+    fn start_game(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> u32;
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    TournamentNotFound = 1,
+    InvalidConfig = 2,
+    InvalidPrizeSplit = 3,
+    RegistrationClosed = 4,
+    TournamentFull = 5,
+    AlreadyRegistered = 6,
+    NotEnoughEntrants = 7,
+    TournamentNotInProgress = 8,
+    MatchNotFound = 9,
+    MatchNotReady = 10,
+    MatchAlreadyStarted = 11,
+    MatchNotStarted = 12,
+    MatchInProgress = 13,
+    MatchAlreadyDecided = 14,
+    Overflow = 15,
+    SessionMismatch = 16,
+    DeadlineNotPassed = 17,
+    NotPlayer = 18,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+// Versioned like the hub's events, e.g. `["match_decided", "v1"]`.
+
+#[contractevent(topics = ["player_registered", "v1"])]
+pub struct PlayerRegistered {
+    pub tournament_id: u32,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["match_started", "v1"])]
+pub struct MatchStarted {
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub session_id: u32,
+}
+
+#[contractevent(topics = ["match_decided", "v1"])]
+pub struct MatchDecided {
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub winner: Address,
+}
+
+#[contractevent(topics = ["player_checked_in", "v1"])]
+pub struct PlayerCheckedIn {
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub player: Address,
+}
+
+#[contractevent(topics = ["tournament_completed", "v1"])]
+pub struct TournamentCompleted {
+    pub tournament_id: u32,
+    pub champion: Address,
+    pub prize_pool: i128,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Completed,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub organizer: Address,
+    /// Game contract every match is played on
+    pub game_id: Address,
+    /// SEP-41 token used for buy-ins and prizes
+    pub token: Address,
+    pub buy_in: i128,
+    pub max_entrants: u32,
+    /// Share of the pool for 1st, 2nd and 3rd place, in basis points
+    pub prize_split_bps: Vec<u32>,
+    pub entrants: Vec<Address>,
+    pub prize_pool: i128,
+    pub status: TournamentStatus,
+    /// Number of bracket rounds (set when the tournament starts)
+    pub rounds: u32,
+    pub champion: Option<Address>,
+}
+
+/// One bracket match. Round 1 matches with no `player2` are byes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub player1: Option<Address>,
+    pub player2: Option<Address>,
+    /// Hub session of the current attempt, or 0 if not started
+    pub session_id: u32,
+    pub winner: Option<Address>,
+}
+
+/// Deadline of a match whose players are both known, and who has checked in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchDeadline {
+    /// Last ledger before anyone can award a walkover
+    pub deadline: u32,
+    pub checked_in: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    NextTournamentId,
+    Tournament(u32),
+    /// (tournament_id, round, index)
+    Match(u32, u32, u32),
+    /// (tournament_id, round, index); set once both players are known
+    MatchDeadline(u32, u32, u32),
+    /// Last ledger before anyone can cancel a tournament still in registration
+    StartDeadline(u32),
+}
+
+// ============================================================================
+// Storage Schema
+// ============================================================================
+// Version 1: the original layout
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 1;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Largest field a tournament can take. Starting a tournament writes every
+/// first-round match and deadline in one transaction; a larger field would
+/// go over the network's limit of 50 written entries.
+const MAX_ENTRANTS: u32 = 32;
+
+/// 100% in basis points
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Ledgers a match has to be decided once both players are known (~2 days)
+const MATCH_LEDGERS: u32 = 34_560;
+
+/// Ledgers the organizer has to start a tournament (~7 days)
+const REGISTRATION_LEDGERS: u32 = 120_960;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn read_tournament(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tournament(tournament_id))
+        .ok_or(Error::TournamentNotFound)
+}
+
+// Tournaments hold buy-ins, so they live in persistent storage and are bumped
+// whenever they are touched.
+fn write_tournament(env: &Env, tournament_id: u32, tournament: &Tournament) {
+    let key = DataKey::Tournament(tournament_id);
+    env.storage().persistent().set(&key, tournament);
+    ttl::extend_persistent(env, &key);
+}
+
+// Later-round matches are only written once a player advances into them, so
+// starting a tournament writes just the first round. A match in the bracket
+// that has not been written yet is empty.
+fn read_match(
+    env: &Env,
+    tournament_id: u32,
+    tournament: &Tournament,
+    round: u32,
+    index: u32,
+) -> Result<Match, Error> {
+    let rounds = tournament.rounds;
+    if round == 0 || round > rounds || index >= matches_in_round(rounds, round) {
+        return Err(Error::MatchNotFound);
+    }
+    Ok(env
+        .storage()
+        .persistent()
+        .get(&DataKey::Match(tournament_id, round, index))
+        .unwrap_or(Match {
+            player1: None,
+            player2: None,
+            session_id: 0,
+            winner: None,
+        }))
+}
+
+fn write_match(env: &Env, tournament_id: u32, round: u32, index: u32, game: &Match) {
+    let key = DataKey::Match(tournament_id, round, index);
+    env.storage().persistent().set(&key, game);
+    ttl::extend_persistent(env, &key);
+}
+
+fn read_deadline(
+    env: &Env,
+    tournament_id: u32,
+    round: u32,
+    index: u32,
+) -> Result<MatchDeadline, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MatchDeadline(tournament_id, round, index))
+        .ok_or(Error::MatchNotReady)
+}
+
+fn write_deadline(env: &Env, tournament_id: u32, round: u32, index: u32, deadline: &MatchDeadline) {
+    let key = DataKey::MatchDeadline(tournament_id, round, index);
+    env.storage().persistent().set(&key, deadline);
+    ttl::extend_persistent(env, &key);
+}
+
+/// Start the clock on a match whose players are both known
+fn open_match(env: &Env, tournament_id: u32, round: u32, index: u32) -> Result<(), Error> {
+    let deadline = MatchDeadline {
+        deadline: env
+            .ledger()
+            .sequence()
+            .checked_add(MATCH_LEDGERS)
+            .ok_or(Error::Overflow)?,
+        checked_in: Vec::new(env),
+    };
+    write_deadline(env, tournament_id, round, index, &deadline);
+    Ok(())
+}
+
+/// Check that a hub session was opened by the tournament's game for exactly
+/// this match's players, so a game cannot hand back an unrelated session
+fn check_session(tournament: &Tournament, game: &Match, session: &Session) -> Result<(), Error> {
+    let players_match = session.players.len() == 2
+        && session.players.get(0) == game.player1
+        && session.players.get(1) == game.player2;
+    if session.game_id != tournament.game_id || !players_match {
+        return Err(Error::SessionMismatch);
+    }
+    Ok(())
+}
+
+/// Number of matches in a round of a bracket with `rounds` rounds
+fn matches_in_round(rounds: u32, round: u32) -> u32 {
+    1 << (rounds - round)
+}
+
+/// Seeds in bracket order for a bracket with `rounds` rounds. First-round
+/// match `i` pairs `order[2i]` with `order[2i + 1]`; each pair adds up to the
+/// bracket size minus one, and the top two seeds can only meet in the final.
+fn seed_order(env: &Env, rounds: u32) -> Vec<u32> {
+    let mut order = Vec::from_array(env, [0u32]);
+    for round in 1..=rounds {
+        let size = 1u32 << round;
+        let mut next = Vec::new(env);
+        for seed in order.iter() {
+            next.push_back(seed);
+            next.push_back(size - 1 - seed);
+        }
+        order = next;
+    }
+    order
+}
+
+fn transfer_out(env: &Env, tournament: &Tournament, to: &Address, amount: i128) {
+    if amount > 0 {
+        token::TokenClient::new(env, &tournament.token).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount,
+        );
+    }
+}
+
+/// Whether `player1` is seeded above `player2`. Seeds follow registration
+/// order; a match's `player1` is only the better seed in the first round.
+fn is_better_seed(tournament: &Tournament, player1: &Address, player2: &Address) -> bool {
+    tournament.entrants.first_index_of(player1) < tournament.entrants.first_index_of(player2)
+}
+
+/// The player in a decided match who did not win it
+fn loser(game: &Match) -> Option<Address> {
+    if game.winner == game.player1 {
+        game.player2.clone()
+    } else {
+        game.player1.clone()
+    }
+}
+
+/// Record a match winner and move them into the next round, finishing the
+/// tournament after the final
+fn advance(
+    env: &Env,
+    tournament_id: u32,
+    tournament: &mut Tournament,
+    round: u32,
+    index: u32,
+    winner: Address,
+) -> Result<(), Error> {
+    let mut game = read_match(env, tournament_id, tournament, round, index)?;
+    game.winner = Some(winner.clone());
+    write_match(env, tournament_id, round, index, &game);
+
+    MatchDecided {
+        tournament_id,
+        round,
+        index,
+        winner: winner.clone(),
+    }
+    .publish(env);
+
+    if round == tournament.rounds {
+        return complete(env, tournament_id, tournament, winner);
+    }
+
+    let mut next = read_match(env, tournament_id, tournament, round + 1, index / 2)?;
+    if index.is_multiple_of(2) {
+        next.player1 = Some(winner);
+    } else {
+        next.player2 = Some(winner);
+    }
+    write_match(env, tournament_id, round + 1, index / 2, &next);
+    if next.player1.is_some() && next.player2.is_some() {
+        open_match(env, tournament_id, round + 1, index / 2)?;
+    }
+    Ok(())
+}
+
+/// Pay the prize pool to the placings and close the tournament
+fn complete(
+    env: &Env,
+    tournament_id: u32,
+    tournament: &mut Tournament,
+    champion: Address,
+) -> Result<(), Error> {
+    let pool = tournament.prize_pool;
+    let share = |place: u32| -> Result<i128, Error> {
+        let bps = tournament.prize_split_bps.get(place).unwrap_or(0) as i128;
+        Ok(pool.checked_mul(bps).ok_or(Error::Overflow)? / BPS_DENOMINATOR)
+    };
+    let mut paid: i128 = 0;
+
+    // 2nd place: the other finalist
+    let final_match = read_match(env, tournament_id, tournament, tournament.rounds, 0)?;
+    if let Some(runner_up) = loser(&final_match) {
+        let amount = share(1)?;
+        transfer_out(env, tournament, &runner_up, amount);
+        paid += amount;
+    }
+
+    // 3rd place: shared by the two losing semi-finalists
+    if tournament.rounds >= 2 {
+        let amount = share(2)? / 2;
+        for index in 0..2 {
+            let semi_final =
+                read_match(env, tournament_id, tournament, tournament.rounds - 1, index)?;
+            if let Some(semi_finalist) = loser(&semi_final) {
+                transfer_out(env, tournament, &semi_finalist, amount);
+                paid += amount;
+            }
+        }
+    }
+
+    transfer_out(env, tournament, &champion, pool - paid);
+
+    tournament.status = TournamentStatus::Completed;
+    tournament.champion = Some(champion.clone());
+
+    TournamentCompleted {
+        tournament_id,
+        champion,
+        prize_pool: pool,
+    }
+    .publish(env);
+    Ok(())
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct TournamentContract;
+
+#[contractimpl]
+impl TournamentContract {
+    /// Initialize the contract with GameHub address and admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can upgrade contract)
+    /// * `game_hub` - Address of the GameHub contract
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        sgs_common::admin::init(&env, &admin, &game_hub);
+        schema::init(&env, SCHEMA_VERSION);
+    }
+
+    /// Create a tournament and open registration
+    ///
+    /// # Arguments
+    /// * `organizer` - Address that starts (or cancels) the tournament
+    /// * `game_id` - Game contract the matches are played on
+    /// * `token` - SEP-41 token for buy-ins and prizes
+    /// * `buy_in` - Amount each entrant pays into the prize pool
+    /// * `max_entrants` - Field size limit (2-32)
+    /// * `prize_split_bps` - Share of the pool for 1st, 2nd and 3rd place; must total 10,000
+    ///
+    /// # Returns
+    /// * `u32` - The new tournament ID
+    pub fn create_tournament(
+        env: Env,
+        organizer: Address,
+        game_id: Address,
+        token: Address,
+        buy_in: i128,
+        max_entrants: u32,
+        prize_split_bps: Vec<u32>,
+    ) -> Result<u32, Error> {
+        organizer.require_auth();
+
+        if buy_in < 0 || !(2..=MAX_ENTRANTS).contains(&max_entrants) {
+            return Err(Error::InvalidConfig);
+        }
+        let total_bps: u32 = prize_split_bps.iter().sum();
+        if prize_split_bps.is_empty()
+            || prize_split_bps.len() > 3
+            || total_bps as i128 != BPS_DENOMINATOR
+        {
+            return Err(Error::InvalidPrizeSplit);
+        }
+
+        let tournament_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTournamentId)
+            .unwrap_or(1);
+        let next_tournament_id = tournament_id.checked_add(1).ok_or(Error::Overflow)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTournamentId, &next_tournament_id);
+
+        let tournament = Tournament {
+            organizer,
+            game_id,
+            token,
+            buy_in,
+            max_entrants,
+            prize_split_bps,
+            entrants: Vec::new(&env),
+            prize_pool: 0,
+            status: TournamentStatus::Registration,
+            rounds: 0,
+            champion: None,
+        };
+        write_tournament(&env, tournament_id, &tournament);
+
+        let start_deadline = env
+            .ledger()
+            .sequence()
+            .checked_add(REGISTRATION_LEDGERS)
+            .ok_or(Error::Overflow)?;
+        let key = DataKey::StartDeadline(tournament_id);
+        env.storage().persistent().set(&key, &start_deadline);
+        ttl::extend_persistent(&env, &key);
+
+        // bump instance ttl if required
+        ttl::extend_instance(&env);
+
+        Ok(tournament_id)
+    }
+
+    /// Register for a tournament, paying the buy-in into the prize pool
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to enter
+    /// * `player` - Address of the entrant
+    pub fn register(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        if tournament.entrants.len() >= tournament.max_entrants {
+            return Err(Error::TournamentFull);
+        }
+        if tournament.entrants.contains(&player) {
+            return Err(Error::AlreadyRegistered);
+        }
+
+        if tournament.buy_in > 0 {
+            token::TokenClient::new(&env, &tournament.token).transfer(
+                &player,
+                env.current_contract_address(),
+                &tournament.buy_in,
+            );
+        }
+        tournament.prize_pool = tournament
+            .prize_pool
+            .checked_add(tournament.buy_in)
+            .ok_or(Error::Overflow)?;
+        tournament.entrants.push_back(player.clone());
+        write_tournament(&env, tournament_id, &tournament);
+
+        PlayerRegistered {
+            tournament_id,
+            player,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Close registration and seed the bracket (organizer only).
+    /// Entrants are seeded in registration order and the bracket is laid out so
+    /// the top two seeds can only meet in the final. When the field is not a
+    /// power of two, the top `2^rounds - entrants` seeds get first-round byes.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to start
+    pub fn start_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = read_tournament(&env, tournament_id)?;
+        tournament.organizer.require_auth();
+
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        let entrants = tournament.entrants.len();
+        if entrants < 2 {
+            return Err(Error::NotEnoughEntrants);
+        }
+
+        let mut rounds = 1;
+        while (1u32 << rounds) < entrants {
+            rounds += 1;
+        }
+        tournament.rounds = rounds;
+        tournament.status = TournamentStatus::InProgress;
+
+        // Seeds past the field are empty slots, so the top seeds face them and get byes
+        let order = seed_order(&env, rounds);
+        for index in 0..matches_in_round(rounds, 1) {
+            let player1 = tournament.entrants.get_unchecked(order.get_unchecked(2 * index));
+            let player2 = tournament.entrants.get(order.get_unchecked(2 * index + 1));
+            let is_bye = player2.is_none();
+            let game = Match {
+                player1: Some(player1.clone()),
+                player2,
+                session_id: 0,
+                winner: None,
+            };
+            write_match(&env, tournament_id, 1, index, &game);
+            if is_bye {
+                advance(&env, tournament_id, &mut tournament, 1, index, player1)?;
+            } else {
+                open_match(&env, tournament_id, 1, index)?;
+            }
+        }
+
+        write_tournament(&env, tournament_id, &tournament);
+        Ok(())
+    }
+
+    /// Cancel a tournament that has not started and refund every buy-in.
+    /// The organizer can cancel at any time during registration; anyone can
+    /// once the organizer has let the start deadline pass.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to cancel
+    pub fn cancel_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::Registration {
+            return Err(Error::RegistrationClosed);
+        }
+        let start_deadline: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::StartDeadline(tournament_id))
+            .unwrap_or(u32::MAX);
+        if env.ledger().sequence() <= start_deadline {
+            tournament.organizer.require_auth();
+        }
+
+        for entrant in tournament.entrants.iter() {
+            transfer_out(&env, &tournament, &entrant, tournament.buy_in);
+        }
+        tournament.prize_pool = 0;
+        tournament.status = TournamentStatus::Cancelled;
+        write_tournament(&env, tournament_id, &tournament);
+        Ok(())
+    }
+
+    /// Start a bracket match on the tournament's game contract.
+    /// The game opens a hub session with no stakes; both players must authorize
+    /// the game's `start_game`. Games with a minimum stake on the hub cannot
+    /// host tournament matches.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round (1 = first round)
+    /// * `index` - Match index within the round
+    ///
+    /// # Returns
+    /// * `u32` - The hub session ID for the match
+    pub fn start_match(env: Env, tournament_id: u32, round: u32, index: u32) -> Result<u32, Error> {
+        let tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::InProgress {
+            return Err(Error::TournamentNotInProgress);
+        }
+
+        let mut game = read_match(&env, tournament_id, &tournament, round, index)?;
+        if game.winner.is_some() {
+            return Err(Error::MatchAlreadyDecided);
+        }
+        if game.session_id != 0 {
+            return Err(Error::MatchAlreadyStarted);
+        }
+        let (player1, player2) = match (&game.player1, &game.player2) {
+            (Some(player1), Some(player2)) => (player1.clone(), player2.clone()),
+            _ => return Err(Error::MatchNotReady),
+        };
+
+        let session_id =
+            GameClient::new(&env, &tournament.game_id).start_game(&player1, &player2, &0, &0);
+        let session = hub::client(&env).get_session(&session_id);
+        check_session(&tournament, &game, &session)?;
+        if session.status != SessionStatus::Active
+            || session.start_ledger != env.ledger().sequence()
+        {
+            return Err(Error::SessionMismatch);
+        }
+        game.session_id = session_id;
+        write_match(&env, tournament_id, round, index, &game);
+
+        MatchStarted {
+            tournament_id,
+            round,
+            index,
+            session_id,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Read a match result from the hub and advance the winner.
+    /// Anyone can call this once the game has ended the session. A drawn,
+    /// cancelled or expired session is cleared so the match can be replayed.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round
+    /// * `index` - Match index within the round
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winner, or `None` if the match must be replayed
+    pub fn report_result(
+        env: Env,
+        tournament_id: u32,
+        round: u32,
+        index: u32,
+    ) -> Result<Option<Address>, Error> {
+        let mut tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::InProgress {
+            return Err(Error::TournamentNotInProgress);
+        }
+
+        let mut game = read_match(&env, tournament_id, &tournament, round, index)?;
+        if game.winner.is_some() {
+            return Err(Error::MatchAlreadyDecided);
+        }
+        if game.session_id == 0 {
+            return Err(Error::MatchNotStarted);
+        }
+
+        let session = hub::client(&env).get_session(&game.session_id);
+        check_session(&tournament, &game, &session)?;
+        let winner = match session.status {
+            SessionStatus::Active => return Err(Error::MatchInProgress),
+            SessionStatus::Ended(Outcome::Player1) => game.player1.clone(),
+            SessionStatus::Ended(Outcome::Player2) => game.player2.clone(),
+            _ => None,
+        };
+
+        match winner {
+            Some(winner) => {
+                advance(&env, tournament_id, &mut tournament, round, index, winner.clone())?;
+                write_tournament(&env, tournament_id, &tournament);
+                Ok(Some(winner))
+            }
+            None => {
+                game.session_id = 0;
+                write_match(&env, tournament_id, round, index, &game);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Check in for a match, showing the player is ready to play it. If the
+    /// match deadline passes undecided, a walkover goes to the only player who
+    /// checked in.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round
+    /// * `index` - Match index within the round
+    /// * `player` - The checking-in player
+    pub fn check_in(
+        env: Env,
+        tournament_id: u32,
+        round: u32,
+        index: u32,
+        player: Address,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::InProgress {
+            return Err(Error::TournamentNotInProgress);
+        }
+        let game = read_match(&env, tournament_id, &tournament, round, index)?;
+        if game.winner.is_some() {
+            return Err(Error::MatchAlreadyDecided);
+        }
+        if game.player1 != Some(player.clone()) && game.player2 != Some(player.clone()) {
+            return Err(Error::NotPlayer);
+        }
+
+        let mut deadline = read_deadline(&env, tournament_id, round, index)?;
+        if !deadline.checked_in.contains(&player) {
+            deadline.checked_in.push_back(player.clone());
+            write_deadline(&env, tournament_id, round, index, &deadline);
+        }
+
+        PlayerCheckedIn {
+            tournament_id,
+            round,
+            index,
+            player,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Decide a match whose deadline has passed. Anyone can call this.
+    /// A result the hub already holds stands; otherwise the only player who
+    /// checked in wins a walkover, or the better seed if both or neither did.
+    /// A session still running on the hub must end or expire first.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round
+    /// * `index` - Match index within the round
+    ///
+    /// # Returns
+    /// * `Address` - The player who advances
+    pub fn claim_walkover(
+        env: Env,
+        tournament_id: u32,
+        round: u32,
+        index: u32,
+    ) -> Result<Address, Error> {
+        let mut tournament = read_tournament(&env, tournament_id)?;
+        if tournament.status != TournamentStatus::InProgress {
+            return Err(Error::TournamentNotInProgress);
+        }
+        let game = read_match(&env, tournament_id, &tournament, round, index)?;
+        if game.winner.is_some() {
+            return Err(Error::MatchAlreadyDecided);
+        }
+        let deadline = read_deadline(&env, tournament_id, round, index)?;
+        if env.ledger().sequence() <= deadline.deadline {
+            return Err(Error::DeadlineNotPassed);
+        }
+        let (player1, player2) = match (&game.player1, &game.player2) {
+            (Some(player1), Some(player2)) => (player1.clone(), player2.clone()),
+            _ => return Err(Error::MatchNotReady),
+        };
+
+        let mut result = None;
+        if game.session_id != 0 {
+            let session = hub::client(&env).get_session(&game.session_id);
+            check_session(&tournament, &game, &session)?;
+            result = match session.status {
+                SessionStatus::Active if env.ledger().sequence() <= session.expires_ledger => {
+                    return Err(Error::MatchInProgress)
+                }
+                SessionStatus::Ended(Outcome::Player1) => Some(player1.clone()),
+                SessionStatus::Ended(Outcome::Player2) => Some(player2.clone()),
+                _ => None,
+            };
+        }
+        let winner = result.unwrap_or_else(|| {
+            let checked_in1 = deadline.checked_in.contains(&player1);
+            let checked_in2 = deadline.checked_in.contains(&player2);
+            if checked_in1 != checked_in2 {
+                if checked_in1 {
+                    player1
+                } else {
+                    player2
+                }
+            } else if is_better_seed(&tournament, &player1, &player2) {
+                player1
+            } else {
+                player2
+            }
+        });
+
+        advance(
+            &env,
+            tournament_id,
+            &mut tournament,
+            round,
+            index,
+            winner.clone(),
+        )?;
+        write_tournament(&env, tournament_id, &tournament);
+        Ok(winner)
+    }
+
+    /// Get a tournament
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    ///
+    /// # Returns
+    /// * `Tournament` - Settings, entrants, prize pool and status
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        read_tournament(&env, tournament_id)
+    }
+
+    /// Get one bracket match
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round (1 = first round)
+    /// * `index` - Match index within the round
+    ///
+    /// # Returns
+    /// * `Match` - Players, current session and winner
+    pub fn get_match(env: Env, tournament_id: u32, round: u32, index: u32) -> Result<Match, Error> {
+        let tournament = read_tournament(&env, tournament_id)?;
+        read_match(&env, tournament_id, &tournament, round, index)
+    }
+
+    /// Get a match's deadline and check-ins
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round (1 = first round)
+    /// * `index` - Match index within the round
+    ///
+    /// # Returns
+    /// * `MatchDeadline` - Set once both players are known
+    pub fn get_match_deadline(
+        env: Env,
+        tournament_id: u32,
+        round: u32,
+        index: u32,
+    ) -> Result<MatchDeadline, Error> {
+        read_deadline(&env, tournament_id, round, index)
+    }
+
+    /// Get every match in a round, in bracket order
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `round` - Bracket round (1 = first round)
+    ///
+    /// # Returns
+    /// * `Vec<Match>` - The round's matches
+    pub fn get_round(env: Env, tournament_id: u32, round: u32) -> Result<Vec<Match>, Error> {
+        let tournament = read_tournament(&env, tournament_id)?;
+        if round == 0 || round > tournament.rounds {
+            return Err(Error::MatchNotFound);
+        }
+        let mut matches = Vec::new(&env);
+        for index in 0..matches_in_round(tournament.rounds, round) {
+            matches.push_back(read_match(&env, tournament_id, &tournament, round, index)?);
+        }
+        Ok(matches)
+    }
+
+    /// Get the whole bracket, one entry per round starting with the first
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    ///
+    /// # Returns
+    /// * `Vec<Vec<Match>>` - Matches grouped by round (empty before the start)
+    pub fn get_bracket(env: Env, tournament_id: u32) -> Result<Vec<Vec<Match>>, Error> {
+        let tournament = read_tournament(&env, tournament_id)?;
+        let mut bracket = Vec::new(&env);
+        for round in 1..=tournament.rounds {
+            bracket.push_back(Self::get_round(env.clone(), tournament_id, round)?);
+        }
+        Ok(bracket)
+    }
+
+    // ========================================================================
+    // Storage Schema
+    // ========================================================================
+
+    /// Get the schema version of the stored data
+    ///
+    /// # Returns
+    /// * `u32` - Schema version; lags this build's version until data is migrated after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
+    }
+}
+
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_common::impl_admin!(TournamentContract);
+sgs_common::impl_hub_config!(TournamentContract);
+sgs_common::impl_upgrade!(TournamentContract);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
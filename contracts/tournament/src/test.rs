@@ -0,0 +1,715 @@
+#![cfg(test)]
+
+// Unit tests for the tournament contract, played through the real mock Game Hub
+// and a minimal match game.
+
+extern crate std;
+
+use crate::{Error, TournamentContract, TournamentContractClient, TournamentStatus};
+use mock_game_hub::{MockGameHub, MockGameHubClient, Outcome};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, xdr, Address, Env, Vec};
+
+// ============================================================================
+// Match Game for Unit Testing
+// ============================================================================
+
+/// Opens hub sessions like a real game; tests decide results with `finish`
+#[contract]
+pub struct MatchGame;
+
+#[contractimpl]
+impl MatchGame {
+    pub fn __constructor(env: Env, hub: Address) {
+        env.storage().instance().set(&symbol_short!("hub"), &hub);
+    }
+
+    pub fn start_game(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> u32 {
+        player1.require_auth();
+        player2.require_auth();
+        let hub: Address = env.storage().instance().get(&symbol_short!("hub")).unwrap();
+        MockGameHubClient::new(&env, &hub).start_game(
+            &env.current_contract_address(),
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        )
+    }
+
+    pub fn finish(env: Env, session_id: u32, outcome: Outcome) {
+        let hub: Address = env.storage().instance().get(&symbol_short!("hub")).unwrap();
+        MockGameHubClient::new(&env, &hub).end_game(&session_id, &outcome);
+    }
+}
+
+/// Hands back whatever session it was given instead of opening one
+#[contract]
+pub struct ForeignGame;
+
+#[contractimpl]
+impl ForeignGame {
+    pub fn __constructor(env: Env, hub: Address) {
+        env.storage().instance().set(&symbol_short!("hub"), &hub);
+    }
+
+    /// Open and end a session of its own, and hand it back from `start_game`
+    pub fn prepare(env: Env, player1: Address, player2: Address) -> u32 {
+        let hub: Address = env.storage().instance().get(&symbol_short!("hub")).unwrap();
+        let hub = MockGameHubClient::new(&env, &hub);
+        let session_id =
+            hub.start_game(&env.current_contract_address(), &player1, &player2, &0, &0);
+        hub.end_game(&session_id, &Outcome::Player1);
+        Self::use_session(env, session_id);
+        session_id
+    }
+
+    pub fn use_session(env: Env, session_id: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("session"), &session_id);
+    }
+
+    pub fn start_game(
+        env: Env,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("session"))
+            .unwrap()
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+struct Setup {
+    env: Env,
+    client: TournamentContractClient<'static>,
+    game: MatchGameClient<'static>,
+    token: TokenClient<'static>,
+    organizer: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    // Players authorize the game's start_game beneath the tournament's call
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, (&admin,));
+    let hub = MockGameHubClient::new(&env, &hub_addr);
+
+    let game_addr = env.register(MatchGame, (&hub_addr,));
+    hub.add_game(&game_addr);
+
+    let contract_id = env.register(TournamentContract, (&admin, &hub_addr));
+    let client = TournamentContractClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+
+    Setup {
+        client,
+        game: MatchGameClient::new(&env, &game_addr),
+        token: TokenClient::new(&env, &sac.address()),
+        organizer: Address::generate(&env),
+        env,
+    }
+}
+
+/// Create a 100-token buy-in tournament split 60/30/10
+fn create(s: &Setup, max_entrants: u32) -> u32 {
+    s.client.create_tournament(
+        &s.organizer,
+        &s.game.address,
+        &s.token.address,
+        &100,
+        &max_entrants,
+        &vec![&s.env, 6000u32, 3000, 1000],
+    )
+}
+
+/// Register `count` funded players
+fn register_players(s: &Setup, tournament_id: u32, count: u32) -> std::vec::Vec<Address> {
+    let asset = StellarAssetClient::new(&s.env, &s.token.address);
+    let mut players = std::vec::Vec::new();
+    for _ in 0..count {
+        let player = Address::generate(&s.env);
+        asset.mint(&player, &1000);
+        s.client.register(&tournament_id, &player);
+        players.push(player);
+    }
+    players
+}
+
+/// Start a match, have the game report `outcome`, and read it back
+fn play(s: &Setup, tournament_id: u32, round: u32, index: u32, outcome: Outcome) -> Option<Address> {
+    let session_id = s.client.start_match(&tournament_id, &round, &index);
+    s.game.finish(&session_id, &outcome);
+    s.client.report_result(&tournament_id, &round, &index)
+}
+
+/// Assert that a Result contains a specific tournament error
+fn assert_tournament_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Registration Tests
+// ============================================================================
+
+#[test]
+fn test_create_tournament_validation() {
+    let s = setup_test();
+
+    let bad_split = vec![&s.env, 6000u32, 3000];
+    assert_tournament_error(
+        &s.client.try_create_tournament(
+            &s.organizer,
+            &s.game.address,
+            &s.token.address,
+            &100,
+            &8,
+            &bad_split,
+        ),
+        Error::InvalidPrizeSplit,
+    );
+
+    let split = vec![&s.env, 10_000u32];
+    assert_tournament_error(
+        &s.client.try_create_tournament(
+            &s.organizer,
+            &s.game.address,
+            &s.token.address,
+            &100,
+            &33,
+            &split,
+        ),
+        Error::InvalidConfig,
+    );
+    assert_tournament_error(
+        &s.client.try_create_tournament(
+            &s.organizer,
+            &s.game.address,
+            &s.token.address,
+            &-1,
+            &8,
+            &split,
+        ),
+        Error::InvalidConfig,
+    );
+}
+
+#[test]
+fn test_register_collects_buy_in() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+
+    let players = register_players(&s, tournament_id, 2);
+
+    assert_eq!(s.token.balance(&players[0]), 900);
+    assert_eq!(s.token.balance(&s.client.address), 200);
+    let tournament = s.client.get_tournament(&tournament_id);
+    assert_eq!(tournament.prize_pool, 200);
+    assert_eq!(tournament.entrants.len(), 2);
+    assert_eq!(tournament.status, TournamentStatus::Registration);
+
+    assert_tournament_error(
+        &s.client.try_register(&tournament_id, &players[0]),
+        Error::AlreadyRegistered,
+    );
+}
+
+#[test]
+fn test_events_carry_schema_version() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+
+    register_players(&s, tournament_id, 1);
+
+    let events = s.env.events().all().filter_by_contract(&s.client.address);
+    let xdr::ContractEventBody::V0(body) = &events.events()[0].body;
+    let expected: [xdr::ScVal; 2] = [
+        xdr::ScVal::Symbol("player_registered".try_into().unwrap()),
+        xdr::ScVal::Symbol("v1".try_into().unwrap()),
+    ];
+    assert_eq!(body.topics.as_slice(), &expected);
+}
+
+#[test]
+fn test_registration_limits() {
+    let s = setup_test();
+    let tournament_id = create(&s, 2);
+    let players = register_players(&s, tournament_id, 2);
+
+    let late = Address::generate(&s.env);
+    assert_tournament_error(
+        &s.client.try_register(&tournament_id, &late),
+        Error::TournamentFull,
+    );
+
+    s.client.start_tournament(&tournament_id);
+    assert_tournament_error(
+        &s.client.try_register(&tournament_id, &players[0]),
+        Error::RegistrationClosed,
+    );
+}
+
+#[test]
+fn test_cannot_start_with_one_entrant() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    register_players(&s, tournament_id, 1);
+
+    assert_tournament_error(
+        &s.client.try_start_tournament(&tournament_id),
+        Error::NotEnoughEntrants,
+    );
+}
+
+#[test]
+fn test_cancel_refunds_buy_ins() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    let players = register_players(&s, tournament_id, 3);
+
+    s.client.cancel_tournament(&tournament_id);
+
+    for player in &players {
+        assert_eq!(s.token.balance(player), 1000);
+    }
+    let tournament = s.client.get_tournament(&tournament_id);
+    assert_eq!(tournament.status, TournamentStatus::Cancelled);
+    assert_eq!(tournament.prize_pool, 0);
+}
+
+#[test]
+fn test_anyone_can_cancel_after_start_deadline() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    let players = register_players(&s, tournament_id, 2);
+
+    // Before the deadline only the organizer can cancel
+    s.env.ledger().set_sequence_number(120_960);
+    s.client.cancel_tournament(&tournament_id);
+    assert_eq!(s.env.auths()[0].0, s.organizer);
+
+    let tournament_id = create(&s, 8);
+    let late = register_players(&s, tournament_id, 1);
+    s.env.ledger().set_sequence_number(120_960 * 2 + 1);
+    s.client.cancel_tournament(&tournament_id);
+    assert!(s.env.auths().is_empty());
+
+    for player in players.iter().chain(late.iter()) {
+        assert_eq!(s.token.balance(player), 1000);
+    }
+    assert_eq!(
+        s.client.get_tournament(&tournament_id).status,
+        TournamentStatus::Cancelled
+    );
+}
+
+// ============================================================================
+// Bracket Tests
+// ============================================================================
+
+#[test]
+fn test_bracket_seeding() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    let players = register_players(&s, tournament_id, 4);
+
+    s.client.start_tournament(&tournament_id);
+
+    let tournament = s.client.get_tournament(&tournament_id);
+    assert_eq!(tournament.status, TournamentStatus::InProgress);
+    assert_eq!(tournament.rounds, 2);
+
+    let bracket = s.client.get_bracket(&tournament_id);
+    assert_eq!(bracket.len(), 2);
+    let first_round = bracket.get(0).unwrap();
+    assert_eq!(first_round.len(), 2);
+    assert_eq!(first_round.get(0).unwrap().player1, Some(players[0].clone()));
+    assert_eq!(first_round.get(0).unwrap().player2, Some(players[3].clone()));
+    assert_eq!(first_round.get(1).unwrap().player1, Some(players[1].clone()));
+    assert_eq!(first_round.get(1).unwrap().player2, Some(players[2].clone()));
+
+    let final_match = bracket.get(1).unwrap().get(0).unwrap();
+    assert_eq!(final_match.player1, None);
+    assert_eq!(final_match.player2, None);
+}
+
+#[test]
+fn test_top_seeds_get_byes() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    let players = register_players(&s, tournament_id, 5);
+
+    s.client.start_tournament(&tournament_id);
+
+    // 5 entrants fill an 8-slot bracket: seeds 0-2 get byes, seed 3 plays seed 4
+    let first_round = s.client.get_round(&tournament_id, &1);
+    assert_eq!(first_round.len(), 4);
+    let played = first_round.get(1).unwrap();
+    assert_eq!(played.player1, Some(players[3].clone()));
+    assert_eq!(played.player2, Some(players[4].clone()));
+    assert_eq!(played.winner, None);
+    for (index, seed) in [(0, 0), (2, 1), (3, 2)] {
+        let bye = first_round.get(index).unwrap();
+        assert_eq!(bye.player1, Some(players[seed].clone()));
+        assert_eq!(bye.player2, None);
+        assert_eq!(bye.winner, Some(players[seed].clone()));
+    }
+
+    // Seed 0 waits for the winner of seeds 3 and 4; seeds 1 and 2 meet
+    let second_round = s.client.get_round(&tournament_id, &2);
+    assert_eq!(second_round.get(0).unwrap().player1, Some(players[0].clone()));
+    assert_eq!(second_round.get(0).unwrap().player2, None);
+    assert_eq!(second_round.get(1).unwrap().player1, Some(players[1].clone()));
+    assert_eq!(second_round.get(1).unwrap().player2, Some(players[2].clone()));
+}
+
+#[test]
+fn test_six_entrants_give_byes_to_top_two_seeds() {
+    let s = setup_test();
+    let tournament_id = create(&s, 8);
+    let players = register_players(&s, tournament_id, 6);
+
+    s.client.start_tournament(&tournament_id);
+
+    let first_round = s.client.get_round(&tournament_id, &1);
+    let byes: std::vec::Vec<Address> = first_round
+        .iter()
+        .filter(|game| game.player2.is_none())
+        .map(|game| game.winner.unwrap())
+        .collect();
+    assert_eq!(byes, [players[0].clone(), players[1].clone()]);
+
+    // Seed 0 meets the winner of seeds 3 and 4, seed 1 the winner of seeds 2 and 5
+    assert_eq!(first_round.get(1).unwrap().player1, Some(players[3].clone()));
+    assert_eq!(first_round.get(1).unwrap().player2, Some(players[4].clone()));
+    assert_eq!(first_round.get(3).unwrap().player1, Some(players[2].clone()));
+    assert_eq!(first_round.get(3).unwrap().player2, Some(players[5].clone()));
+}
+
+#[test]
+fn test_largest_fields_start_within_write_limit() {
+    // 32 entrants fill the first round; 17 leave the most byes to advance
+    for entrants in [17, 32] {
+        let s = setup_test();
+        let tournament_id = create(&s, 32);
+        register_players(&s, tournament_id, entrants);
+
+        // The test environment enforces mainnet limits, so this would fail
+        // past 50 written entries
+        s.client.start_tournament(&tournament_id);
+        assert!(s.env.cost_estimate().resources().write_entries <= 50);
+        assert_eq!(s.client.get_tournament(&tournament_id).rounds, 5);
+    }
+}
+
+#[test]
+fn test_full_tournament_pays_placings() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+    let players = register_players(&s, tournament_id, 4);
+    s.client.start_tournament(&tournament_id);
+
+    // Semi-finals: seed 0 beats seed 3, seed 2 beats seed 1
+    assert_eq!(play(&s, tournament_id, 1, 0, Outcome::Player1), Some(players[0].clone()));
+    assert_eq!(play(&s, tournament_id, 1, 1, Outcome::Player2), Some(players[2].clone()));
+
+    let final_match = s.client.get_match(&tournament_id, &2, &0);
+    assert_eq!(final_match.player1, Some(players[0].clone()));
+    assert_eq!(final_match.player2, Some(players[2].clone()));
+
+    // Final: seed 2 wins
+    assert_eq!(play(&s, tournament_id, 2, 0, Outcome::Player2), Some(players[2].clone()));
+
+    let tournament = s.client.get_tournament(&tournament_id);
+    assert_eq!(tournament.status, TournamentStatus::Completed);
+    assert_eq!(tournament.champion, Some(players[2].clone()));
+
+    // Pool 400: 240 / 120 / 20 + 20
+    assert_eq!(s.token.balance(&players[2]), 900 + 240);
+    assert_eq!(s.token.balance(&players[0]), 900 + 120);
+    assert_eq!(s.token.balance(&players[1]), 900 + 20);
+    assert_eq!(s.token.balance(&players[3]), 900 + 20);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_two_player_tournament_champion_takes_unused_places() {
+    let s = setup_test();
+    let tournament_id = create(&s, 2);
+    let players = register_players(&s, tournament_id, 2);
+    s.client.start_tournament(&tournament_id);
+
+    play(&s, tournament_id, 1, 0, Outcome::Player1);
+
+    // No semi-finals, so the 3rd place share goes to the champion
+    assert_eq!(s.token.balance(&players[0]), 900 + 140);
+    assert_eq!(s.token.balance(&players[1]), 900 + 60);
+}
+
+// ============================================================================
+// Match Tests
+// ============================================================================
+
+#[test]
+fn test_drawn_match_is_replayed() {
+    let s = setup_test();
+    let tournament_id = create(&s, 2);
+    let players = register_players(&s, tournament_id, 2);
+    s.client.start_tournament(&tournament_id);
+
+    assert_eq!(play(&s, tournament_id, 1, 0, Outcome::Draw), None);
+    assert_eq!(s.client.get_match(&tournament_id, &1, &0).session_id, 0);
+
+    assert_eq!(play(&s, tournament_id, 1, 0, Outcome::Player2), Some(players[1].clone()));
+}
+
+#[test]
+fn test_match_state_errors() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+    register_players(&s, tournament_id, 4);
+
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &1, &0),
+        Error::TournamentNotInProgress,
+    );
+    s.client.start_tournament(&tournament_id);
+
+    // The final has no players yet
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &2, &0),
+        Error::MatchNotReady,
+    );
+    assert_tournament_error(
+        &s.client.try_report_result(&tournament_id, &1, &0),
+        Error::MatchNotStarted,
+    );
+
+    let session_id = s.client.start_match(&tournament_id, &1, &0);
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &1, &0),
+        Error::MatchAlreadyStarted,
+    );
+    assert_tournament_error(
+        &s.client.try_report_result(&tournament_id, &1, &0),
+        Error::MatchInProgress,
+    );
+
+    s.game.finish(&session_id, &Outcome::Player1);
+    s.client.report_result(&tournament_id, &1, &0);
+    assert_tournament_error(
+        &s.client.try_report_result(&tournament_id, &1, &0),
+        Error::MatchAlreadyDecided,
+    );
+    assert_tournament_error(
+        &s.client.try_get_match(&tournament_id, &3, &0),
+        Error::MatchNotFound,
+    );
+}
+
+#[test]
+fn test_match_session_is_opened_on_the_game() {
+    let s = setup_test();
+    let tournament_id = create(&s, 2);
+    let players = register_players(&s, tournament_id, 2);
+    s.client.start_tournament(&tournament_id);
+
+    let session_id = s.client.start_match(&tournament_id, &1, &0);
+
+    let hub = MockGameHubClient::new(&s.env, &s.client.get_hub());
+    let session = hub.get_session(&session_id);
+    assert_eq!(session.game_id, s.game.address);
+    assert_eq!(
+        session.players,
+        Vec::from_array(&s.env, [players[0].clone(), players[1].clone()])
+    );
+    assert_eq!(session.stakes, vec![&s.env, 0i128, 0]);
+}
+
+#[test]
+fn test_walkover_after_match_deadline() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+    let players = register_players(&s, tournament_id, 4);
+    s.client.start_tournament(&tournament_id);
+    assert_eq!(
+        s.client.get_match_deadline(&tournament_id, &1, &0).deadline,
+        34_560
+    );
+    assert_tournament_error(
+        &s.client.try_get_match_deadline(&tournament_id, &2, &0),
+        Error::MatchNotReady,
+    );
+
+    // Seed 3 shows up for the first semi-final; seed 0 never does
+    s.client.check_in(&tournament_id, &1, &0, &players[3]);
+    assert_tournament_error(
+        &s.client.try_check_in(&tournament_id, &1, &0, &players[1]),
+        Error::NotPlayer,
+    );
+    assert_tournament_error(
+        &s.client.try_claim_walkover(&tournament_id, &1, &0),
+        Error::DeadlineNotPassed,
+    );
+
+    s.env.ledger().set_sequence_number(34_561);
+    assert_eq!(s.client.claim_walkover(&tournament_id, &1, &0), players[3]);
+    // Nobody checked in for the other semi-final, so the better seed (seed 1) advances
+    assert_eq!(s.client.claim_walkover(&tournament_id, &1, &1), players[1]);
+    assert_tournament_error(
+        &s.client.try_claim_walkover(&tournament_id, &1, &1),
+        Error::MatchAlreadyDecided,
+    );
+
+    // The final's clock starts once both finalists are known
+    let final_deadline = s.client.get_match_deadline(&tournament_id, &2, &0);
+    assert_eq!(final_deadline.deadline, 34_561 + 34_560);
+    assert!(final_deadline.checked_in.is_empty());
+    assert_eq!(
+        play(&s, tournament_id, 2, 0, Outcome::Player1),
+        Some(players[3].clone())
+    );
+    assert_eq!(
+        s.client.get_tournament(&tournament_id).champion,
+        Some(players[3].clone())
+    );
+}
+
+#[test]
+fn test_walkover_goes_to_the_better_seed_in_later_rounds() {
+    let s = setup_test();
+    let tournament_id = create(&s, 4);
+    let players = register_players(&s, tournament_id, 4);
+    s.client.start_tournament(&tournament_id);
+
+    // Seed 3 comes through the upper semi-final, so it is the final's player1
+    play(&s, tournament_id, 1, 0, Outcome::Player2);
+    play(&s, tournament_id, 1, 1, Outcome::Player1);
+    let final_match = s.client.get_match(&tournament_id, &2, &0);
+    assert_eq!(final_match.player1, Some(players[3].clone()));
+    assert_eq!(final_match.player2, Some(players[1].clone()));
+
+    // Both check in but the final is never played: seed 1 takes the walkover
+    s.client.check_in(&tournament_id, &2, &0, &players[3]);
+    s.client.check_in(&tournament_id, &2, &0, &players[1]);
+    s.env.ledger().set_sequence_number(34_561);
+    assert_eq!(s.client.claim_walkover(&tournament_id, &2, &0), players[1]);
+    assert_eq!(
+        s.client.get_tournament(&tournament_id).champion,
+        Some(players[1].clone())
+    );
+}
+
+#[test]
+fn test_walkover_waits_for_running_session() {
+    let s = setup_test();
+    let tournament_id = create(&s, 2);
+    let players = register_players(&s, tournament_id, 2);
+    s.client.start_tournament(&tournament_id);
+    s.client.check_in(&tournament_id, &1, &0, &players[0]);
+
+    // A match started just before the deadline is still being played after it
+    s.env.ledger().set_sequence_number(34_550);
+    let session_id = s.client.start_match(&tournament_id, &1, &0);
+    s.env.ledger().set_sequence_number(34_561);
+    assert_tournament_error(
+        &s.client.try_claim_walkover(&tournament_id, &1, &0),
+        Error::MatchInProgress,
+    );
+
+    // Its result stands over the check-in
+    s.game.finish(&session_id, &Outcome::Player2);
+    assert_eq!(s.client.claim_walkover(&tournament_id, &1, &0), players[1]);
+}
+
+#[test]
+fn test_match_rejects_foreign_sessions() {
+    let s = setup_test();
+    let hub = MockGameHubClient::new(&s.env, &s.client.get_hub());
+    let foreign = ForeignGameClient::new(&s.env, &s.env.register(ForeignGame, (&hub.address,)));
+    hub.add_game(&foreign.address);
+
+    let tournament_id = s.client.create_tournament(
+        &s.organizer,
+        &foreign.address,
+        &s.token.address,
+        &100,
+        &2,
+        &vec![&s.env, 10_000u32],
+    );
+    let players = register_players(&s, tournament_id, 2);
+    s.client.start_tournament(&tournament_id);
+
+    // A session the game already ended for these players
+    foreign.prepare(&players[0], &players[1]);
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &1, &0),
+        Error::SessionMismatch,
+    );
+
+    // An active session between other players
+    let others = register_players(&s, create(&s, 2), 2);
+    let session_id = s.game.start_game(&others[0], &others[1], &0, &0);
+    foreign.use_session(&session_id);
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &1, &0),
+        Error::SessionMismatch,
+    );
+
+    // An active session for these players, opened by another game
+    let session_id = s.game.start_game(&players[0], &players[1], &0, &0);
+    foreign.use_session(&session_id);
+    assert_tournament_error(
+        &s.client.try_start_match(&tournament_id, &1, &0),
+        Error::SessionMismatch,
+    );
+}
+
@@ -6,31 +6,30 @@
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
 //!
+//! **Hidden Dealing:**
+//! Each round, both players commit to `keccak256(keccak256(secret))` for a
+//! 32-byte secret of their choosing. Once both have committed, each reveals
+//! their deal secret, `keccak256(secret)`. Commitments and deal secrets are
+//! passed on to the randomness beacon, whose output for the round seeds the
+//! deal, so neither player can steer it. Each player gets a face-up
+//! card from the deal seed and a hole card derived from the deal seed and
+//! their own secret, so only they can work it out. Every hit is dealt from a
+//! beacon round of its own: both players commit to a fresh hit secret and
+//! reveal it, and the card comes from that round's output, so nobody can see
+//! a hit coming before deciding to take it. Once both players have stuck,
+//! each reveals their secret, which proves their hole card. A player who does
+//! not reveal within a reveal window forfeits the game (`claim_forfeit`).
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
-    contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror,
+    contractevent, contractimpl, contracttype, vec
 };
-
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
+use sgs_common::admin::{self, Role};
+use sgs_common::{beacon, hub, schema, ttl, Outcome};
 
 // ============================================================================
 // Errors
@@ -51,18 +50,41 @@ pub enum Error {
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    SessionAlreadyExists = 12,
+    HandNotDealt = 13,
+    AlreadyCommitted = 14,
+    InvalidReveal = 15,
+    AlreadyRevealed = 16,
+    RevealWindowClosed = 17,
+    RevealWindowOpen = 18,
+    BothPlayersNotCommitted = 19,
+    SessionExpiring = 20,
+    HitPending = 21,
+    NoHitPending = 22,
 }
 
 // ============================================================================
-// Events (REMOVED)
+// Events
 // ============================================================================
 //
-// All events have been removed to avoid duplication with GameHub events.
-// Game lifecycle is tracked through GameHub's GameStarted and GameEnded events.
-// Game-specific state (hands, scores) can be queried via get_game().
-//
-// This keeps the event stream clean and makes GameHub the single source of
-// truth for game lifecycle monitoring.
+// Game lifecycle is tracked through GameHub's GameStarted and GameEnded events,
+// and game state (hands, scores) can be queried via get_game(). The one event
+// here records a drawn round, whose cards and secrets are cleared for the next
+// deal and could not be queried afterwards.
+
+/// A round whose hands tied; the game continues with a new deal
+#[contractevent(topics = ["round_drawn", "v1"])]
+pub struct RoundDrawn {
+    pub session_id: u32,
+    pub round: u32,
+    pub player1_secret: BytesN<32>,
+    pub player2_secret: BytesN<32>,
+    /// Player 1's face-up cards: the up card, then any hits
+    pub player1_hand: Bytes,
+    pub player2_hand: Bytes,
+    pub player1_hole_card: u32,
+    pub player2_hole_card: u32,
+}
 
 // ============================================================================
 // Data Types
@@ -75,11 +97,44 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_hand: Bytes,  // Each byte represents a card (1-13)
-    pub player2_hand: Bytes,  // Each byte represents a card (1-13)
+    pub player1_hand: Bytes,  // Face-up cards (1-13): the up card, then any hits
+    pub player2_hand: Bytes,  // Face-up cards (1-13): the up card, then any hits
+    /// `keccak256(keccak256(secret))` committed by player 1 for the round
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Player 1's deal secret, `keccak256(secret)`, once revealed
+    pub player1_deal_secret: Option<BytesN<32>>,
+    pub player2_deal_secret: Option<BytesN<32>>,
+    /// Beacon request the round's deal seed comes from; each round opens its own
+    pub beacon_request: u32,
+    /// Beacon output once both deal secrets are revealed; seeds the round's deal
+    pub deal_seed: Option<BytesN<32>>,
+    /// Cards hit so far this round, by either player
+    pub hits: u32,
+    /// Player whose hit waits on its beacon round
+    pub hitter: Option<Address>,
+    /// Beacon request the pending hit is dealt from
+    pub hit_request: u32,
+    /// `keccak256(hit_secret)` committed by player 1 for the pending hit
+    pub player1_hit_commitment: Option<BytesN<32>>,
+    pub player2_hit_commitment: Option<BytesN<32>>,
+    /// Player 1's hit secret for the pending hit, once revealed
+    pub player1_hit_secret: Option<BytesN<32>>,
+    pub player2_hit_secret: Option<BytesN<32>>,
+    /// Player 1's secret, once revealed
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
+    /// Player 1's hole card (1-13), once revealed
+    pub player1_hole_card: Option<u32>,
+    pub player2_hole_card: Option<u32>,
     pub player1_stuck: bool,
     pub player2_stuck: bool,
+    /// Last ledger on which to reveal: the deal secrets once both players have
+    /// committed, the hit secrets once a player hits, then the secrets once
+    /// both have stuck
+    pub reveal_deadline: Option<u32>,
     pub winner: Option<Address>,
+    pub is_draw: bool,
     pub round: u32,
 }
 
@@ -87,19 +142,93 @@ pub struct Game {
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
+    /// ID of the next beacon request this contract opens
+    NextBeaconRequest,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Storage Schema
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
+// Version 1: the original layout
+// Version 2: hidden dealing through the randomness beacon; `Game` gains
+//            commitments, revealed deal secrets, the beacon request and deal
+//            seed, a hit counter and the pending hit's beacon round, revealed
+//            secrets and hole cards, a reveal deadline and `is_draw`
+
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 2;
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// `Game` as stored at schema version 1
+#[contracttype(export = false)]
+#[derive(Clone)]
+struct GameV1 {
+    player1: Address,
+    player2: Address,
+    player1_points: i128,
+    player2_points: i128,
+    player1_hand: Bytes,
+    player2_hand: Bytes,
+    player1_stuck: bool,
+    player2_stuck: bool,
+    winner: Option<Address>,
+    round: u32,
+}
+
+impl From<GameV1> for Game {
+    fn from(old: GameV1) -> Self {
+        // Version 1 hands could be computed by anyone, so unfinished games go
+        // back to the commit phase for a fresh deal and `migrate` opens a
+        // beacon request for them; finished games keep their hands and result
+        let finished = old.winner.is_some();
+        let (player1_hand, player2_hand) = if finished {
+            (old.player1_hand, old.player2_hand)
+        } else {
+            (
+                Bytes::new(old.player1_hand.env()),
+                Bytes::new(old.player2_hand.env()),
+            )
+        };
+        Game {
+            player1: old.player1,
+            player2: old.player2,
+            player1_points: old.player1_points,
+            player2_points: old.player2_points,
+            player1_hand,
+            player2_hand,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_deal_secret: None,
+            player2_deal_secret: None,
+            beacon_request: 0,
+            deal_seed: None,
+            hits: 0,
+            hitter: None,
+            hit_request: 0,
+            player1_hit_commitment: None,
+            player2_hit_commitment: None,
+            player1_hit_secret: None,
+            player2_hit_secret: None,
+            player1_secret: None,
+            player2_secret: None,
+            player1_hole_card: None,
+            player2_hole_card: None,
+            player1_stuck: finished && old.player1_stuck,
+            player2_stuck: finished && old.player2_stuck,
+            reveal_deadline: None,
+            winner: old.winner,
+            is_draw: false,
+            round: old.round,
+        }
+    }
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Ledgers both players have to reveal, once both have committed and again
+/// once both have stuck (~1 hour). A hit's commits and reveals share one window.
+const REVEAL_WINDOW_LEDGERS: u32 = 720;
 
 // ============================================================================
 // Helper Functions
@@ -127,11 +256,131 @@ fn calculate_hand_value(hand: &Bytes) -> Result<u32, Error> {
     Ok(total)
 }
 
-/// Deal a card (1-13) using deterministic PRNG
-/// The seed is passed in (as Hash from keccak256)
-fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
-    env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=13) as u8
+/// Total value of a hand plus its hole card
+fn full_hand_value(hand: &Bytes, hole_card: u32) -> Result<u32, Error> {
+    calculate_hand_value(hand)?
+        .checked_add(card_value(hole_card))
+        .ok_or(Error::InvalidHandData)
+}
+
+/// Deal a card (1-13) from the keccak256 hash of `card_seed_bytes`.
+/// The hash is reduced directly rather than through the PRNG, so a card
+/// depends on nothing but the bytes it is dealt from.
+fn deal_card(env: &Env, card_seed_bytes: &Bytes) -> u8 {
+    let hash = env.crypto().keccak256(card_seed_bytes).to_array();
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&hash[..8]);
+    (u64::from_be_bytes(word) % 13 + 1) as u8
+}
+
+/// Deal a player's face-up card from the round's deal seed; `player` is 1 or 2
+fn up_card(env: &Env, deal_seed: &BytesN<32>, player: u8) -> u8 {
+    let mut card_seed_bytes = Bytes::from(deal_seed.clone());
+    card_seed_bytes.append(&Bytes::from_array(env, &[0, player])); // [card_index, player]
+    deal_card(env, &card_seed_bytes)
+}
+
+/// Deal a player's hole card from the round's deal seed and their secret
+fn hole_card(env: &Env, deal_seed: &BytesN<32>, secret: &BytesN<32>, player: u8) -> u8 {
+    let mut card_seed_bytes = Bytes::from(deal_seed.clone());
+    card_seed_bytes.append(&Bytes::from(secret.clone()));
+    card_seed_bytes.append(&Bytes::from_array(env, &[1, player])); // [card_index, player]
+    deal_card(env, &card_seed_bytes)
+}
+
+/// Deal a hit from the output of its own beacon round
+fn hit_card(env: &Env, hit_seed: &BytesN<32>) -> u8 {
+    let mut card_seed_bytes = Bytes::from(hit_seed.clone());
+    card_seed_bytes.push_back(2); // card_index
+    deal_card(env, &card_seed_bytes)
+}
+
+/// Open a beacon request for the session's players.
+/// Every deal and every hit draws its own output, so requests are numbered
+/// by this contract rather than keyed by session.
+fn request_beacon(env: &Env, session_id: u32, game: &Game) -> Result<u32, Error> {
+    let request_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextBeaconRequest)
+        .unwrap_or(1);
+    let next_request_id = request_id.checked_add(1).ok_or(Error::RoundOverflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextBeaconRequest, &next_request_id);
+
+    beacon::request_round(
+        env,
+        request_id,
+        session_id,
+        &game.player1,
+        &game.player2,
+        REVEAL_WINDOW_LEDGERS,
+    );
+    Ok(request_id)
+}
+
+/// Open the beacon request the round's deal seed comes from
+fn request_deal(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+    game.beacon_request = request_beacon(env, session_id, game)?;
+    Ok(())
+}
+
+/// Deal both up cards from the round's deal seed.
+/// Called once the beacon has combined both deal secrets, which were
+/// committed before either was known, so neither player could steer the
+/// seed or choose a secret that steers their hole card.
+fn deal_round(env: &Env, game: &mut Game, deal_seed: BytesN<32>) {
+    game.player1_hand = Bytes::new(env);
+    game.player1_hand.push_back(up_card(env, &deal_seed, 1));
+    game.player2_hand = Bytes::new(env);
+    game.player2_hand.push_back(up_card(env, &deal_seed, 2));
+    game.deal_seed = Some(deal_seed);
+}
+
+/// Last ledger of a reveal window opening now.
+/// The window must close before the hub session expires, or a player could
+/// reclaim their stake from the hub instead of revealing.
+fn open_reveal_window(env: &Env, session_id: u32) -> Result<u32, Error> {
+    let deadline = env
+        .ledger()
+        .sequence()
+        .saturating_add(REVEAL_WINDOW_LEDGERS);
+    let session = hub::find_session(env, session_id);
+    if session.is_some_and(|session| deadline >= session.expires_ledger) {
+        return Err(Error::SessionExpiring);
+    }
+    Ok(deadline)
+}
+
+/// Clear a dealt or abandoned hit so the next one can start
+fn clear_hit(game: &mut Game) {
+    game.hitter = None;
+    game.hit_request = 0;
+    game.player1_hit_commitment = None;
+    game.player2_hit_commitment = None;
+    game.player1_hit_secret = None;
+    game.player2_hit_secret = None;
+}
+
+/// Clear the round's cards and secrets so both players commit again
+fn reset_round(env: &Env, game: &mut Game) {
+    game.player1_hand = Bytes::new(env);
+    game.player2_hand = Bytes::new(env);
+    game.player1_commitment = None;
+    game.player2_commitment = None;
+    game.player1_deal_secret = None;
+    game.player2_deal_secret = None;
+    game.deal_seed = None;
+    game.hits = 0;
+    clear_hit(game);
+    game.player1_secret = None;
+    game.player2_secret = None;
+    game.player1_hole_card = None;
+    game.player2_hole_card = None;
+    game.player1_stuck = false;
+    game.player2_stuck = false;
+    game.reveal_deadline = None;
 }
 
 // ============================================================================
@@ -149,130 +398,252 @@ impl TwentyOneContract {
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
+        schema::init(&env, SCHEMA_VERSION);
     }
 
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
-    /// Each player is dealt 2 cards to start.
+    /// Cards are dealt once both players have committed with `commit_secret`
+    /// and revealed their deal secrets with `reveal_deal`.
     ///
     /// **CRITICAL:** This method requires authorization from THIS contract (not players).
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    ///
+    /// # Returns
+    /// * `u32` - Session ID allocated by the Game Hub
     pub fn start_game(
         env: Env,
-        session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             return Err(Error::SelfPlay);
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Require authentication from both players (each consents to the opponent and their stake)
+        player1.require_auth_for_args(vec![&env, player2.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player1.into_val(&env), player2_points.into_val(&env)]);
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub::client(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
+        let session_id = game_hub.start_game(
             &env.current_contract_address(),
-            &session_id,
             &player1,
             &player2,
             &player1_points,
             &player2_points,
         );
 
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Create game
-        let game = Game {
+        // The hub never reissues an ID, but never overwrite a game in progress
+        let game_key = DataKey::Game(session_id);
+        if env.storage().temporary().has(&game_key) {
+            return Err(Error::SessionAlreadyExists);
+        }
+
+        // Create game (hands are dealt once both deal secrets are revealed)
+        let mut game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
+            player1_hand: Bytes::new(&env),
+            player2_hand: Bytes::new(&env),
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_deal_secret: None,
+            player2_deal_secret: None,
+            beacon_request: 0,
+            deal_seed: None,
+            hits: 0,
+            hitter: None,
+            hit_request: 0,
+            player1_hit_commitment: None,
+            player2_hit_commitment: None,
+            player1_hit_secret: None,
+            player2_hit_secret: None,
+            player1_secret: None,
+            player2_secret: None,
+            player1_hole_card: None,
+            player2_hole_card: None,
             player1_stuck: false,
             player2_stuck: false,
+            reveal_deadline: None,
             winner: None,
+            is_draw: false,
             round: 1,
         };
 
+        // Both players commit to the beacon, which seeds the deal once both reveal
+        request_deal(&env, session_id, &mut game)?;
+
         // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
 
         // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        ttl::extend_temporary(&env, &game_key);
 
         // Event emitted by GameHub contract (GameStarted)
 
+        Ok(session_id)
+    }
+
+    /// Commit to the player's secret for the current round.
+    /// Once both players have committed, the window to reveal the deal
+    /// secrets opens.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - `keccak256(keccak256(secret))` for the player's 32-byte secret
+    pub fn commit_secret(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Either commitment may be the one to open the reveal window
+        let deadline = open_reveal_window(&env, session_id)?;
+
+        if player == game.player1 {
+            if game.player1_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player1_commitment = Some(commitment.clone());
+        } else if player == game.player2 {
+            if game.player2_commitment.is_some() {
+                return Err(Error::AlreadyCommitted);
+            }
+            game.player2_commitment = Some(commitment.clone());
+        } else {
+            return Err(Error::NotPlayer);
+        }
+        beacon::client(&env).commit(
+            &env.current_contract_address(),
+            &game.beacon_request,
+            &player,
+            &commitment,
+        );
+
+        // The window to reveal the deal secrets opens once both have committed
+        if game.player1_commitment.is_some() && game.player2_commitment.is_some() {
+            game.reveal_deadline = Some(deadline);
+        }
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the player's deal secret for the current round.
+    /// Can only be called after both players have committed and before the
+    /// reveal deadline. The second reveal finalizes the beacon output that
+    /// seeds the deal, and each player is dealt a face-up card and a hole card
+    /// that only they can work out.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `deal_secret` - `keccak256(secret)`, whose hash was committed in `commit_secret`
+    pub fn reveal_deal(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        deal_secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.player1_commitment.is_none() || game.player2_commitment.is_none() {
+            return Err(Error::BothPlayersNotCommitted);
+        }
+
+        let (commitment, revealed) = if player == game.player1 {
+            (&game.player1_commitment, &mut game.player1_deal_secret)
+        } else if player == game.player2 {
+            (&game.player2_commitment, &mut game.player2_deal_secret)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if let Some(deadline) = game.reveal_deadline {
+            if env.ledger().sequence() > deadline {
+                return Err(Error::RevealWindowClosed);
+            }
+        }
+        let hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&Bytes::from(deal_secret.clone()))
+            .into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed = Some(deal_secret.clone());
+
+        let beacon = beacon::client(&env);
+        beacon.reveal(
+            &env.current_contract_address(),
+            &game.beacon_request,
+            &player,
+            &Bytes::from(deal_secret),
+        );
+
+        // Both deal secrets are in, so the beacon has finalized the deal seed;
+        // play has no deadline until both players stick
+        if game.player1_deal_secret.is_some() && game.player2_deal_secret.is_some() {
+            let deal_seed = beacon
+                .get_output(&env.current_contract_address(), &game.beacon_request)
+                .expect("Beacon output not finalized");
+            deal_round(&env, &mut game, deal_seed);
+            game.reveal_deadline = None;
+        }
+
+        env.storage().temporary().set(&key, &game);
+
         Ok(())
     }
 
-    /// Player draws another card ("hit").
-    /// If the player's hand value exceeds 21, they bust and lose immediately.
+    /// Player asks for another card ("hit").
+    /// The card is dealt from a beacon round of its own, so it cannot be known
+    /// before both players commit to and reveal a fresh hit secret with
+    /// `commit_hit` and `reveal_hit`. Both must do so within the reveal window
+    /// this opens, or the one who did not forfeits. No one can hit again until
+    /// the card is dealt.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -288,8 +659,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.is_draw {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -301,6 +672,10 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        if game.deal_seed.is_none() {
+            return Err(Error::HandNotDealt);
+        }
+
         if is_player1 && game.player1_stuck {
             return Err(Error::AlreadyStuck);
         }
@@ -309,68 +684,184 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
+        if game.hitter.is_some() {
+            return Err(Error::HitPending);
+        }
+
+        // Both players commit and reveal for the card within one window
+        game.reveal_deadline = Some(open_reveal_window(&env, session_id)?);
+        game.hit_request = request_beacon(&env, session_id, &game)?;
+        game.hitter = Some(player);
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Commit to the player's hit secret for the pending hit.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - `keccak256(hit_secret)` for the player's 32-byte hit secret
+    pub fn commit_hit(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.hitter.is_none() {
+            return Err(Error::NoHitPending);
+        }
 
-        let card_count = if is_player1 {
-            game.player1_hand.len()
+        let committed = if player == game.player1 {
+            &mut game.player1_hit_commitment
+        } else if player == game.player2 {
+            &mut game.player2_hit_commitment
         } else {
-            game.player2_hand.len()
+            return Err(Error::NotPlayer);
         };
+        if committed.is_some() {
+            return Err(Error::AlreadyCommitted);
+        }
+        if let Some(deadline) = game.reveal_deadline {
+            if env.ledger().sequence() > deadline {
+                return Err(Error::RevealWindowClosed);
+            }
+        }
+        *committed = Some(commitment.clone());
 
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
+        beacon::client(&env).commit(
+            &env.current_contract_address(),
+            &game.hit_request,
+            &player,
+            &commitment,
+        );
 
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
+        env.storage().temporary().set(&key, &game);
 
-        // Add card to player's hand
-        if is_player1 {
-            game.player1_hand.push_back(new_card);
+        Ok(())
+    }
 
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player1_hand)?;
-            if hand_value > 21 {
-                // Player 1 busted, player 2 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
+    /// Reveal the player's hit secret for the pending hit.
+    /// Can only be called after both players have committed. The second reveal
+    /// finalizes the beacon round and deals the card face up to the player who
+    /// hit. If their face-up cards alone then exceed 21 once the smallest
+    /// possible hole card is added, they bust and lose immediately; any other
+    /// bust is settled when the hole cards are revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `hit_secret` - The hit secret whose hash was committed in `commit_hit`
+    pub fn reveal_hit(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hit_secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
 
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player2.clone());
-                env.storage().temporary().set(&key, &game);
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let Some(hitter) = game.hitter.clone() else {
+            return Err(Error::NoHitPending);
+        };
+        if game.player1_hit_commitment.is_none() || game.player2_hit_commitment.is_none() {
+            return Err(Error::BothPlayersNotCommitted);
+        }
+
+        let (commitment, revealed) = if player == game.player1 {
+            (&game.player1_hit_commitment, &mut game.player1_hit_secret)
+        } else if player == game.player2 {
+            (&game.player2_hit_commitment, &mut game.player2_hit_secret)
         } else {
-            game.player2_hand.push_back(new_card);
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if let Some(deadline) = game.reveal_deadline {
+            if env.ledger().sequence() > deadline {
+                return Err(Error::RevealWindowClosed);
+            }
+        }
+        let hash: BytesN<32> = env
+            .crypto()
+            .keccak256(&Bytes::from(hit_secret.clone()))
+            .into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed = Some(hit_secret.clone());
 
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player2_hand)?;
-            if hand_value > 21 {
-                // Player 2 busted, player 1 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
+        let beacon = beacon::client(&env);
+        beacon.reveal(
+            &env.current_contract_address(),
+            &game.hit_request,
+            &player,
+            &Bytes::from(hit_secret),
+        );
 
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player1.clone());
-                env.storage().temporary().set(&key, &game);
+        // Wait for the other player's reveal
+        if game.player1_hit_secret.is_none() || game.player2_hit_secret.is_none() {
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
+        }
 
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        // Both hit secrets are in, so the beacon has finalized the card's seed
+        let hit_seed = beacon
+            .get_output(&env.current_contract_address(), &game.hit_request)
+            .expect("Beacon output not finalized");
+        let new_card = hit_card(&env, &hit_seed);
+        game.hits = game.hits.checked_add(1).ok_or(Error::InvalidHandData)?;
+        clear_hit(&mut game);
+        game.reveal_deadline = None;
+
+        // Add card to the hitter's hand
+        let (hand, outcome) = if hitter == game.player1 {
+            (&mut game.player1_hand, Outcome::Player2)
+        } else {
+            (&mut game.player2_hand, Outcome::Player1)
+        };
+        hand.push_back(new_card);
+
+        // Even an ace in the hole cannot save 21 or more face up
+        if calculate_hand_value(hand)? >= 21 {
+            Self::settle(&env, session_id, &mut game, outcome)?;
+
+            // Return Ok - caller should check game.winner to see if game ended
+            return Ok(());
         }
 
-        // Store updated game
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
     /// Player chooses to stick (end their turn with current hand).
-    /// If both players have stuck, the game can be revealed.
+    /// Once both players have stuck, the reveal window opens.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -386,11 +877,16 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.is_draw {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // A player waiting on their own hit takes the card first
+        if game.hitter == Some(player.clone()) {
+            return Err(Error::HitPending);
+        }
+
         // Mark player as stuck
         if player == game.player1 {
             if game.player1_stuck {
@@ -406,24 +902,42 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        if game.deal_seed.is_none() {
+            return Err(Error::HandNotDealt);
+        }
+
+        // The reveal window opens once both players have stuck; either stick
+        // may be the one to open it, so both must fit in the hub session. A
+        // player with a hit pending cannot stick, so it never replaces a hit's window.
+        let deadline = open_reveal_window(&env, session_id)?;
+        if game.player1_stuck && game.player2_stuck {
+            game.reveal_deadline = Some(deadline);
+        }
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have stuck.
-    /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// Reveal the secret behind a player's hole card.
+    /// Can only be called after both players have stuck and before the reveal
+    /// deadline. The second reveal determines the winner (closest to 21
+    /// without going over) and ends the session; a draw starts a new round
+    /// for which both players commit again.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    ///
-    /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
-        // Get game from temporary storage
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The secret committed to in `commit_secret`
+    pub fn reveal_hand(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
         let key = DataKey::Game(session_id);
         let mut game: Game = env
             .storage()
@@ -431,90 +945,148 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let deal_seed = game.deal_seed.clone().ok_or(Error::HandNotDealt)?;
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotStuck)?;
+        if env.ledger().sequence() > deadline {
+            return Err(Error::RevealWindowClosed);
         }
 
-        // Check both players have stuck
-        if !game.player1_stuck || !game.player2_stuck {
-            return Err(Error::BothPlayersNotStuck);
+        let (seat, deal_secret, revealed, hole) = if player == game.player1 {
+            (
+                1,
+                &game.player1_deal_secret,
+                &mut game.player1_secret,
+                &mut game.player1_hole_card,
+            )
+        } else if player == game.player2 {
+            (
+                2,
+                &game.player2_deal_secret,
+                &mut game.player2_secret,
+                &mut game.player2_hole_card,
+            )
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
         }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if deal_secret.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        *hole = Some(hole_card(&env, &deal_seed, &secret, seat) as u32);
+        *revealed = Some(secret);
 
-        // Calculate hand values
-        let player1_value = calculate_hand_value(&game.player1_hand)?;
-        let player2_value = calculate_hand_value(&game.player2_hand)?;
+        // Wait for the other player's reveal
+        let (Some(player1_hole), Some(player2_hole)) =
+            (game.player1_hole_card, game.player2_hole_card)
+        else {
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
+        };
+
+        // Calculate hand values (a hand over 21 is bust)
+        let player1_value = full_hand_value(&game.player1_hand, player1_hole)?;
+        let player2_value = full_hand_value(&game.player2_hand, player2_hole)?;
+        let player1_score = if player1_value > 21 { 0 } else { player1_value };
+        let player2_score = if player2_value > 21 { 0 } else { player2_value };
 
         // Determine winner (closest to 21 without going over)
-        // Note: Bust conditions are already handled in hit(), so values should be <= 21
-        let winner = if player1_value > player2_value {
-            // Player 1 is closer to 21
-            game.player1.clone()
-        } else if player2_value > player1_value {
-            // Player 2 is closer to 21
-            game.player2.clone()
+        let outcome = if player1_score > player2_score {
+            Outcome::Player1
+        } else if player2_score > player1_score {
+            Outcome::Player2
         } else {
-            // Draw - deal new hands and continue
-            game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
-
-            // Reset stuck flags
-            game.player1_stuck = false;
-            game.player2_stuck = false;
-
-            // Clear hands
-            game.player1_hand = Bytes::new(&env);
-            game.player2_hand = Bytes::new(&env);
-
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
+            // Draw - record the round, then both players commit again for a new deal
+            RoundDrawn {
+                session_id,
+                round: game.round,
+                player1_secret: game.player1_secret.clone().unwrap(),
+                player2_secret: game.player2_secret.clone().unwrap(),
+                player1_hand: game.player1_hand.clone(),
+                player2_hand: game.player2_hand.clone(),
+                player1_hole_card: player1_hole,
+                player2_hole_card: player2_hole,
             }
+            .publish(&env);
+            game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
+            reset_round(&env, &mut game);
+            request_deal(&env, session_id, &mut game)?;
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
+        };
+        Self::settle(&env, session_id, &mut game, outcome)?;
 
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
+        Ok(())
+    }
 
-            // Store updated game and return error to indicate draw
-            env.storage().temporary().set(&key, &game);
+    /// End a game whose reveal deadline has passed without both reveals.
+    /// A player who revealed wins against one who did not; if neither
+    /// revealed, the game is a draw. This covers the deal secrets, the hit
+    /// secrets of a pending hit and the secrets revealed after both players
+    /// stick. Anyone can call this.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn claim_forfeit(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-            return Err(Error::Draw);
+        if game.winner.is_some() || game.is_draw {
+            return Err(Error::GameAlreadyEnded);
+        }
+        // Outside the reveal windows, the hub's session deadline refunds the stakes
+        let deadline = match game.reveal_deadline {
+            Some(deadline) => deadline,
+            None if game.deal_seed.is_none() => return Err(Error::HandNotDealt),
+            None => return Err(Error::BothPlayersNotStuck),
         };
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::RevealWindowOpen);
+        }
 
-        // Call GameHub FIRST (before setting winner)
-        let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
-
-        // Only update game with winner AFTER GameHub succeeds
-        game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
+        let revealed = if game.deal_seed.is_none() {
+            (
+                game.player1_deal_secret.is_some(),
+                game.player2_deal_secret.is_some(),
+            )
+        } else if game.hitter.is_some() {
+            (
+                game.player1_hit_secret.is_some(),
+                game.player2_hit_secret.is_some(),
+            )
+        } else {
+            (game.player1_secret.is_some(), game.player2_secret.is_some())
+        };
+        let outcome = match revealed {
+            (true, false) => Outcome::Player1,
+            (false, true) => Outcome::Player2,
+            _ => Outcome::Draw,
+        };
+        Self::settle(&env, session_id, &mut game, outcome)?;
 
-        Ok(winner)
+        Ok(game.winner)
     }
 
     /// Get game information.
+    /// Hole cards are only present once revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Game` - The game state (includes hands and winner after game ends)
+    /// * `Game` - The game state (includes face-up hands, revealed hole cards and winner)
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
         let key = DataKey::Game(session_id);
         env.storage()
@@ -524,13 +1096,14 @@ impl TwentyOneContract {
     }
 
     /// Get the current hand value for a player.
+    /// Counts the face-up cards, plus the hole card once revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player
     ///
     /// # Returns
-    /// * `u32` - The total value of the player's hand
+    /// * `u32` - The total value of the player's known cards
     pub fn get_hand_value(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
         let key = DataKey::Game(session_id);
         let game: Game = env
@@ -539,12 +1112,16 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if player == game.player1 {
-            calculate_hand_value(&game.player1_hand)
+        let (hand, hole) = if player == game.player1 {
+            (&game.player1_hand, game.player1_hole_card)
         } else if player == game.player2 {
-            calculate_hand_value(&game.player2_hand)
+            (&game.player2_hand, game.player2_hole_card)
         } else {
-            Err(Error::NotPlayer)
+            return Err(Error::NotPlayer);
+        };
+        match hole {
+            Some(hole) => full_hand_value(hand, hole),
+            None => calculate_hand_value(hand),
         }
     }
 
@@ -553,99 +1130,101 @@ impl TwentyOneContract {
     // ========================================================================
 
     /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+    fn end_game_with_hub(env: &Env, session_id: u32, outcome: Outcome) -> Result<(), Error> {
+        // Games started before the hub recorded sessions have nothing to settle
+        if hub::find_session(env, session_id).is_none() {
+            return Ok(());
+        }
+        let game_hub = hub::client(env);
 
         // Call the Game Hub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game(&session_id, &outcome);
 
         Ok(())
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+    /// Helper to end the game with the Game Hub and record its result
+    fn settle(env: &Env, session_id: u32, game: &mut Game, outcome: Outcome) -> Result<(), Error> {
+        // Call GameHub FIRST (before setting winner)
+        Self::end_game_with_hub(env, session_id, outcome)?;
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
+        // Only set winner AFTER GameHub succeeds
+        game.winner = match outcome {
+            Outcome::Player1 => Some(game.player1.clone()),
+            Outcome::Player2 => Some(game.player2.clone()),
+            Outcome::Draw | Outcome::Cancelled => None,
+        };
+        game.is_draw = outcome == Outcome::Draw;
         env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
-
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .set(&DataKey::Game(session_id), game);
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
     }
 
-    /// Get the current GameHub contract address
+    // ========================================================================
+    // Schema Migration
+    // ========================================================================
+
+    /// Get the schema version of the stored data
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    /// * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
     }
 
-    /// Set a new GameHub contract address
+    /// Convert games stored by an earlier build to the current layout (admin or upgrader).
+    /// Run after `upgrade`, once the beacon is set. Missing or already converted
+    /// games are skipped, so a large backlog can be migrated in batches; the
+    /// version only moves once the last batch is sent. Those builds chose their
+    /// own session IDs and the hub kept no record of them, so their games play
+    /// on without a hub session and end without settling anything at the hub.
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
-    }
-
-    /// Update the contract WASM hash (upgrade contract)
+    /// * `caller` - The admin or an account holding `Upgrader`
+    /// * `session_ids` - Session IDs of in-flight games
+    /// * `complete` - True on the last batch; records this build's schema version
     ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// # Returns
+    /// * `u32` - Number of games converted
+    pub fn migrate(env: Env, caller: Address, session_ids: Vec<u32>, complete: bool) -> u32 {
+        admin::require_role(&env, Role::Upgrader, &caller);
+
+        let mut converted = 0;
+        for session_id in session_ids.iter() {
+            let key = DataKey::Game(session_id);
+            if schema::migrate_temporary(&env, &key, "deal_seed", |old: GameV1| Game::from(old)) {
+                converted += 1;
+
+                // Games sent back to the commit phase deal through the beacon
+                let mut game: Game = env.storage().temporary().get(&key).unwrap();
+                if game.winner.is_none() && !game.is_draw {
+                    request_deal(&env, session_id, &mut game)
+                        .expect("Beacon request IDs exhausted");
+                    env.storage().temporary().set(&key, &game);
+                }
+            }
+        }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        if complete {
+            schema::write_version(&env, SCHEMA_VERSION);
+        }
+        converted
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_common::impl_admin!(TwentyOneContract);
+sgs_common::impl_hub_config!(TwentyOneContract);
+sgs_common::impl_beacon_config!(TwentyOneContract);
+sgs_common::impl_upgrade!(TwentyOneContract);
+
 // ============================================================================
 // Tests
 // ============================================================================
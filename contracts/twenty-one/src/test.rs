@@ -7,9 +7,17 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use crate::{
+    DataKey, Error, Game, GameV1, Outcome, RoundDrawn, TwentyOneContract, TwentyOneContractClient,
+};
+use randomness::{RandomnessContract, RandomnessContractClient};
+use sgs_common::admin::CommonKey;
+use sgs_common::{Session, SessionStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, Event as _, IntoVal,
+    Val,
+};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -21,24 +29,66 @@ pub struct MockGameHub;
 #[contractimpl]
 impl MockGameHub {
     pub fn start_game(
-        _env: Env,
+        env: Env,
         _game_id: Address,
-        _session_id: u32,
         _player1: Address,
         _player2: Address,
         _player1_points: i128,
         _player2_points: i128,
-    ) {
-        // Mock implementation - does nothing
+    ) -> u32 {
+        // Allocate sequential session IDs like the real hub
+        let session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("next_id"))
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &(session_id + 1));
+        session_id
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    /// Test hook: make the hub hand out an ID it has already issued
+    pub fn set_next_session_id(env: Env, session_id: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("next_id"), &session_id);
+    }
+
+    pub fn get_session(env: Env, _session_id: u32) -> Session {
+        Session {
+            game_id: env.storage().instance().get(&symbol_short!("game")).unwrap(),
+            players: vec![&env],
+            stakes: vec![&env],
+            status: SessionStatus::Active,
+            start_ledger: 0,
+            expires_ledger: env
+                .storage()
+                .instance()
+                .get(&symbol_short!("expires"))
+                .unwrap_or(u32::MAX),
+            token: None,
+            fee_bps: 0,
+        }
     }
 
-    pub fn add_game(_env: Env, _game_address: Address) {
+    /// Test hook: make every session expire after `ledger`
+    pub fn set_expires_ledger(env: Env, ledger: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("expires"), &ledger);
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _outcome: Outcome) {
         // Mock implementation - does nothing
     }
+
+    pub fn add_game(env: Env, game_address: Address) {
+        // Sessions are reported as the game's own
+        env.storage()
+            .instance()
+            .set(&symbol_short!("game"), &game_address);
+    }
 }
 
 // ============================================================================
@@ -81,12 +131,33 @@ fn setup_test() -> (
     // Register twenty-one as a whitelisted game (mock does nothing)
     game_hub.add_game(&contract_id);
 
+    // Deploy the randomness beacon the deals are seeded from
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
 
     (env, client, game_hub, player1, player2)
 }
 
+/// Point the game at a real Game Hub with 1000 points for each player
+fn use_real_hub(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    player1: &Address,
+    player2: &Address,
+) -> mock_game_hub::MockGameHubClient<'static> {
+    let admin = client.get_admin();
+    let hub_addr = env.register(mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(env, &hub_addr);
+    hub.add_game(&client.address);
+    hub.add_points(player1, &1000);
+    hub.add_points(player2, &1000);
+    client.set_hub(&admin, &hub_addr);
+    hub
+}
+
 /// Assert that a Result contains a specific twenty-one error
 ///
 /// This helper provides type-safe error assertions following Stellar/Soroban best practices.
@@ -151,117 +222,400 @@ fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     total
 }
 
+/// A player's deal secret: the hash of their secret
+fn deal_secret(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(secret.clone())).into()
+}
+
+/// A player's secret and the commitment they deal with
+fn secret_deal(env: &Env, seed: u8) -> (BytesN<32>, BytesN<32>) {
+    let secret = BytesN::from_array(env, &[seed; 32]);
+    let commitment = deal_secret(env, &deal_secret(env, &secret));
+    (secret, commitment)
+}
+
+/// Commit both players' secrets and reveal both deal secrets, which deals the round
+fn deal(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) -> (BytesN<32>, BytesN<32>) {
+    let (secret1, commitment1) = secret_deal(env, 1);
+    let (secret2, commitment2) = secret_deal(env, 2);
+    client.commit_secret(&session_id, player1, &commitment1);
+    client.commit_secret(&session_id, player2, &commitment2);
+    client.reveal_deal(&session_id, player1, &deal_secret(env, &secret1));
+    client.reveal_deal(&session_id, player2, &deal_secret(env, &secret2));
+    (secret1, secret2)
+}
+
+/// A player's hit secret and the commitment to it
+fn hit_secret(env: &Env, seed: u8) -> (BytesN<32>, BytesN<32>) {
+    let secret = BytesN::from_array(env, &[seed; 32]);
+    let commitment = deal_secret(env, &secret);
+    (secret, commitment)
+}
+
+/// Hit for `hitter` and run the hit's beacon round, with player 2 revealing
+/// the hit secret made from `seed2`
+fn draw_with(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    hitter: &Address,
+    player1: &Address,
+    player2: &Address,
+    seed2: u8,
+) {
+    let (secret1, commitment1) = hit_secret(env, 3);
+    let (secret2, commitment2) = hit_secret(env, seed2);
+    client.hit(&session_id, hitter);
+    client.commit_hit(&session_id, player1, &commitment1);
+    client.commit_hit(&session_id, player2, &commitment2);
+    client.reveal_hit(&session_id, player1, &secret1);
+    client.reveal_hit(&session_id, player2, &secret2);
+}
+
+/// Hit for `hitter` and run the hit's beacon round, which deals the card
+fn draw(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    hitter: &Address,
+    player1: &Address,
+    player2: &Address,
+) {
+    draw_with(env, client, session_id, hitter, player1, player2, 4);
+}
+
+/// Stick both players and reveal both secrets
+fn stick_and_reveal(
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    (secret1, secret2): (BytesN<32>, BytesN<32>),
+) {
+    client.stick(&session_id, player1);
+    client.stick(&session_id, player2);
+    client.reveal_hand(&session_id, player1, &secret1);
+    client.reveal_hand(&session_id, player2, &secret2);
+}
+
+/// Overwrite a player's face-up cards in storage
+fn set_hand(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player: u8,
+    cards: &[u8],
+) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env.storage().temporary().get(&key).unwrap();
+        let hand = Bytes::from_slice(env, cards);
+        if player == 1 {
+            game.player1_hand = hand;
+        } else {
+            game.player2_hand = hand;
+        }
+        env.storage().temporary().set(&key, &game);
+    });
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game_simple() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let session_id = client.start_game(&player1, &player2, &points, &points);
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
-    assert!(game.winner.is_none()); // Game is still active
+    assert!(game.winner.is_none() && !game.is_draw); // Game is still active
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert!(game.player1_hand.is_empty()); // Nothing dealt before both commit
+    assert!(game.deal_seed.is_none());
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
+
+    // Both players commit and reveal their deal secrets, which deals one face-up card each
+    let (secret1, secret2) = deal(&env, &client, session_id, &player1, &player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 1);
+    assert_eq!(game.player2_hand.len(), 1);
+    assert!(game.deal_seed.is_some());
+    assert!(game.player1_hole_card.is_none() && game.player2_hole_card.is_none());
 
-    // Both players stick immediately (no hits)
+    // Both players stick immediately (no hits), which opens the reveal window
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
+    assert_eq!(
+        client.get_game(&session_id).reveal_deadline,
+        Some(100 + 720)
+    );
 
-    // Reveal winner
-    let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    // One reveal shows that player's hole card only
+    client.reveal_hand(&session_id, &player2, &secret2);
+    let game = client.get_game(&session_id);
+    assert!(game.player2_hole_card.is_some() && game.player1_hole_card.is_none());
+    assert!(game.winner.is_none());
 
-    // Verify game is ended
+    // The second reveal settles the game, or starts a new round on a draw
+    client.reveal_hand(&session_id, &player1, &secret1);
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    match final_game.winner {
+        Some(winner) => assert!(winner == player1 || winner == player2),
+        None => assert_eq!(final_game.round, 2),
+    }
 }
 
 #[test]
-fn test_initial_cards_dealt() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_cards_dealt_once_both_reveal_deal_secrets() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
-    let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, commitment1) = secret_deal(&env, 1);
+    let (secret2, commitment2) = secret_deal(&env, 2);
+    client.commit_secret(&session_id, &player1, &commitment1);
+    assert!(client.get_game(&session_id).player1_hand.is_empty());
 
+    let result = client.try_commit_secret(&session_id, &player1, &commitment1);
+    assert_twenty_one_error(&result, Error::AlreadyCommitted);
+    let result = client.try_reveal_deal(&session_id, &player1, &deal_secret(&env, &secret1));
+    assert_twenty_one_error(&result, Error::BothPlayersNotCommitted);
+
+    // Both commitments open the window to reveal the deal secrets
+    client.commit_secret(&session_id, &player2, &commitment2);
+    let game = client.get_game(&session_id);
+    assert!(game.player1_hand.is_empty());
+    assert_eq!(game.reveal_deadline, Some(100 + 720));
+
+    // A deal secret must hash to the commitment; the secret itself does not
+    let result = client.try_reveal_deal(&session_id, &player1, &secret1);
+    assert_twenty_one_error(&result, Error::InvalidReveal);
+    client.reveal_deal(&session_id, &player1, &deal_secret(&env, &secret1));
+    assert!(client.get_game(&session_id).deal_seed.is_none());
+    let result = client.try_reveal_deal(&session_id, &player1, &deal_secret(&env, &secret1));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    client.reveal_deal(&session_id, &player2, &deal_secret(&env, &secret2));
     let game = client.get_game(&session_id);
 
-    // Each player should have exactly 2 cards
-    assert_eq!(game.player1_hand.len(), 2);
-    assert_eq!(game.player2_hand.len(), 2);
+    // The deal seed is the beacon's output for the round, and play has no deadline
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+    let output = beacon.get_output(&client.address, &game.beacon_request);
+    assert!(output.is_some());
+    assert_eq!(game.deal_seed, output);
+    assert!(game.reveal_deadline.is_none());
+
+    // Each player has a face-up card in valid range (1-13)
+    assert_eq!(game.player1_hand.len(), 1);
+    assert_eq!(game.player2_hand.len(), 1);
+    assert!((1..=13).contains(&game.player1_hand.get(0).unwrap()));
+    assert!((1..=13).contains(&game.player2_hand.get(0).unwrap()));
+}
 
-    // Cards should be in valid range (1-13)
-    for i in 0..game.player1_hand.len() {
-        let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
-    }
-    for i in 0..game.player2_hand.len() {
-        let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
-    }
+#[test]
+fn test_cannot_play_before_deal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_secret1, commitment1) = secret_deal(&env, 1);
+    client.commit_secret(&session_id, &player1, &commitment1);
+
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandNotDealt);
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandNotDealt);
+
+    // Committing is not enough; both deal secrets must be revealed
+    let (secret2, commitment2) = secret_deal(&env, 2);
+    client.commit_secret(&session_id, &player2, &commitment2);
+    client.reveal_deal(&session_id, &player2, &deal_secret(&env, &secret2));
+    let result = client.try_hit(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::HandNotDealt);
+}
+
+#[test]
+fn test_cannot_commit_when_session_expires_before_deal() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (_secret1, commitment1) = secret_deal(&env, 1);
+    let (_secret2, commitment2) = secret_deal(&env, 2);
+    client.commit_secret(&session_id, &player1, &commitment1);
+
+    // The second commitment at ledger 100 opens a window up to ledger 820
+    hub.set_expires_ledger(&(100 + 720));
+    let result = client.try_commit_secret(&session_id, &player2, &commitment2);
+    assert_twenty_one_error(&result, Error::SessionExpiring);
+
+    hub.set_expires_ledger(&(100 + 721));
+    client.commit_secret(&session_id, &player2, &commitment2);
+}
+
+#[test]
+fn test_cannot_stick_when_session_expires_before_reveals() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+
+    // A stick at ledger 100 can open a window up to ledger 820
+    hub.set_expires_ledger(&(100 + 720));
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::SessionExpiring);
+
+    hub.set_expires_ledger(&(100 + 721));
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    assert_eq!(
+        client.get_game(&session_id).reveal_deadline,
+        Some(100 + 720)
+    );
 }
 
 #[test]
 fn test_get_hand_value() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, _secret2) = deal(&env, &client, session_id, &player1, &player2);
 
-    // Get hand values
+    // Hand values count the face-up card only (1-10)
     let player1_value = client.get_hand_value(&session_id, &player1);
     let player2_value = client.get_hand_value(&session_id, &player2);
-
-    // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((1..=10).contains(&player1_value));
+    assert!((1..=10).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
     let expected_value1 = calculate_hand_value_helper(&game.player1_hand);
     let expected_value2 = calculate_hand_value_helper(&game.player2_hand);
-
     assert_eq!(player1_value, expected_value1);
     assert_eq!(player2_value, expected_value2);
+
+    // A revealed hole card counts too
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret1);
+    let hole = client.get_game(&session_id).player1_hole_card.unwrap();
+    let hole_value = if hole >= 10 { 10 } else { hole };
+    assert_eq!(
+        client.get_hand_value(&session_id, &player1),
+        player1_value + hole_value
+    );
 }
 
 #[test]
 fn test_hit_adds_card() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
-    // Player 1 hits
-    client.hit(&session_id, &player1);
+    // Player 1 hits (a single hit can never bust a lone face-up card)
+    draw(&env, &client, session_id, &player1, &player1, &player2);
 
     let after_hit_game = client.get_game(&session_id);
     assert_eq!(after_hit_game.player1_hand.len(), initial_hand_size + 1);
+    assert!(after_hit_game.winner.is_none());
+}
+
+#[test]
+fn test_hit_is_dealt_from_its_own_beacon_round() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let beacon = RandomnessContractClient::new(&env, &client.get_beacon());
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+
+    // Hitting only opens the card's beacon round
+    client.hit(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player2_hand.len(), 1);
+    assert_eq!(game.hitter, Some(player2.clone()));
+    assert_ne!(game.hit_request, game.beacon_request);
+    assert_eq!(game.reveal_deadline, Some(100 + 720));
+
+    // Nobody else can hit, and the hitter cannot stick, until the card is dealt
+    assert_twenty_one_error(&client.try_hit(&session_id, &player1), Error::HitPending);
+    assert_twenty_one_error(&client.try_stick(&session_id, &player2), Error::HitPending);
+
+    let (secret1, commitment1) = hit_secret(&env, 3);
+    let (secret2, commitment2) = hit_secret(&env, 4);
+    client.commit_hit(&session_id, &player2, &commitment2);
+    assert_twenty_one_error(
+        &client.try_reveal_hit(&session_id, &player2, &secret2),
+        Error::BothPlayersNotCommitted,
+    );
+    client.commit_hit(&session_id, &player1, &commitment1);
+    assert_twenty_one_error(
+        &client.try_reveal_hit(&session_id, &player1, &secret2),
+        Error::InvalidReveal,
+    );
+    client.reveal_hit(&session_id, &player2, &secret2);
+    assert_eq!(client.get_game(&session_id).player2_hand.len(), 1);
+
+    // The second reveal finalizes the round and deals the card from its output
+    client.reveal_hit(&session_id, &player1, &secret1);
+    let hit_seed = beacon
+        .get_output(&client.address, &game.hit_request)
+        .unwrap();
+    let card = env.as_contract(&client.address, || crate::hit_card(&env, &hit_seed));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player2_hand.get(1), Some(card));
+    assert_eq!(game.player1_hand.len(), 1);
+    assert_eq!(game.hits, 1);
+    assert!(game.hitter.is_none() && game.reveal_deadline.is_none());
+    assert_twenty_one_error(
+        &client.try_commit_hit(&session_id, &player1, &commitment1),
+        Error::NoHitPending,
+    );
+}
+
+#[test]
+fn test_public_game_state_does_not_predict_next_hit() {
+    // Play the same game twice; only player 2's hit secret differs
+    let play = |seed2: u8| {
+        let (env, client, _hub, player1, player2) = setup_test();
+        let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+        deal(&env, &client, session_id, &player1, &player2);
+
+        // Everything a client can read when player 1 decides to hit
+        let before_hit = client.get_game(&session_id);
+        draw_with(&env, &client, session_id, &player1, &player1, &player2, seed2);
+        let card = client.get_game(&session_id).player1_hand.get(1).unwrap();
+        (before_hit, card)
+    };
+
+    let (state_a, card_a) = play(4);
+    let (state_b, card_b) = play(5);
+    assert!(state_a.deal_seed.is_some());
+    assert_eq!(state_a, state_b);
+    assert_ne!(card_a, card_b);
 }
 
 #[test]
 fn test_stick_prevents_further_hits() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -273,30 +627,22 @@ fn test_stick_prevents_further_hits() {
 
 #[test]
 fn test_multiple_hits_allowed() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
-    // Player 1 hits multiple times (be careful not to bust in deterministic test)
-    // This test may fail if player1 busts, but we're testing the mechanics
-    let result1 = client.try_hit(&session_id, &player1);
-
-    // If first hit succeeds (didn't bust), try another
-    if result1.is_ok() {
-        let mid_game = client.get_game(&session_id);
-        assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
+    // Player 1 hits twice; the second hit may bust, both are valid
+    draw(&env, &client, session_id, &player1, &player1, &player2);
+    let mid_game = client.get_game(&session_id);
+    assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
 
-        let result2 = client.try_hit(&session_id, &player1);
-        // Could succeed or fail (bust), both are valid
-        if result2.is_ok() {
-            let final_game = client.get_game(&session_id);
-            assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
-        }
-    }
+    draw(&env, &client, session_id, &player1, &player1, &player2);
+    let final_game = client.get_game(&session_id);
+    assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
 }
 
 // ============================================================================
@@ -305,69 +651,140 @@ fn test_multiple_hits_allowed() {
 
 #[test]
 fn test_closer_to_21_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
 
-    // Both players stick
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Both players stick and reveal
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
 
-    let winner = client.reveal_winner(&session_id);
-
-    // Get final hand values
     let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
-
-    // Winner should be closer to 21
-    if player1_value > player2_value {
-        assert_eq!(winner, player1);
-    } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
+    if let Some(winner) = game.winner {
+        // Winner should be closer to 21 (two cards never bust)
+        let player1_value = client.get_hand_value(&session_id, &player1);
+        let player2_value = client.get_hand_value(&session_id, &player2);
+        if player1_value > player2_value {
+            assert_eq!(winner, player1);
+        } else {
+            assert_eq!(winner, player2);
+        }
     }
-    // If equal, test will continue (draw handling)
+    // If equal, a new round has started (draw handling)
 }
 
 #[test]
-fn test_reveal_winner_requires_both_stuck() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_hole_card_bust_settled_at_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
+
+    // Face-up 20 busts with anything but an ace in the hole; 2 never busts
+    set_hand(&env, &client, session_id, 1, &[10, 10]);
+    set_hand(&env, &client, session_id, 2, &[2]);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
+
+    let game = client.get_game(&session_id);
+    let expected = if game.player1_hole_card == Some(1) {
+        player1
+    } else {
+        player2
+    };
+    assert_eq!(game.winner, Some(expected));
+}
+
+#[test]
+fn test_reveal_requires_both_stuck() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, _secret2) = deal(&env, &client, session_id, &player1, &player2);
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
 
-    // Try to reveal winner - should fail
-    let result = client.try_reveal_winner(&session_id);
+    // Try to reveal - should fail
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1);
     assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
 }
 
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, secret2) = deal(&env, &client, session_id, &player1, &player2);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    let result = client.try_reveal_hand(&session_id, &player1, &secret2);
+    assert_twenty_one_error(&result, Error::InvalidReveal);
+
+    client.reveal_hand(&session_id, &player1, &secret1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1);
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    let non_player = Address::generate(&env);
+    let result = client.try_reveal_hand(&session_id, &non_player, &secret2);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_hole_card_comes_from_secret() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, _secret2) = deal(&env, &client, session_id, &player1, &player2);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret1);
+
+    // The revealed secret proves the hole card against the public deal seed
+    let game = client.get_game(&session_id);
+    let deal_seed = game.deal_seed.unwrap();
+    let expected = env.as_contract(&client.address, || {
+        crate::hole_card(&env, &deal_seed, &secret1, 1)
+    });
+    assert_eq!(game.player1_hole_card, Some(expected as u32));
+
+    // Other secrets give other hole cards on the same deal
+    let mut holes = vec![&env];
+    for seed in 3..9u8 {
+        let (secret, _commitment) = secret_deal(&env, seed);
+        let hole = env.as_contract(&client.address, || {
+            crate::hole_card(&env, &deal_seed, &secret, 1)
+        });
+        holes.push_back(hole as u32);
+    }
+    let first = game.player1_hole_card.unwrap();
+    assert!(holes.iter().any(|hole| hole != first));
+}
+
 // ============================================================================
 // Bust Tests
 // ============================================================================
 
 #[test]
 fn test_bust_detection() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     // Keep hitting until player1 busts
-    // Note: With enough hits, player will eventually bust (hand value > 21)
+    // Note: With enough hits, the face-up cards alone reach 21 or more
     let mut busted = false;
     for _ in 0..20 {
-        // Hit succeeds even when busting (returns Ok), but game ends
-        client.hit(&session_id, &player1);
+        // The card is dealt even when it busts (returns Ok), but game ends
+        draw(&env, &client, session_id, &player1, &player1, &player2);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
+            assert_eq!(winner, player2, "Player 2 should win when player 1 busts");
+            assert!(calculate_hand_value_helper(&game.player1_hand) >= 21);
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -381,24 +798,24 @@ fn test_bust_detection() {
 
 #[test]
 fn test_cannot_hit_after_bust() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     // Hit until bust (game ends)
     let mut did_bust = false;
     for _ in 0..20 {
-        client.hit(&session_id, &player1);
+        draw(&env, &client, session_id, &player2, &player1, &player2);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
+            assert_eq!(winner, player1);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
+            let result = client.try_hit(&session_id, &player2);
             assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
@@ -413,34 +830,175 @@ fn test_cannot_hit_after_bust() {
 
 #[test]
 fn test_draw_starts_new_round() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
+
+    let (secret1, secret2) = secrets.clone();
+    let deal_seed = client.get_game(&session_id).deal_seed.unwrap();
+    let hole = |secret: &BytesN<32>, seat: u8| {
+        env.as_contract(&client.address, || {
+            crate::hole_card(&env, &deal_seed, secret, seat) as u32
+        })
+    };
+    let drawn = RoundDrawn {
+        session_id,
+        round: 1,
+        player1_secret: secret1.clone(),
+        player2_secret: secret2.clone(),
+        player1_hand: Bytes::from_array(&env, &[10, 10, 5]),
+        player2_hand: Bytes::from_array(&env, &[10, 10, 5]),
+        player1_hole_card: hole(&secret1, 1),
+        player2_hole_card: hole(&secret2, 2),
+    };
+
+    // Both hands bust whatever the hole cards, which is a draw
+    set_hand(&env, &client, session_id, 1, &[10, 10, 5]);
+    set_hand(&env, &client, session_id, 2, &[10, 10, 5]);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
+
+    // The drawn round's cards and secrets are kept in an event
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [drawn.to_xdr(&env, &client.address)]
+    );
+
+    // Verify new round was created
+    let game_after = client.get_game(&session_id);
+    assert!(game_after.winner.is_none() && !game_after.is_draw);
+    assert_eq!(game_after.round, 2); // Round should increment
+    assert!(!game_after.player1_stuck); // Flags reset
+    assert!(!game_after.player2_stuck);
+    assert!(game_after.player1_commitment.is_none()); // Fresh secrets needed
+    assert!(game_after.player1_deal_secret.is_none());
+    assert!(game_after.deal_seed.is_none());
+    assert!(game_after.player1_hand.is_empty());
+    assert_eq!(game_after.hits, 0);
+    assert_eq!(game_after.beacon_request, 2); // The new deal has its own beacon request
+
+    // Both players commit again for the new deal
+    let (secret1, commitment1) = secret_deal(&env, 3);
+    let (secret2, commitment2) = secret_deal(&env, 4);
+    client.commit_secret(&session_id, &player1, &commitment1);
+    client.commit_secret(&session_id, &player2, &commitment2);
+    client.reveal_deal(&session_id, &player1, &deal_secret(&env, &secret1));
+    client.reveal_deal(&session_id, &player2, &deal_secret(&env, &secret2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 1); // New cards dealt
+    assert_eq!(game.player2_hand.len(), 1);
+}
+
+// ============================================================================
+// Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_player_who_never_reveals_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    // Both players stick immediately
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, secret2) = deal(&env, &client, session_id, &player1, &player2);
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player2, &secret2);
 
+    // Player 1 still has until the deadline to reveal
+    env.ledger().set_sequence_number(100 + 720);
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::RevealWindowOpen);
+
+    env.ledger().set_sequence_number(100 + 721);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1);
+    assert_twenty_one_error(&result, Error::RevealWindowClosed);
+
+    assert_eq!(client.claim_forfeit(&session_id), Some(player2.clone()));
     let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
-
-    // Only test draw behavior if hands are equal
-    if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
-
-        // Verify new round was created
-        let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
-    }
-    // If not a draw, test passes (no assertion needed)
+    assert_eq!(game.winner, Some(player2));
+    assert!(game.player1_hole_card.is_none());
+
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_player_who_never_reveals_deal_secret_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, commitment1) = secret_deal(&env, 1);
+    let (secret2, commitment2) = secret_deal(&env, 2);
+    client.commit_secret(&session_id, &player1, &commitment1);
+
+    // Until both have committed there is no reveal window; the hub deadline applies
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::HandNotDealt);
+
+    client.commit_secret(&session_id, &player2, &commitment2);
+    client.reveal_deal(&session_id, &player2, &deal_secret(&env, &secret2));
+
+    // Player 1 could see the deal coming and hold back, but only until the deadline
+    env.ledger().set_sequence_number(100 + 720);
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::RevealWindowOpen);
+
+    env.ledger().set_sequence_number(100 + 721);
+    let result = client.try_reveal_deal(&session_id, &player1, &deal_secret(&env, &secret1));
+    assert_twenty_one_error(&result, Error::RevealWindowClosed);
+
+    assert_eq!(client.claim_forfeit(&session_id), Some(player2.clone()));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2));
+    assert!(game.deal_seed.is_none());
+}
+
+#[test]
+fn test_player_who_never_reveals_hit_secret_forfeits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+    let (secret1, commitment1) = hit_secret(&env, 3);
+    let (secret2, commitment2) = hit_secret(&env, 4);
+
+    // Player 1 hits and player 2 stands, but both take part in the card's round
+    env.ledger().set_sequence_number(200);
+    client.stick(&session_id, &player2);
+    client.hit(&session_id, &player1);
+    client.commit_hit(&session_id, &player1, &commitment1);
+    client.commit_hit(&session_id, &player2, &commitment2);
+    client.reveal_hit(&session_id, &player1, &secret1);
+
+    // Player 2 could see the card coming and hold back, but only until the deadline
+    env.ledger().set_sequence_number(200 + 720);
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::RevealWindowOpen);
+
+    env.ledger().set_sequence_number(200 + 721);
+    let result = client.try_reveal_hit(&session_id, &player2, &secret2);
+    assert_twenty_one_error(&result, Error::RevealWindowClosed);
+
+    assert_eq!(client.claim_forfeit(&session_id), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
+}
+
+#[test]
+fn test_forfeit_by_both_players_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+    client.stick(&session_id, &player1);
+
+    // Without both sticks there is no reveal window; the hub deadline applies
+    let result = client.try_claim_forfeit(&session_id);
+    assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
+
+    client.stick(&session_id, &player2);
+    env.ledger().set_sequence_number(100 + 721);
+
+    assert_eq!(client.claim_forfeit(&session_id), None);
+    assert!(client.get_game(&session_id).is_draw);
 }
 
 // ============================================================================
@@ -449,10 +1007,10 @@ fn test_draw_starts_new_round() {
 
 #[test]
 fn test_cannot_stick_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -467,8 +1025,7 @@ fn test_non_player_cannot_hit() {
     let (env, client, _hub, player1, player2) = setup_test();
     let non_player = Address::generate(&env);
 
-    let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to hit
     let result = client.try_hit(&session_id, &non_player);
@@ -480,21 +1037,32 @@ fn test_non_player_cannot_stick() {
     let (env, client, _hub, player1, player2) = setup_test();
     let non_player = Address::generate(&env);
 
-    let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
     assert_twenty_one_error(&result, Error::NotPlayer);
 }
 
+#[test]
+fn test_non_player_cannot_commit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let non_player = Address::generate(&env);
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    // Non-player tries to commit
+    let (_secret, commitment) = secret_deal(&env, 1);
+    let result = client.try_commit_secret(&session_id, &non_player, &commitment);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
+
 #[test]
 fn test_non_player_cannot_get_hand_value() {
     let (env, client, _hub, player1, player2) = setup_test();
     let non_player = Address::generate(&env);
 
-    let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -519,9 +1087,10 @@ fn test_cannot_stick_nonexistent_game() {
 
 #[test]
 fn test_cannot_reveal_nonexistent_game() {
-    let (_env, client, _hub, _player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
-    let result = client.try_reveal_winner(&999);
+    let (secret, _commitment) = secret_deal(&env, 1);
+    let result = client.try_reveal_hand(&999, &player1, &secret);
     assert_twenty_one_error(&result, Error::GameNotFound);
 }
 
@@ -535,70 +1104,60 @@ fn test_cannot_get_nonexistent_game() {
 
 #[test]
 fn test_cannot_hit_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
 
-    // Both players stick
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Player 2 busts on the reveal, so the game ends with a winner
+    set_hand(&env, &client, session_id, 2, &[10, 10, 5]);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
 
-    // Reveal winner (may panic if draw, but that's okay)
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
-        // Game ended successfully
-        // Try to hit after game ended
-        let hit_result = client.try_hit(&session_id, &player1);
-        assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
-    }
+    // Try to hit after game ended
+    let hit_result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_cannot_stick_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
 
-    // Player 1 sticks
-    client.stick(&session_id, &player1);
+    set_hand(&env, &client, session_id, 2, &[10, 10, 5]);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
 
-    // Player 2 sticks
-    client.stick(&session_id, &player2);
-
-    // Reveal winner
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
-        // Game ended, verify stuck flag is set
-        let game = client.get_game(&session_id);
-        assert!(game.player1_stuck);
+    // Game ended, verify stuck flag is set
+    let game = client.get_game(&session_id);
+    assert!(game.player1_stuck);
 
-        // Try to stick again after game ended
-        let stick_result = client.try_stick(&session_id, &player1);
-        assert_twenty_one_error(&stick_result, Error::GameAlreadyEnded);
-    }
+    // Try to stick again after game ended
+    let stick_result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&stick_result, Error::GameAlreadyEnded);
 }
 
 #[test]
-fn test_reveal_winner_idempotent() {
-    let (_env, client, _hub, player1, player2) = setup_test();
-
-    let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
-
-    // First reveal
-    let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
+fn test_cannot_reveal_after_game_ended() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
-        // Second reveal should return same winner (idempotent)
-        let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
-    }
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let (secret1, secret2) = deal(&env, &client, session_id, &player1, &player2);
+
+    set_hand(&env, &client, session_id, 2, &[10, 10, 5]);
+    stick_and_reveal(
+        &client,
+        session_id,
+        &player1,
+        &player2,
+        (secret1.clone(), secret2),
+    );
+
+    // The result is final
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
 }
 
 // ============================================================================
@@ -611,30 +1170,18 @@ fn test_multiple_games_independent() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    let session1 = 20u32;
-    let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
-
-    // Play both games independently (use try_ methods to handle potential busts)
-    let _ = client.try_hit(&session1, &player1);
-    let _ = client.try_hit(&session2, &player3);
+    let session1 = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let session2 = client.start_game(&player3, &player4, &50_0000000, &50_0000000);
+    deal(&env, &client, session1, &player1, &player2);
+    deal(&env, &client, session2, &player3, &player4);
 
-    // Only continue if games haven't ended from busts
-    let game1_check = client.get_game(&session1);
-    let game2_check = client.get_game(&session2);
-
-    if game1_check.winner.is_none() {
-        client.stick(&session1, &player1);
-        client.stick(&session1, &player2);
-    }
-
-    if game2_check.winner.is_none() {
-        client.stick(&session2, &player3);
-        client.stick(&session2, &player4);
-    }
+    // Play both games independently
+    draw(&env, &client, session1, &player1, &player1, &player2);
+    draw(&env, &client, session2, &player3, &player3, &player4);
+    client.stick(&session1, &player1);
+    client.stick(&session2, &player3);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -642,6 +1189,9 @@ fn test_multiple_games_independent() {
 
     assert_eq!(game1.player1, player1);
     assert_eq!(game2.player1, player3);
+    assert_eq!(game1.player1_hand.len(), 2);
+    assert_eq!(game2.player1_hand.len(), 2);
+    assert!(game1.reveal_deadline.is_none() && game2.reveal_deadline.is_none());
 }
 
 #[test]
@@ -650,11 +1200,9 @@ fn test_multiple_sessions() {
     let player3 = Address::generate(&env);
     let player4 = Address::generate(&env);
 
-    let session1 = 22u32;
-    let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    let session1 = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    let session2 = client.start_game(&player3, &player4, &50_0000000, &50_0000000);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -668,23 +1216,32 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 24u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    let session_id = client.start_game(&player1, &player2, &points1, &points2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Play out the round (may be draw)
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
+}
+
+#[test]
+fn test_reused_session_id_rejected() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
-    // Try to reveal winner (may be draw)
-    let _ = client.try_reveal_winner(&session_id);
+    // A hub that hands out the same ID again must not overwrite the live game
+    hub.set_next_session_id(&session_id);
+    let result = client.try_start_game(&player1, &player2, &100_0000000, &100_0000000);
+    assert_twenty_one_error(&result, Error::SessionAlreadyExists);
 }
 
 // ============================================================================
@@ -695,10 +1252,11 @@ fn test_asymmetric_points() {
 fn test_face_cards_worth_10() {
     // This is a deterministic test of card value logic
     // We can't control what cards are dealt, but we can verify the hand value calculation
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+    draw(&env, &client, session_id, &player1, &player1, &player2);
 
     let game = client.get_game(&session_id);
 
@@ -708,16 +1266,17 @@ fn test_face_cards_worth_10() {
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
 #[test]
 fn test_hand_value_calculation() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+    deal(&env, &client, session_id, &player1, &player2);
+    draw(&env, &client, session_id, &player1, &player1, &player2);
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -764,7 +1323,7 @@ fn test_get_hub() {
 }
 
 #[test]
-fn test_set_admin() {
+fn test_admin_transfer() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -775,11 +1334,14 @@ fn test_set_admin() {
     let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
-    // Set new admin
-    client.set_admin(&new_admin);
+    // Control only moves once the new admin accepts
+    client.propose_admin(&new_admin, &100);
+    assert_eq!(client.get_admin(), admin);
 
+    client.accept_admin();
     let retrieved_admin = client.get_admin();
     assert_eq!(retrieved_admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
 }
 
 #[test]
@@ -795,7 +1357,7 @@ fn test_set_hub() {
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     // Set new game_hub address
-    client.set_hub(&new_hub_addr);
+    client.set_hub(&admin, &new_hub_addr);
 
     let retrieved_hub = client.get_hub();
     assert_eq!(retrieved_hub, new_hub_addr);
@@ -815,43 +1377,148 @@ fn test_upgrade_function_exists() {
     // Verify the upgrade function exists and can be called
     // Note: We can't test actual upgrade without real WASM files
     let new_wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
-    let result = client.try_upgrade(&new_wasm_hash);
+    let result = client.try_upgrade(&admin, &new_wasm_hash);
 
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
 
 // ============================================================================
-// Determinism Tests
+// Schema Migration Tests
 // ============================================================================
 
+/// A version 1 game in which the hands were dealt in plain view
+fn v1_game(env: &Env, player1: &Address, player2: &Address, winner: Option<Address>) -> GameV1 {
+    GameV1 {
+        player1: player1.clone(),
+        player2: player2.clone(),
+        player1_points: 100_0000000,
+        player2_points: 100_0000000,
+        player1_hand: Bytes::from_array(env, &[10, 9]),
+        player2_hand: Bytes::from_array(env, &[3, 4]),
+        player1_stuck: true,
+        player2_stuck: false,
+        winner,
+        round: 1,
+    }
+}
+
+/// Rewrite a game and the stored version the way an earlier build left them
+fn write_legacy(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    game: impl IntoVal<Env, Val>,
+) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Game(session_id);
+        env.storage().temporary().set(&key, &game.into_val(env));
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
 #[test]
-fn test_deterministic_card_dealing() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_version_and_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    let session_id = 27u32;
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, ());
+
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
 
-    // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-    let game1 = client.get_game(&session_id);
+    // A new deployment writes the current layout; missing games are skipped
+    assert_eq!(client.version(), 2);
+    assert_eq!(client.migrate(&admin, &vec![&env, 1], &true), 0);
+    assert_eq!(client.version(), 2);
+}
 
-    // Start second game with same session_id in new environment (should be identical)
-    let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
-    let game2 = client2.get_game(&session_id);
+#[test]
+fn test_migrate_redeals_in_flight_games() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    // Simulate an upgrade from a build that wrote the version 1 layout
+    let in_flight = v1_game(&env, &player1, &player2, None);
+    write_legacy(&env, &client, session_id, in_flight);
+    assert_eq!(client.version(), 1);
+    assert!(client.try_get_game(&session_id).is_err());
+
+    // A partial batch converts its games but leaves the version alone
+    assert_eq!(
+        client.migrate(&admin, &vec![&env, session_id, 999], &false),
+        1
+    );
+    assert_eq!(client.version(), 1);
+
+    // The version 1 hands were predictable, so the game is back in the commit phase
+    let game = client.get_game(&session_id);
+    assert!(game.player1_hand.is_empty() && game.player2_hand.is_empty());
+    assert!(!game.player1_stuck && game.deal_seed.is_none());
+    assert_eq!(game.beacon_request, 2);
+
+    // Already converted games are skipped, and the last batch moves the version
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 0);
+    assert_eq!(client.version(), 2);
+    let secrets = deal(&env, &client, session_id, &player1, &player2);
+    stick_and_reveal(&client, session_id, &player1, &player2, secrets);
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_some() || game.round == 2);
+}
 
-    // Note: Since we generate new addresses each time, the cards will be different
-    // But we can verify that within the same session, cards are consistent
-    assert_eq!(game1.player1_hand.len(), 2);
-    assert_eq!(game2.player1_hand.len(), 2);
+#[test]
+fn test_migrate_plays_out_games_the_hub_never_recorded() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let hub = use_real_hub(&env, &client, &player1, &player2);
+    let session_id = 7;
+
+    // The original build chose session 7 itself, so the hub has no record of it
+    write_legacy(&env, &client, session_id, v1_game(&env, &player1, &player2, None));
+    assert!(hub.try_get_session(&session_id).is_err());
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+
+    // The game plays out, redealing drawn rounds; nothing was locked at the
+    // hub, so no points move
+    play_to_end(&env, &client, session_id, &player1, &player2);
+    assert_eq!(hub.get_balance(&player1), 1000);
+    assert_eq!(hub.get_balance(&player2), 1000);
+
+    // Games started after the upgrade still settle through the hub
+    hub.reserve_session_ids(&session_id);
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    play_to_end(&env, &client, session_id, &player1, &player2);
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
 }
 
+#[test]
+fn test_migrate_keeps_version_1_results() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
+
+    let finished = v1_game(&env, &player1, &player2, Some(player1.clone()));
+    write_legacy(&env, &client, session_id, finished);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, session_id], &true), 1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.player1_hand, Bytes::from_array(&env, &[10, 9]));
+    assert_eq!(client.get_hand_value(&session_id, &player1), 19);
+    assert!(!game.is_draw);
+}
+
+// ============================================================================
+// Round Tests
+// ============================================================================
+
 #[test]
 fn test_round_counter() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let session_id = client.start_game(&player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.round, 1); // First round
@@ -865,8 +1532,105 @@ fn test_round_counter() {
 fn test_cannot_play_against_self() {
     let (_env, client, _hub, player1, _player2) = setup_test();
 
-    let session_id = 29u32;
     // Try to start game where player1 plays against themselves
-    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
+    let result = client.try_start_game(&player1, &player1, &100_0000000, &100_0000000);
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
+
+// ============================================================================
+// WASM Upgrade Test
+// ============================================================================
+// Games are started on the baseline release, deployed from its WASM with the
+// baseline hub, and finished after upgrading both contracts to this build.
+
+mod baseline {
+    pub mod game {
+        soroban_sdk::contractimport!(file = "fixtures/twenty_one_baseline.wasm");
+    }
+
+    pub mod hub {
+        soroban_sdk::contractimport!(file = "../mock-game-hub/fixtures/mock_game_hub_baseline.wasm");
+    }
+}
+
+/// Swap this build's code in under a contract deployed from an older WASM.
+/// Registering it runs the constructor, which an on-chain upgrade does not:
+/// the admin and hub it writes are the ones already stored, and the schema
+/// version it records is removed so the data reads as unmigrated.
+fn upgrade_in_place(env: &Env, contract_id: &Address, admin: &Address, hub: &Address) {
+    env.register_at(contract_id, TwentyOneContract, (admin, hub));
+    env.as_contract(contract_id, || {
+        env.storage().instance().remove(&CommonKey::SchemaVersion);
+    });
+}
+
+/// Deal and reveal rounds until one decides the game
+fn play_to_end(
+    env: &Env,
+    client: &TwentyOneContractClient<'static>,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    while client.get_game(&session_id).winner.is_none() {
+        let secrets = deal(env, client, session_id, player1, player2);
+        stick_and_reveal(client, session_id, player1, player2, secrets);
+    }
+}
+
+#[test]
+fn test_upgrade_from_baseline_wasm() {
+    let (env, _client, _hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+
+    // The baseline release starts three games with IDs it picks itself
+    let hub_addr = env.register(baseline::hub::WASM, ());
+    let contract_id = env.register(baseline::game::WASM, (&admin, &hub_addr));
+    let old = baseline::game::Client::new(&env, &contract_id);
+    for session_id in 1..=3 {
+        old.start_game(&session_id, &player1, &player2, &100, &100);
+    }
+    old.hit(&1, &player1);
+    old.stick(&1, &player1);
+    old.stick(&2, &player1);
+    old.stick(&2, &player2);
+    let finished_winner = old.reveal_winner(&2);
+    let finished_hand = old.get_game(&2).player1_hand;
+
+    // The baseline hub kept no state, so this build's hub replaces it; its
+    // counter moves past the IDs the games already hold
+    env.register_at(&hub_addr, mock_game_hub::MockGameHub, (&admin,));
+    let hub = mock_game_hub::MockGameHubClient::new(&env, &hub_addr);
+    hub.add_game(&contract_id);
+    hub.add_points(&player1, &1000);
+    hub.add_points(&player2, &1000);
+    hub.reserve_session_ids(&3);
+
+    upgrade_in_place(&env, &contract_id, &admin, &hub_addr);
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.get_admin(), admin);
+    let beacon_addr = env.register(RandomnessContract, (&admin,));
+    client.set_beacon(&admin, &beacon_addr);
+
+    assert_eq!(client.migrate(&admin, &vec![&env, 1, 2, 3], &true), 3);
+    assert_eq!(client.version(), 2);
+
+    // The finished game keeps its hands and result; the others are redealt
+    // through the beacon and play out, settling nothing at the hub
+    let finished = client.get_game(&2);
+    assert_eq!(finished.winner, Some(finished_winner));
+    assert_eq!(finished.player1_hand, finished_hand);
+    for session_id in [1, 3] {
+        let game = client.get_game(&session_id);
+        assert!(game.player1_hand.is_empty() && !game.player1_stuck);
+        play_to_end(&env, &client, session_id, &player1, &player2);
+    }
+    assert_eq!(hub.get_balance(&player1), 1000);
+
+    // New games take hub-allocated IDs past the baseline ones and settle there
+    let session_id = client.start_game(&player1, &player2, &100, &100);
+    assert_eq!(session_id, 4);
+    play_to_end(&env, &client, session_id, &player1, &player2);
+    assert_ne!(hub.get_session(&session_id).status, SessionStatus::Active);
+}
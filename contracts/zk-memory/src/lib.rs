@@ -18,9 +18,11 @@
 //! - Proofs verified on-chain using Stellar Protocol 25 BN254 operations
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contractclient, contracterror, 
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror, 
     contractimpl, contracttype, vec
 };
+use sgs_common::admin;
+use sgs_common::{hub, schema, ttl, Outcome};
 
 // ============================================================================
 // Verification Key for Noir Circuit (Groth16 on BN254)
@@ -54,27 +56,6 @@ use soroban_sdk::{
 // NOTE: Keep this commented out until vk.bin exists, otherwise compilation will fail
 // const VERIFICATION_KEY: &[u8] = include_bytes!("../vk.bin");
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -90,6 +71,7 @@ pub enum Error {
     InvalidProof = 5,
     InvalidPosition = 6,
     NotPlayer = 7,
+    SessionAlreadyExists = 8,
 }
 
 // ============================================================================
@@ -126,19 +108,15 @@ pub struct GameState {
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Storage Schema
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
+// Version 1: the original layout
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Layout version written by this build
+const SCHEMA_VERSION: u32 = 1;
 
 // ============================================================================
 // Contract Definition
@@ -155,11 +133,8 @@ impl ZkMemoryContract {
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
+        schema::init(&env, SCHEMA_VERSION);
     }
 
     /// Start a new game between two players with points and a committed deck.
@@ -169,51 +144,49 @@ impl ZkMemoryContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
     /// * `deck_commitment` - Poseidon hash of the shuffled deck + salt (32 bytes)
+    ///
+    /// # Returns
+    /// * `u32` - Session ID allocated by the Game Hub
     pub fn start_game(
         env: Env,
-        session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
         deck_commitment: BytesN<32>,
-    ) -> Result<(), Error> {
+    ) -> Result<u32, Error> {
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Require authentication from both players (each consents to the opponent and their stake)
+        player1.require_auth_for_args(vec![&env, player2.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, player1.into_val(&env), player2_points.into_val(&env)]);
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub::client(&env);
 
         // Call Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
+        let session_id = game_hub.start_game(
             &env.current_contract_address(),
-            &session_id,
             &player1,
             &player2,
             &player1_points,
             &player2_points,
         );
 
+        // The hub never reissues an ID, but never overwrite a game in progress
+        let game_key = DataKey::Game(session_id);
+        if env.storage().temporary().has(&game_key) {
+            return Err(Error::SessionAlreadyExists);
+        }
+
         // Initialize 4 cards as FaceDown (2x2 grid)
         let mut cards: Vec<CardState> = Vec::new(&env);
         for _ in 0..4 {
@@ -239,15 +212,12 @@ impl ZkMemoryContract {
         };
 
         // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
 
         // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        ttl::extend_temporary(&env, &game_key);
 
-        Ok(())
+        Ok(session_id)
     }
 
     /// Flip a card with ZK proof verification.
@@ -358,29 +328,25 @@ impl ZkMemoryContract {
         if game.pairs_found == 2 {
             game.is_active = false;
 
-            // Get GameHub address
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
+            let game_hub = hub::client(&env);
 
-            // Create GameHub client
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-            // Determine winner
-            let player1_won = game.score1 > game.score2;
+            // Determine outcome (equal scores are a draw)
+            let outcome = if game.score1 > game.score2 {
+                Outcome::Player1
+            } else if game.score2 > game.score1 {
+                Outcome::Player2
+            } else {
+                Outcome::Draw
+            };
 
             // Call GameHub to end the session
             // This unlocks points and updates standings
-            game_hub.end_game(&session_id, &player1_won);
+            game_hub.end_game(&session_id, &outcome);
         }
 
         // Save state and extend TTL
         env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        ttl::extend_temporary(&env, &key);
 
         Ok(())
     }
@@ -491,78 +457,25 @@ impl ZkMemoryContract {
     }
 
     // ========================================================================
-    // Admin Functions
+    // Schema Version
     // ========================================================================
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
-
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
+    /// Get the schema version of the stored data
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
-    }
-
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+    /// * `u32` - Schema version; lags this build's version until data is migrated after an upgrade
+    pub fn version(env: Env) -> u32 {
+        schema::read_version(&env)
     }
+}
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+// ============================================================================
+// Admin Functions
+// ============================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-}
+sgs_common::impl_admin!(ZkMemoryContract);
+sgs_common::impl_hub_config!(ZkMemoryContract);
+sgs_common::impl_upgrade!(ZkMemoryContract);
 
 // ============================================================================
 // Tests
@@ -0,0 +1,634 @@
+//! # Hub Events
+//!
+//! Typed decoders for the events published by the Game Hub and the randomness
+//! beacon, for indexers and other off-chain consumers reading `ContractEvent`
+//! XDR (e.g. from RPC `getEvents`).
+//!
+//! Every hub event has two topics, its name and its schema version
+//! (`["game_ended", "v1"]`), and carries its fields in a map. Events with a
+//! version this crate does not know are rejected rather than misread.
+//!
+//! ```ignore
+//! let event = HubEvent::from_xdr_base64(&xdr)?;
+//! if let HubEvent::GameEnded(ended) = event {
+//!     println!("session {} won by {:?}", ended.session_id, ended.winner);
+//! }
+//! ```
+
+use core::fmt;
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, Limits, ReadXdr, ScAddress, ScMap, ScVal,
+};
+
+pub use stellar_xdr::curr as xdr;
+
+/// Schema version this crate decodes
+pub const SCHEMA_VERSION: &str = "v1";
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The input is not valid `ContractEvent` XDR
+    Xdr(xdr::Error),
+    /// The event has no name topic, or a topic is not a symbol
+    InvalidTopics,
+    /// The event name is not a hub event
+    UnknownEvent(String),
+    /// A hub event with a schema version this crate does not decode
+    UnsupportedVersion {
+        event: String,
+        version: Option<String>,
+    },
+    /// The event data is not a map
+    InvalidData,
+    MissingField(&'static str),
+    /// A field is present but has the wrong type
+    InvalidField(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Xdr(err) => write!(f, "invalid event XDR: {err}"),
+            DecodeError::InvalidTopics => write!(f, "event topics are not hub topics"),
+            DecodeError::UnknownEvent(name) => write!(f, "unknown hub event `{name}`"),
+            DecodeError::UnsupportedVersion { event, version } => match version {
+                Some(version) => write!(f, "unsupported `{event}` schema version `{version}`"),
+                None => write!(f, "`{event}` event has no schema version"),
+            },
+            DecodeError::InvalidData => write!(f, "event data is not a map"),
+            DecodeError::MissingField(name) => write!(f, "missing field `{name}`"),
+            DecodeError::InvalidField(name) => write!(f, "field `{name}` has the wrong type"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<xdr::Error> for DecodeError {
+    fn from(err: xdr::Error) -> Self {
+        DecodeError::Xdr(err)
+    }
+}
+
+// ============================================================================
+// Event Types
+// ============================================================================
+// Mirrors of the hub's event structs. Addresses are kept as `ScAddress`, whose
+// `Display` gives the strkey (`G...` / `C...`).
+
+/// Result of a session (mirrors the hub's `Outcome`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1,
+    Player2,
+    Draw,
+    Cancelled,
+}
+
+/// Permission the hub admin can grant (mirrors `sgs_common::admin::Role`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Upgrader,
+    HubManager,
+    Pauser,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameStarted {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub player1: ScAddress,
+    pub player2: ScAddress,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub outcome: Outcome,
+    pub winner: Option<ScAddress>,
+    /// Token the stakes were escrowed in, or `None` for hub points
+    pub token: Option<ScAddress>,
+    pub pot: i128,
+    pub fee: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiGameStarted {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub stakes: Vec<i128>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiGameEnded {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub payouts: Vec<i128>,
+    pub token: Option<ScAddress>,
+    pub pot: i128,
+    pub fee: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollected {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub token: Option<ScAddress>,
+    pub treasury: ScAddress,
+    pub treasury_amount: i128,
+    pub developer: Option<ScAddress>,
+    pub developer_amount: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionCancelled {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub stakes: Vec<i128>,
+    pub token: Option<ScAddress>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionExpired {
+    pub session_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub stakes: Vec<i128>,
+    pub token: Option<ScAddress>,
+    pub claimed_by: ScAddress,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesStarted {
+    pub series_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub stakes: Vec<i128>,
+    pub best_of: u32,
+    /// Session holding the series stakes
+    pub pot_session: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesEnded {
+    pub series_id: u32,
+    pub game_id: ScAddress,
+    pub players: Vec<ScAddress>,
+    pub outcome: Outcome,
+    pub winner: Option<ScAddress>,
+    /// Games won by each player, in seat order
+    pub wins: Vec<u32>,
+    pub token: Option<ScAddress>,
+    pub pot: i128,
+    pub fee: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsClaimed {
+    pub player: ScAddress,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRegistered {
+    pub game_id: ScAddress,
+    pub max_session_ledgers: u32,
+}
+
+/// `game_id` is `None` when the whole hub was paused
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    pub game_id: Option<ScAddress>,
+}
+
+/// `game_id` is `None` when the whole hub was unpaused
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    pub game_id: Option<ScAddress>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonStarted {
+    pub season: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonClosed {
+    pub season: u32,
+    pub players: u32,
+    pub rewards_paid: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    pub new_admin: ScAddress,
+    pub live_until_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAccepted {
+    pub previous_admin: ScAddress,
+    pub new_admin: ScAddress,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    pub role: Role,
+    pub account: ScAddress,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub account: ScAddress,
+}
+
+/// Published by the randomness beacon when a request is opened
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RandomnessRequested {
+    pub requester: ScAddress,
+    pub session_id: u32,
+    pub participants: Vec<ScAddress>,
+}
+
+/// Published by the randomness beacon when a request's output is fixed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RandomnessFinalized {
+    pub requester: ScAddress,
+    pub session_id: u32,
+    pub output: [u8; 32],
+    /// Participants that committed but never revealed
+    pub defaulted: Vec<ScAddress>,
+}
+
+/// Any event published by the Game Hub or the randomness beacon
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HubEvent {
+    GameStarted(GameStarted),
+    GameEnded(GameEnded),
+    MultiGameStarted(MultiGameStarted),
+    MultiGameEnded(MultiGameEnded),
+    FeeCollected(FeeCollected),
+    SessionCancelled(SessionCancelled),
+    SessionExpired(SessionExpired),
+    SeriesStarted(SeriesStarted),
+    SeriesEnded(SeriesEnded),
+    PointsClaimed(PointsClaimed),
+    GameRegistered(GameRegistered),
+    Paused(Paused),
+    Unpaused(Unpaused),
+    SeasonStarted(SeasonStarted),
+    SeasonClosed(SeasonClosed),
+    AdminProposed(AdminProposed),
+    AdminAccepted(AdminAccepted),
+    RoleGranted(RoleGranted),
+    RoleRevoked(RoleRevoked),
+    RandomnessRequested(RandomnessRequested),
+    RandomnessFinalized(RandomnessFinalized),
+}
+
+// ============================================================================
+// Decoding
+// ============================================================================
+
+impl HubEvent {
+    /// Decode a hub event.
+    ///
+    /// The emitting contract is not checked; filter events by the hub's (or
+    /// the beacon's) contract ID before decoding.
+    pub fn decode(event: &ContractEvent) -> Result<Self, DecodeError> {
+        let ContractEventBody::V0(body) = &event.body;
+        let name = body
+            .topics
+            .first()
+            .and_then(symbol)
+            .ok_or(DecodeError::InvalidTopics)?;
+        let version = match body.topics.get(1) {
+            Some(topic) => Some(symbol(topic).ok_or(DecodeError::InvalidTopics)?),
+            None => None,
+        };
+        if !is_hub_event(&name) {
+            return Err(DecodeError::UnknownEvent(name));
+        }
+        if version.as_deref() != Some(SCHEMA_VERSION) || body.topics.len() != 2 {
+            return Err(DecodeError::UnsupportedVersion {
+                event: name,
+                version,
+            });
+        }
+
+        let data = match &body.data {
+            ScVal::Map(Some(map)) => Fields(map),
+            _ => return Err(DecodeError::InvalidData),
+        };
+        let event = match name.as_str() {
+            "game_started" => HubEvent::GameStarted(GameStarted {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                player1: data.address("player1")?,
+                player2: data.address("player2")?,
+                player1_points: data.i128("player1_points")?,
+                player2_points: data.i128("player2_points")?,
+            }),
+            "game_ended" => HubEvent::GameEnded(GameEnded {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                outcome: data.outcome("outcome")?,
+                winner: data.option_address("winner")?,
+                token: data.option_address("token")?,
+                pot: data.i128("pot")?,
+                fee: data.i128("fee")?,
+            }),
+            "multi_game_started" => HubEvent::MultiGameStarted(MultiGameStarted {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                stakes: data.amounts("stakes")?,
+            }),
+            "multi_game_ended" => HubEvent::MultiGameEnded(MultiGameEnded {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                payouts: data.amounts("payouts")?,
+                token: data.option_address("token")?,
+                pot: data.i128("pot")?,
+                fee: data.i128("fee")?,
+            }),
+            "fee_collected" => HubEvent::FeeCollected(FeeCollected {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                token: data.option_address("token")?,
+                treasury: data.address("treasury")?,
+                treasury_amount: data.i128("treasury_amount")?,
+                developer: data.option_address("developer")?,
+                developer_amount: data.i128("developer_amount")?,
+            }),
+            "session_cancelled" => HubEvent::SessionCancelled(SessionCancelled {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                stakes: data.amounts("stakes")?,
+                token: data.option_address("token")?,
+            }),
+            "session_expired" => HubEvent::SessionExpired(SessionExpired {
+                session_id: data.u32("session_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                stakes: data.amounts("stakes")?,
+                token: data.option_address("token")?,
+                claimed_by: data.address("claimed_by")?,
+            }),
+            "series_started" => HubEvent::SeriesStarted(SeriesStarted {
+                series_id: data.u32("series_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                stakes: data.amounts("stakes")?,
+                best_of: data.u32("best_of")?,
+                pot_session: data.u32("pot_session")?,
+            }),
+            "series_ended" => HubEvent::SeriesEnded(SeriesEnded {
+                series_id: data.u32("series_id")?,
+                game_id: data.address("game_id")?,
+                players: data.addresses("players")?,
+                outcome: data.outcome("outcome")?,
+                winner: data.option_address("winner")?,
+                wins: data.counts("wins")?,
+                token: data.option_address("token")?,
+                pot: data.i128("pot")?,
+                fee: data.i128("fee")?,
+            }),
+            "points_claimed" => HubEvent::PointsClaimed(PointsClaimed {
+                player: data.address("player")?,
+                amount: data.i128("amount")?,
+            }),
+            "game_registered" => HubEvent::GameRegistered(GameRegistered {
+                game_id: data.address("game_id")?,
+                max_session_ledgers: data.u32("max_session_ledgers")?,
+            }),
+            "paused" => HubEvent::Paused(Paused {
+                game_id: data.option_address("game_id")?,
+            }),
+            "unpaused" => HubEvent::Unpaused(Unpaused {
+                game_id: data.option_address("game_id")?,
+            }),
+            "season_started" => HubEvent::SeasonStarted(SeasonStarted {
+                season: data.u32("season")?,
+                start_ledger: data.u32("start_ledger")?,
+                end_ledger: data.u32("end_ledger")?,
+            }),
+            "season_closed" => HubEvent::SeasonClosed(SeasonClosed {
+                season: data.u32("season")?,
+                players: data.u32("players")?,
+                rewards_paid: data.i128("rewards_paid")?,
+            }),
+            "admin_proposed" => HubEvent::AdminProposed(AdminProposed {
+                new_admin: data.address("new_admin")?,
+                live_until_ledger: data.u32("live_until_ledger")?,
+            }),
+            "admin_accepted" => HubEvent::AdminAccepted(AdminAccepted {
+                previous_admin: data.address("previous_admin")?,
+                new_admin: data.address("new_admin")?,
+            }),
+            "role_granted" => HubEvent::RoleGranted(RoleGranted {
+                role: data.role("role")?,
+                account: data.address("account")?,
+            }),
+            "role_revoked" => HubEvent::RoleRevoked(RoleRevoked {
+                role: data.role("role")?,
+                account: data.address("account")?,
+            }),
+            "randomness_requested" => HubEvent::RandomnessRequested(RandomnessRequested {
+                requester: data.address("requester")?,
+                session_id: data.u32("session_id")?,
+                participants: data.addresses("participants")?,
+            }),
+            "randomness_finalized" => HubEvent::RandomnessFinalized(RandomnessFinalized {
+                requester: data.address("requester")?,
+                session_id: data.u32("session_id")?,
+                output: data.bytes32("output")?,
+                defaulted: data.addresses("defaulted")?,
+            }),
+            _ => unreachable!("checked by is_hub_event"),
+        };
+        Ok(event)
+    }
+
+    /// Decode a hub event from base64 `ContractEvent` XDR
+    pub fn from_xdr_base64(xdr: &str) -> Result<Self, DecodeError> {
+        let event = ContractEvent::from_xdr_base64(xdr, Limits::none())?;
+        Self::decode(&event)
+    }
+}
+
+fn is_hub_event(name: &str) -> bool {
+    matches!(
+        name,
+        "game_started"
+            | "game_ended"
+            | "multi_game_started"
+            | "multi_game_ended"
+            | "fee_collected"
+            | "session_cancelled"
+            | "session_expired"
+            | "series_started"
+            | "series_ended"
+            | "points_claimed"
+            | "game_registered"
+            | "paused"
+            | "unpaused"
+            | "season_started"
+            | "season_closed"
+            | "admin_proposed"
+            | "admin_accepted"
+            | "role_granted"
+            | "role_revoked"
+            | "randomness_requested"
+            | "randomness_finalized"
+    )
+}
+
+fn symbol(val: &ScVal) -> Option<String> {
+    match val {
+        ScVal::Symbol(symbol) => symbol.0.to_utf8_string().ok(),
+        _ => None,
+    }
+}
+
+fn as_address(val: &ScVal) -> Option<ScAddress> {
+    match val {
+        ScVal::Address(address) => Some(address.clone()),
+        _ => None,
+    }
+}
+
+fn as_u32(val: &ScVal) -> Option<u32> {
+    match val {
+        ScVal::U32(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn as_i128(val: &ScVal) -> Option<i128> {
+    match val {
+        ScVal::I128(parts) => Some(parts.into()),
+        _ => None,
+    }
+}
+
+/// Field lookup over an event's data map
+struct Fields<'a>(&'a ScMap);
+
+impl Fields<'_> {
+    fn get(&self, name: &'static str) -> Result<&ScVal, DecodeError> {
+        self.0
+            .iter()
+            .find(|entry| symbol(&entry.key).as_deref() == Some(name))
+            .map(|entry| &entry.val)
+            .ok_or(DecodeError::MissingField(name))
+    }
+
+    fn u32(&self, name: &'static str) -> Result<u32, DecodeError> {
+        as_u32(self.get(name)?).ok_or(DecodeError::InvalidField(name))
+    }
+
+    fn i128(&self, name: &'static str) -> Result<i128, DecodeError> {
+        as_i128(self.get(name)?).ok_or(DecodeError::InvalidField(name))
+    }
+
+    fn bytes32(&self, name: &'static str) -> Result<[u8; 32], DecodeError> {
+        match self.get(name)? {
+            ScVal::Bytes(bytes) => bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| DecodeError::InvalidField(name)),
+            _ => Err(DecodeError::InvalidField(name)),
+        }
+    }
+
+    fn address(&self, name: &'static str) -> Result<ScAddress, DecodeError> {
+        as_address(self.get(name)?).ok_or(DecodeError::InvalidField(name))
+    }
+
+    fn option_address(&self, name: &'static str) -> Result<Option<ScAddress>, DecodeError> {
+        match self.get(name)? {
+            ScVal::Void => Ok(None),
+            val => as_address(val)
+                .map(Some)
+                .ok_or(DecodeError::InvalidField(name)),
+        }
+    }
+
+    fn list<T>(
+        &self,
+        name: &'static str,
+        item: impl Fn(&ScVal) -> Option<T>,
+    ) -> Result<Vec<T>, DecodeError> {
+        match self.get(name)? {
+            ScVal::Vec(Some(items)) => items
+                .iter()
+                .map(|val| item(val).ok_or(DecodeError::InvalidField(name)))
+                .collect(),
+            _ => Err(DecodeError::InvalidField(name)),
+        }
+    }
+
+    fn addresses(&self, name: &'static str) -> Result<Vec<ScAddress>, DecodeError> {
+        self.list(name, as_address)
+    }
+
+    fn amounts(&self, name: &'static str) -> Result<Vec<i128>, DecodeError> {
+        self.list(name, as_i128)
+    }
+
+    fn counts(&self, name: &'static str) -> Result<Vec<u32>, DecodeError> {
+        self.list(name, as_u32)
+    }
+
+    /// Unit enum variants are encoded as a one-element vec holding the variant name
+    fn variant(&self, name: &'static str) -> Result<String, DecodeError> {
+        match self.list(name, symbol)?.as_slice() {
+            [variant] => Ok(variant.clone()),
+            _ => Err(DecodeError::InvalidField(name)),
+        }
+    }
+
+    fn outcome(&self, name: &'static str) -> Result<Outcome, DecodeError> {
+        match self.variant(name)?.as_str() {
+            "Player1" => Ok(Outcome::Player1),
+            "Player2" => Ok(Outcome::Player2),
+            "Draw" => Ok(Outcome::Draw),
+            "Cancelled" => Ok(Outcome::Cancelled),
+            _ => Err(DecodeError::InvalidField(name)),
+        }
+    }
+
+    fn role(&self, name: &'static str) -> Result<Role, DecodeError> {
+        match self.variant(name)?.as_str() {
+            "Upgrader" => Ok(Role::Upgrader),
+            "HubManager" => Ok(Role::HubManager),
+            "Pauser" => Ok(Role::Pauser),
+            _ => Err(DecodeError::InvalidField(name)),
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
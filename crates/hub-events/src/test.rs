@@ -0,0 +1,497 @@
+#![cfg(test)]
+
+// Unit tests for the hub event decoders, run against events published by the
+// mock Game Hub and the randomness beacon.
+
+use crate::xdr::{
+    ContractEvent, ContractEventBody, ContractEventType, ContractEventV0, ExtensionPoint, Limits,
+    ScAddress, ScMap, ScVal, WriteXdr,
+};
+use crate::{
+    AdminAccepted, AdminProposed, DecodeError, FeeCollected, GameEnded, GameRegistered,
+    GameStarted, HubEvent, MultiGameEnded, MultiGameStarted, Outcome, Paused, PointsClaimed,
+    RandomnessFinalized, RandomnessRequested, Role, RoleGranted, RoleRevoked, SeasonClosed, SeasonStarted, SeriesEnded, SeriesStarted,
+    SessionCancelled, SessionExpired, Unpaused,
+};
+use mock_game_hub::{LeaderboardSort, MockGameHub, MockGameHubClient};
+use randomness::{RandomnessContract, RandomnessContractClient};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (Env, MockGameHubClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    let game_id = Address::generate(&env);
+    client.add_game(&game_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.add_points(&player1, &1000);
+    client.add_points(&player2, &1000);
+
+    (env, client, game_id, player1, player2)
+}
+
+/// Decode every event the hub published in the last invocation
+fn decode_last(env: &Env, client: &MockGameHubClient) -> Vec<HubEvent> {
+    env.events()
+        .all()
+        .filter_by_contract(&client.address)
+        .events()
+        .iter()
+        .map(|event| HubEvent::decode(event).unwrap())
+        .collect()
+}
+
+fn sc(address: &Address) -> ScAddress {
+    ScAddress::from(address)
+}
+
+fn symbol(name: &str) -> ScVal {
+    ScVal::Symbol(name.try_into().unwrap())
+}
+
+fn raw_event(topics: &[&str], data: ScVal) -> ContractEvent {
+    ContractEvent {
+        ext: ExtensionPoint::V0,
+        contract_id: None,
+        type_: ContractEventType::Contract,
+        body: ContractEventBody::V0(ContractEventV0 {
+            topics: topics
+                .iter()
+                .map(|topic| symbol(topic))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            data,
+        }),
+    }
+}
+
+// ============================================================================
+// Session Event Tests
+// ============================================================================
+
+#[test]
+fn test_decode_game_started() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+
+    let expected = HubEvent::GameStarted(GameStarted {
+        session_id: 1,
+        game_id: sc(&game_id),
+        player1: sc(&player1),
+        player2: sc(&player2),
+        player1_points: 100,
+        player2_points: 50,
+    });
+    assert_eq!(decode_last(&env, &client), [expected]);
+}
+
+#[test]
+fn test_decode_game_ended_with_fee() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let treasury = Address::generate(&env);
+    client.set_fee_config(&500, &0, &treasury);
+
+    client.start_game(&game_id, &player1, &player2, &100, &100);
+    client.end_game(&1, &mock_game_hub::Outcome::Player2);
+
+    let expected = [
+        HubEvent::FeeCollected(FeeCollected {
+            session_id: 1,
+            game_id: sc(&game_id),
+            token: None,
+            treasury: sc(&treasury),
+            treasury_amount: 10,
+            developer: None,
+            developer_amount: 0,
+        }),
+        HubEvent::GameEnded(GameEnded {
+            session_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            outcome: Outcome::Player2,
+            winner: Some(sc(&player2)),
+            token: None,
+            pot: 200,
+            fee: 10,
+        }),
+    ];
+    assert_eq!(decode_last(&env, &client), expected);
+}
+
+#[test]
+fn test_decode_multi_game_events() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let players = soroban_sdk::vec![&env, player1.clone(), player2.clone()];
+
+    client.start_game_multi(&game_id, &players, &soroban_sdk::vec![&env, 30i128, 10]);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::MultiGameStarted(MultiGameStarted {
+            session_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            stakes: vec![30, 10],
+        })]
+    );
+
+    client.end_game_multi(&1, &soroban_sdk::vec![&env, 3u32, 1]);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::MultiGameEnded(MultiGameEnded {
+            session_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            payouts: vec![30, 10],
+            token: None,
+            pot: 40,
+            fee: 0,
+        })]
+    );
+}
+
+#[test]
+fn test_decode_session_cancelled_and_expired() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+    client.end_game(&1, &mock_game_hub::Outcome::Cancelled);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::SessionCancelled(SessionCancelled {
+            session_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            stakes: vec![100, 50],
+            token: None,
+        })]
+    );
+
+    client.start_game(&game_id, &player1, &player2, &20, &20);
+    let expires = client.get_session(&2).expires_ledger;
+    env.ledger().with_mut(|li| li.sequence_number = expires + 1);
+    client.claim_expired_session(&2, &player1);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::SessionExpired(SessionExpired {
+            session_id: 2,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            stakes: vec![20, 20],
+            token: None,
+            claimed_by: sc(&player1),
+        })]
+    );
+}
+
+#[test]
+fn test_decode_series_events() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_series(&game_id, &player1, &player2, &100, &100, &1);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::SeriesStarted(SeriesStarted {
+            series_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            stakes: vec![100, 100],
+            best_of: 1,
+            pot_session: 1,
+        })]
+    );
+
+    let session_id = client.start_series_game(&1);
+    client.end_game(&session_id, &mock_game_hub::Outcome::Player2);
+    let events = decode_last(&env, &client);
+    assert_eq!(
+        events.last(),
+        Some(&HubEvent::SeriesEnded(SeriesEnded {
+            series_id: 1,
+            game_id: sc(&game_id),
+            players: vec![sc(&player1), sc(&player2)],
+            outcome: Outcome::Player2,
+            winner: Some(sc(&player2)),
+            wins: vec![0, 1],
+            token: None,
+            pot: 200,
+            fee: 0,
+        }))
+    );
+}
+
+// ============================================================================
+// Registry and Season Event Tests
+// ============================================================================
+
+#[test]
+fn test_decode_game_registered() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let game_id = Address::generate(&env);
+
+    client.add_game(&game_id);
+
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::GameRegistered(GameRegistered {
+            game_id: sc(&game_id),
+            max_session_ledgers: 17_280,
+        })]
+    );
+}
+
+#[test]
+fn test_decode_points_claimed() {
+    let (env, client, _game_id, player1, _player2) = setup_test();
+    client.set_faucet_config(&100, &1000, &5000);
+
+    client.claim_daily_points(&player1);
+
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::PointsClaimed(PointsClaimed {
+            player: sc(&player1),
+            amount: 100,
+        })]
+    );
+}
+
+#[test]
+fn test_decode_pause_events() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+
+    client.pause_game(&admin, &game_id);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::Paused(Paused {
+            game_id: Some(sc(&game_id)),
+        })]
+    );
+
+    client.unpause_all(&admin);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::Unpaused(Unpaused { game_id: None })]
+    );
+}
+
+#[test]
+fn test_decode_season_events() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+
+    client.start_season(
+        &100,
+        &LeaderboardSort::Wins,
+        &None,
+        &soroban_sdk::vec![&env, 10_000u32],
+    );
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::SeasonStarted(SeasonStarted {
+            season: 1,
+            start_ledger: 0,
+            end_ledger: 100,
+        })]
+    );
+
+    env.ledger().set_sequence_number(101);
+    client.close_season();
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::SeasonClosed(SeasonClosed {
+            season: 1,
+            players: 0,
+            rewards_paid: 0,
+        })]
+    );
+}
+
+// ============================================================================
+// Admin Event Tests
+// ============================================================================
+
+#[test]
+fn test_decode_admin_transfer_events() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin, &100);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::AdminProposed(AdminProposed {
+            new_admin: sc(&new_admin),
+            live_until_ledger: 100,
+        })]
+    );
+
+    client.accept_admin();
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::AdminAccepted(AdminAccepted {
+            previous_admin: sc(&admin),
+            new_admin: sc(&new_admin),
+        })]
+    );
+}
+
+#[test]
+fn test_decode_role_events() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let pauser = Address::generate(&env);
+
+    client.grant_role(&sgs_common::admin::Role::Pauser, &pauser);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::RoleGranted(RoleGranted {
+            role: Role::Pauser,
+            account: sc(&pauser),
+        })]
+    );
+
+    client.revoke_role(&sgs_common::admin::Role::Pauser, &pauser);
+    assert_eq!(
+        decode_last(&env, &client),
+        [HubEvent::RoleRevoked(RoleRevoked {
+            role: Role::Pauser,
+            account: sc(&pauser),
+        })]
+    );
+}
+
+// ============================================================================
+// Randomness Beacon Event Tests
+// ============================================================================
+
+#[test]
+fn test_decode_randomness_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(RandomnessContract, (&Address::generate(&env),));
+    let beacon = RandomnessContractClient::new(&env, &contract_id);
+    let requester = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let decode_last = || -> Vec<HubEvent> {
+        env.events()
+            .all()
+            .filter_by_contract(&contract_id)
+            .events()
+            .iter()
+            .map(|event| HubEvent::decode(event).unwrap())
+            .collect()
+    };
+
+    let participants = soroban_sdk::vec![&env, participant.clone()];
+    beacon.request(&requester, &1, &participants, &10, &20, &None);
+    assert_eq!(
+        decode_last(),
+        [HubEvent::RandomnessRequested(RandomnessRequested {
+            requester: sc(&requester),
+            session_id: 1,
+            participants: vec![sc(&participant)],
+        })]
+    );
+
+    let secret = Bytes::from_array(&env, &[7; 32]);
+    let commitment: BytesN<32> = env.crypto().keccak256(&secret).into();
+    beacon.commit(&requester, &1, &participant, &commitment);
+    // The last reveal finalizes the request
+    beacon.reveal(&requester, &1, &participant, &secret);
+    let events = decode_last();
+    let output = beacon.get_output(&requester, &1).unwrap();
+    assert_eq!(
+        events,
+        [HubEvent::RandomnessFinalized(RandomnessFinalized {
+            requester: sc(&requester),
+            session_id: 1,
+            output: output.to_array(),
+            defaulted: Vec::new(),
+        })]
+    );
+}
+
+// ============================================================================
+// Decoding Tests
+// ============================================================================
+
+#[test]
+fn test_decode_from_base64() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &player1, &player2, &100, &50);
+
+    let events = env.events().all().filter_by_contract(&client.address);
+    let encoded = events.events()[0].to_xdr_base64(Limits::none()).unwrap();
+
+    let decoded = HubEvent::from_xdr_base64(&encoded).unwrap();
+    assert!(matches!(decoded, HubEvent::GameStarted(started) if started.session_id == 1));
+    assert!(matches!(
+        HubEvent::from_xdr_base64("not xdr"),
+        Err(DecodeError::Xdr(_))
+    ));
+}
+
+#[test]
+fn test_rejects_unknown_and_unversioned_events() {
+    let data = ScVal::Map(Some(ScMap(Default::default())));
+
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&["transfer", "v1"], data.clone())),
+        Err(DecodeError::UnknownEvent(name)) if name == "transfer"
+    ));
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&["game_ended"], data.clone())),
+        Err(DecodeError::UnsupportedVersion { version: None, .. })
+    ));
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&["game_ended", "v2"], data.clone())),
+        Err(DecodeError::UnsupportedVersion { version: Some(version), .. }) if version == "v2"
+    ));
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&[], data)),
+        Err(DecodeError::InvalidTopics)
+    ));
+}
+
+#[test]
+fn test_rejects_malformed_data() {
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&["season_started", "v1"], ScVal::U32(1))),
+        Err(DecodeError::InvalidData)
+    ));
+
+    let partial = ScMap(
+        vec![crate::xdr::ScMapEntry {
+            key: symbol("season"),
+            val: symbol("one"),
+        }]
+        .try_into()
+        .unwrap(),
+    );
+    assert!(matches!(
+        HubEvent::decode(&raw_event(
+            &["season_started", "v1"],
+            ScVal::Map(Some(partial))
+        )),
+        Err(DecodeError::InvalidField("season"))
+    ));
+
+    let empty = ScVal::Map(Some(ScMap(Default::default())));
+    assert!(matches!(
+        HubEvent::decode(&raw_event(&["season_started", "v1"], empty)),
+        Err(DecodeError::MissingField("season"))
+    ));
+}
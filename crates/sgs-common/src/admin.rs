@@ -0,0 +1,461 @@
+//! Admin, roles, Game Hub and randomness beacon address storage.
+//!
+//! Everything lives in instance storage under [`CommonKey`]. Unit variants
+//! of a `contracttype` enum encode as their name alone, so these keys match
+//! the `DataKey::Admin` and `DataKey::GameHubAddress` entries that contracts
+//! wrote before this crate existed; deployed contracts keep their state
+//! across an upgrade.
+//!
+//! **Admin Transfer:**
+//! The admin proposes a successor with `propose_admin`, and control only moves
+//! once that address calls `accept_admin` before the proposal expires. A
+//! mistyped address can never take over, and the proposal can be replaced at
+//! any time by proposing again. Proposing emits `admin_proposed` and accepting
+//! emits `admin_accepted`.
+//!
+//! **Roles:**
+//! The admin can grant routine permissions to other accounts so the admin key
+//! is not needed for day-to-day operations. The admin implicitly holds every
+//! role. Granting and revoking a role emit `role_granted` and `role_revoked`.
+
+use soroban_sdk::{
+    contracterror, contractevent, contracttype, panic_with_error, Address, BytesN, Env,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdminError {
+    NoPendingAdmin = 100,
+    AdminProposalExpired = 101,
+    InvalidExpiry = 102,
+    MissingRole = 103,
+}
+
+/// Permission that can be granted to an account besides the admin
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Can replace the contract WASM
+    Upgrader,
+    /// Can change the GameHub address a game reports to and the randomness
+    /// beacon it draws from
+    HubManager,
+    /// Can pause and unpause play
+    Pauser,
+}
+
+#[contractevent(topics = ["admin_proposed", "v1"])]
+pub struct AdminProposed {
+    pub new_admin: Address,
+    pub live_until_ledger: u32,
+}
+
+#[contractevent(topics = ["admin_accepted", "v1"])]
+pub struct AdminAccepted {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent(topics = ["role_granted", "v1"])]
+pub struct RoleGranted {
+    pub role: Role,
+    pub account: Address,
+}
+
+#[contractevent(topics = ["role_revoked", "v1"])]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub account: Address,
+}
+
+/// Admin transfer waiting for the new admin to accept
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub address: Address,
+    /// Last ledger on which the proposal can be accepted
+    pub live_until_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum CommonKey {
+    Admin,
+    GameHubAddress,
+    PendingAdmin,
+    Role(Role, Address),
+    SchemaVersion,
+    BeaconAddress,
+}
+
+/// Store the admin and GameHub addresses; call from `__constructor`
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    write_admin(env, admin);
+    write_hub(env, game_hub);
+}
+
+/// Read the admin address
+///
+/// # Panics
+/// * If the admin has not been set
+pub fn read_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::Admin)
+        .expect("Admin not set")
+}
+
+pub fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&CommonKey::Admin, admin);
+}
+
+/// Require authorization from the admin
+///
+/// # Returns
+/// * `Address` - The admin address
+pub fn require_admin(env: &Env) -> Address {
+    let admin = read_admin(env);
+    admin.require_auth();
+    admin
+}
+
+// ============================================================================
+// Admin Transfer
+// ============================================================================
+
+/// Propose a new admin (admin only). Replaces any earlier proposal.
+///
+/// # Arguments
+/// * `new_admin` - Address that must call [`accept_admin`]
+/// * `live_until_ledger` - Last ledger on which the proposal can be accepted
+pub fn propose_admin(
+    env: &Env,
+    new_admin: &Address,
+    live_until_ledger: u32,
+) -> Result<(), AdminError> {
+    require_admin(env);
+    if live_until_ledger <= env.ledger().sequence() {
+        return Err(AdminError::InvalidExpiry);
+    }
+
+    let pending = PendingAdmin {
+        address: new_admin.clone(),
+        live_until_ledger,
+    };
+    env.storage()
+        .instance()
+        .set(&CommonKey::PendingAdmin, &pending);
+    AdminProposed {
+        new_admin: new_admin.clone(),
+        live_until_ledger,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Complete a proposed admin transfer; requires the proposed admin's auth
+///
+/// # Returns
+/// * `Address` - The new admin
+pub fn accept_admin(env: &Env) -> Result<Address, AdminError> {
+    let pending = read_pending_admin(env).ok_or(AdminError::NoPendingAdmin)?;
+    if env.ledger().sequence() > pending.live_until_ledger {
+        return Err(AdminError::AdminProposalExpired);
+    }
+    pending.address.require_auth();
+
+    let previous_admin = read_admin(env);
+    write_admin(env, &pending.address);
+    env.storage().instance().remove(&CommonKey::PendingAdmin);
+    AdminAccepted {
+        previous_admin,
+        new_admin: pending.address.clone(),
+    }
+    .publish(env);
+    Ok(pending.address)
+}
+
+pub fn read_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage().instance().get(&CommonKey::PendingAdmin)
+}
+
+// ============================================================================
+// Roles
+// ============================================================================
+
+/// Check whether an account was granted a role (the admin's implicit roles
+/// are not reported)
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&CommonKey::Role(role, account.clone()))
+}
+
+/// Grant a role to an account (admin only)
+pub fn grant_role(env: &Env, role: Role, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::Role(role, account.clone()), &true);
+    RoleGranted {
+        role,
+        account: account.clone(),
+    }
+    .publish(env);
+}
+
+/// Revoke a role from an account (admin only)
+pub fn revoke_role(env: &Env, role: Role, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .remove(&CommonKey::Role(role, account.clone()));
+    RoleRevoked {
+        role,
+        account: account.clone(),
+    }
+    .publish(env);
+}
+
+/// Require authorization from `caller`, who must be the admin or hold `role`
+///
+/// # Panics
+/// * With [`AdminError::MissingRole`] if `caller` is neither the admin nor a
+///   holder of `role`
+pub fn require_role(env: &Env, role: Role, caller: &Address) {
+    if *caller != read_admin(env) && !has_role(env, role, caller) {
+        panic_with_error!(env, AdminError::MissingRole);
+    }
+    caller.require_auth();
+}
+
+// ============================================================================
+// Game Hub Address
+// ============================================================================
+
+/// Read the GameHub contract address
+///
+/// # Panics
+/// * If the GameHub address has not been set
+pub fn read_hub(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::GameHubAddress)
+        .expect("GameHub address not set")
+}
+
+pub fn write_hub(env: &Env, game_hub: &Address) {
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, game_hub);
+}
+
+// ============================================================================
+// Randomness Beacon Address
+// ============================================================================
+
+/// Read the randomness beacon contract address
+///
+/// # Panics
+/// * If the beacon address has not been set
+pub fn read_beacon(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::BeaconAddress)
+        .expect("Randomness beacon address not set")
+}
+
+pub fn write_beacon(env: &Env, beacon: &Address) {
+    env.storage()
+        .instance()
+        .set(&CommonKey::BeaconAddress, beacon);
+}
+
+/// Replace the contract WASM; `caller` must be the admin or an upgrader
+pub fn upgrade(env: &Env, caller: &Address, new_wasm_hash: BytesN<32>) {
+    require_role(env, Role::Upgrader, caller);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+}
+
+// ============================================================================
+// Contract Functions
+// ============================================================================
+
+/// Expose admin transfer and role management on a contract
+///
+/// Adds `get_admin`, `propose_admin`, `accept_admin`, `get_pending_admin`,
+/// `grant_role`, `revoke_role` and `has_role` in a separate `#[contractimpl]`
+/// block. The contract must store its admin with [`init`] or [`write_admin`].
+#[macro_export]
+macro_rules! impl_admin {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Get the current admin address
+            ///
+            /// # Returns
+            /// * `Address` - The admin address
+            pub fn get_admin(env: soroban_sdk::Env) -> soroban_sdk::Address {
+                $crate::admin::read_admin(&env)
+            }
+
+            /// Propose a new admin (admin only). The transfer completes when
+            /// `new_admin` calls `accept_admin`; proposing again replaces it.
+            ///
+            /// # Arguments
+            /// * `new_admin` - The proposed admin address
+            /// * `live_until_ledger` - Last ledger on which the proposal can be accepted
+            pub fn propose_admin(
+                env: soroban_sdk::Env,
+                new_admin: soroban_sdk::Address,
+                live_until_ledger: u32,
+            ) -> Result<(), $crate::admin::AdminError> {
+                $crate::admin::propose_admin(&env, &new_admin, live_until_ledger)
+            }
+
+            /// Accept a pending admin proposal (proposed admin only)
+            ///
+            /// # Returns
+            /// * `Address` - The new admin address
+            pub fn accept_admin(
+                env: soroban_sdk::Env,
+            ) -> Result<soroban_sdk::Address, $crate::admin::AdminError> {
+                $crate::admin::accept_admin(&env)
+            }
+
+            /// Get the admin transfer waiting to be accepted, if any
+            pub fn get_pending_admin(env: soroban_sdk::Env) -> Option<$crate::admin::PendingAdmin> {
+                $crate::admin::read_pending_admin(&env)
+            }
+
+            /// Grant a role to an account (admin only)
+            ///
+            /// # Arguments
+            /// * `role` - The role to grant
+            /// * `account` - The account receiving it
+            pub fn grant_role(
+                env: soroban_sdk::Env,
+                role: $crate::admin::Role,
+                account: soroban_sdk::Address,
+            ) {
+                $crate::admin::grant_role(&env, role, &account);
+            }
+
+            /// Revoke a role from an account (admin only)
+            ///
+            /// # Arguments
+            /// * `role` - The role to revoke
+            /// * `account` - The account losing it
+            pub fn revoke_role(
+                env: soroban_sdk::Env,
+                role: $crate::admin::Role,
+                account: soroban_sdk::Address,
+            ) {
+                $crate::admin::revoke_role(&env, role, &account);
+            }
+
+            /// Check whether an account was granted a role
+            ///
+            /// # Returns
+            /// * `bool` - True if granted (the admin holds every role implicitly)
+            pub fn has_role(
+                env: soroban_sdk::Env,
+                role: $crate::admin::Role,
+                account: soroban_sdk::Address,
+            ) -> bool {
+                $crate::admin::has_role(&env, role, &account)
+            }
+        }
+    };
+}
+
+/// Expose the GameHub address functions on a game contract
+///
+/// Adds `get_hub` and `set_hub`; `set_hub` takes the admin or a
+/// [`Role::HubManager`].
+#[macro_export]
+macro_rules! impl_hub_config {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Get the current GameHub contract address
+            ///
+            /// # Returns
+            /// * `Address` - The GameHub contract address
+            pub fn get_hub(env: soroban_sdk::Env) -> soroban_sdk::Address {
+                $crate::admin::read_hub(&env)
+            }
+
+            /// Set a new GameHub contract address (admin or hub manager)
+            ///
+            /// # Arguments
+            /// * `caller` - The admin or an account holding `HubManager`
+            /// * `new_hub` - The new GameHub contract address
+            pub fn set_hub(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+                new_hub: soroban_sdk::Address,
+            ) {
+                $crate::admin::require_role(&env, $crate::admin::Role::HubManager, &caller);
+                $crate::admin::write_hub(&env, &new_hub);
+            }
+        }
+    };
+}
+
+/// Expose the randomness beacon address functions on a game contract
+///
+/// Adds `get_beacon` and `set_beacon`; `set_beacon` takes the admin or a
+/// [`Role::HubManager`].
+#[macro_export]
+macro_rules! impl_beacon_config {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Get the randomness beacon contract address
+            ///
+            /// # Returns
+            /// * `Address` - The randomness beacon contract address
+            pub fn get_beacon(env: soroban_sdk::Env) -> soroban_sdk::Address {
+                $crate::admin::read_beacon(&env)
+            }
+
+            /// Set the randomness beacon contract address (admin or hub manager)
+            ///
+            /// # Arguments
+            /// * `caller` - The admin or an account holding `HubManager`
+            /// * `new_beacon` - The new randomness beacon contract address
+            pub fn set_beacon(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+                new_beacon: soroban_sdk::Address,
+            ) {
+                $crate::admin::require_role(&env, $crate::admin::Role::HubManager, &caller);
+                $crate::admin::write_beacon(&env, &new_beacon);
+            }
+        }
+    };
+}
+
+/// Expose `upgrade` on a contract; it takes the admin or a [`Role::Upgrader`]
+#[macro_export]
+macro_rules! impl_upgrade {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Update the contract WASM hash (admin or upgrader)
+            ///
+            /// # Arguments
+            /// * `caller` - The admin or an account holding `Upgrader`
+            /// * `new_wasm_hash` - The hash of the new WASM binary
+            pub fn upgrade(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+                new_wasm_hash: soroban_sdk::BytesN<32>,
+            ) {
+                $crate::admin::upgrade(&env, &caller, new_wasm_hash);
+            }
+        }
+    };
+}
@@ -0,0 +1,126 @@
+//! Randomness beacon client interface.
+//!
+//! A game opens a beacon request for each session, passes its players'
+//! commitments and reveals on to the beacon, and reads the output once the
+//! beacon has finalized it. These definitions mirror the `randomness`
+//! contract's entry points; the contract is the source of truth.
+
+use soroban_sdk::{contractclient, contracttype, vec, Address, Bytes, BytesN, Env, Vec};
+
+use crate::{admin, hub, ttl};
+
+// Import the randomness beacon interface
+// Requests are keyed by the requesting contract and its session ID
+#[contractclient(name = "RandomnessClient")]
+pub trait Randomness {
+    fn request(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+        participants: Vec<Address>,
+        commit_ledgers: u32,
+        reveal_ledgers: u32,
+        bond: Option<Bond>,
+    );
+
+    fn commit(
+        env: Env,
+        requester: Address,
+        session_id: u32,
+        participant: Address,
+        commitment: BytesN<32>,
+    );
+
+    fn reveal(env: Env, requester: Address, session_id: u32, participant: Address, secret: Bytes);
+
+    /// Returns the finalized output
+    fn finalize(env: Env, requester: Address, session_id: u32) -> BytesN<32>;
+
+    /// Returns `None` until the request is finalized
+    fn get_output(env: Env, requester: Address, session_id: u32) -> Option<BytesN<32>>;
+}
+
+/// Stake each participant posts when committing. It is returned when they
+/// reveal and forfeited if they never do.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bond {
+    /// SEP-41 token the bond is posted in
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Client for the beacon stored with [`admin::write_beacon`]
+///
+/// # Panics
+/// * If the beacon address has not been set
+pub fn client(env: &Env) -> RandomnessClient<'_> {
+    RandomnessClient::new(env, &admin::read_beacon(env))
+}
+
+/// Open a beacon request for a two-player session of the calling game.
+///
+/// Commits close on the last ledger a player can commit and still have the
+/// whole reveal window before the hub session expires, the cut-off the games
+/// enforce with `SessionExpiring`, so the beacon's deadlines never outlast
+/// the game's. A session the hub has no record of (see [`hub::find_session`])
+/// runs for as long as the game keeps its record. No bond is taken; the
+/// game's own forfeit rules punish a player who does not reveal.
+///
+/// # Arguments
+/// * `session_id` - The session the randomness is for
+/// * `player1`, `player2` - The players, who both commit and reveal
+/// * `reveal_ledgers` - Ledgers both players have to reveal once both have committed
+pub fn request_session(
+    env: &Env,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    reveal_ledgers: u32,
+) {
+    request_round(
+        env,
+        session_id,
+        session_id,
+        player1,
+        player2,
+        reveal_ledgers,
+    );
+}
+
+/// Open a beacon request under a game-chosen ID for a two-player session, for
+/// games that draw more than one output per session. Commits close as in
+/// [`request_session`].
+///
+/// # Arguments
+/// * `request_id` - ID the game keys the request by, unique among its requests
+/// * `session_id` - The session the randomness is for
+/// * `player1`, `player2` - The players, who both commit and reveal
+/// * `reveal_ledgers` - Ledgers both players have to reveal once both have committed
+pub fn request_round(
+    env: &Env,
+    request_id: u32,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    reveal_ledgers: u32,
+) {
+    // A commit after `expires_ledger - reveal_ledgers - 1` leaves its reveal
+    // window open when the session expires
+    let expires_ledger = hub::find_session(env, session_id).map_or(
+        env.ledger().sequence().saturating_add(ttl::GAME_TTL_LEDGERS),
+        |session| session.expires_ledger,
+    );
+    let commit_ledgers = expires_ledger
+        .saturating_sub(env.ledger().sequence())
+        .saturating_sub(reveal_ledgers.saturating_add(1))
+        .max(1);
+    client(env).request(
+        &env.current_contract_address(),
+        &request_id,
+        &vec![env, player1.clone(), player2.clone()],
+        &commit_ledgers,
+        &reveal_ledgers,
+        &None,
+    );
+}
@@ -0,0 +1,105 @@
+//! Game Hub client interface.
+//!
+//! `Outcome`, `SessionStatus` and `Session` are defined here and used by the
+//! hub contract itself, so games and the hub always agree on their layout.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+use crate::admin;
+
+// Import GameHub contract interface
+// This allows games to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    /// Returns the session ID allocated by the hub
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> u32;
+
+    fn end_game(env: Env, session_id: u32, outcome: Outcome);
+
+    fn get_session_status(env: Env, session_id: u32) -> SessionStatus;
+
+    fn get_session(env: Env, session_id: u32) -> Session;
+
+    /// Returns the series ID; the stakes cover the whole best-of-N series
+    fn start_series(
+        env: Env,
+        game_id: Address,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        best_of: u32,
+    ) -> u32;
+
+    /// Returns the session ID of the series' next game
+    fn start_series_game(env: Env, series_id: u32) -> u32;
+
+    /// Returns the session ID of a new session with the same players and
+    /// stakes, or of the next game when the session is part of a series
+    fn rematch(env: Env, session_id: u32) -> u32;
+
+    fn get_session_series(env: Env, session_id: u32) -> Option<u32>;
+}
+
+/// Result of a session, reported by the game contract in `end_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Player1,
+    Player2,
+    Draw,
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    /// Ended through `end_game`
+    Ended(Outcome),
+    /// Ended through `end_game_multi`
+    Settled,
+    Expired,
+}
+
+/// A hub session and the stakes it locked. `stakes[i]` belongs to `players[i]`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+    pub status: SessionStatus,
+    pub start_ledger: u32,
+    /// Last ledger before any player can claim a refund
+    pub expires_ledger: u32,
+    /// Token escrowed for this session, or `None` for hub points
+    pub token: Option<Address>,
+    /// Fee rate in effect when the session started
+    pub fee_bps: u32,
+}
+
+/// Client for the Game Hub stored by [`admin::init`]
+///
+/// # Panics
+/// * If the GameHub address has not been set
+pub fn client(env: &Env) -> GameHubClient<'_> {
+    GameHubClient::new(env, &admin::read_hub(env))
+}
+
+/// The calling game's hub session `session_id`, or `None` if the hub has no
+/// record of it. Games started before the hub allocated session IDs were
+/// never recorded there, so they have no deadline and nothing locked to settle.
+pub fn find_session(env: &Env, session_id: u32) -> Option<Session> {
+    match client(env).try_get_session(&session_id) {
+        Ok(Ok(session)) if session.game_id == env.current_contract_address() => Some(session),
+        _ => None,
+    }
+}
@@ -0,0 +1,40 @@
+#![no_std]
+
+//! # SGS Common
+//!
+//! Building blocks shared by every Game Hub-aware contract in the studio.
+//!
+//! - [`hub`] - The Game Hub client interface and the types it exchanges
+//! - [`beacon`] - The randomness beacon client interface
+//! - [`admin`] - Admin, hub and beacon address storage, two-step admin
+//!   transfer and roles, plus the [`impl_admin!`], [`impl_hub_config!`],
+//!   [`impl_beacon_config!`] and [`impl_upgrade!`] macros that expose them on
+//!   a contract
+//! - [`schema`] - Stored schema version and helpers for migrating records
+//! - [`ttl`] - Storage TTL constants and helpers
+//!
+//! **Usage:**
+//! ```ignore
+//! #[contractimpl]
+//! impl MyGame {
+//!     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+//!         sgs_common::admin::init(&env, &admin, &game_hub);
+//!         sgs_common::schema::init(&env, SCHEMA_VERSION);
+//!     }
+//! }
+//!
+//! sgs_common::impl_admin!(MyGame);
+//! sgs_common::impl_hub_config!(MyGame);
+//! sgs_common::impl_upgrade!(MyGame);
+//! ```
+
+pub mod admin;
+pub mod beacon;
+pub mod hub;
+pub mod schema;
+pub mod ttl;
+
+pub use hub::{GameHubClient, Outcome, Session, SessionStatus};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,68 @@
+//! Storage schema versioning.
+//!
+//! Each contract stores the version of the layout its records were written
+//! in. After an `upgrade` that changes a stored type, the new code keeps a
+//! copy of the old type and converts records with `migrate`; `version()`
+//! reports whether that has happened yet.
+//!
+//! Contracts deployed before versioning have no stored version and report
+//! [`UNVERSIONED`].
+
+use soroban_sdk::{Env, IntoVal, Map, Symbol, TryFromVal, Val};
+
+use crate::admin::CommonKey;
+
+/// Version of data written by builds that predate schema versioning
+pub const UNVERSIONED: u32 = 1;
+
+/// Record the schema version of a fresh deployment; call from `__constructor`
+pub fn init(env: &Env, version: u32) {
+    write_version(env, version);
+}
+
+pub fn read_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CommonKey::SchemaVersion)
+        .unwrap_or(UNVERSIONED)
+}
+
+pub fn write_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&CommonKey::SchemaVersion, &version);
+}
+
+/// Rewrite a temporary struct entry stored in a legacy layout in the current
+/// one. `added_field` is a field the current layout has and the legacy one
+/// lacks; entries that already have it, or are missing, are left untouched so
+/// a migration can be repeated safely.
+///
+/// Decoding a struct from a map with different fields traps instead of
+/// failing softly, which is why the layout is told apart by field name.
+///
+/// # Returns
+/// * `bool` - True if the entry was converted
+pub fn migrate_temporary<K, Old, New>(
+    env: &Env,
+    key: &K,
+    added_field: &str,
+    convert: impl FnOnce(Old) -> New,
+) -> bool
+where
+    K: IntoVal<Env, Val>,
+    Old: TryFromVal<Env, Val>,
+    New: IntoVal<Env, Val>,
+{
+    let storage = env.storage().temporary();
+    let Some(fields) = storage.get::<K, Map<Symbol, Val>>(key) else {
+        return false;
+    };
+    if fields.contains_key(Symbol::new(env, added_field)) {
+        return false;
+    }
+
+    let old: Old = storage.get(key).expect("Unknown legacy layout");
+    storage.set(key, &convert(old));
+    true
+}
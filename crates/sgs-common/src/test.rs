@@ -0,0 +1,427 @@
+#![cfg(test)]
+
+// Unit tests for the shared hub, admin and TTL plumbing, run through a small
+// game contract against the mock Game Hub.
+
+use crate::admin::{
+    self, AdminAccepted, AdminError, AdminProposed, CommonKey, PendingAdmin, Role, RoleGranted,
+    RoleRevoked,
+};
+use crate::{hub, schema, ttl, Outcome, SessionStatus};
+use mock_game_hub::{MockGameHub, MockGameHubClient};
+use soroban_sdk::testutils::storage::{Persistent as _, Temporary as _};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, BytesN, Env, Event as _};
+
+// ============================================================================
+// Test Contract
+// ============================================================================
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        admin::init(&env, &admin, &game_hub);
+    }
+
+    pub fn play(env: Env, player1: Address, player2: Address) -> u32 {
+        hub::client(&env).start_game(
+            &env.current_contract_address(),
+            &player1,
+            &player2,
+            &100,
+            &100,
+        )
+    }
+
+    pub fn finish(env: Env, session_id: u32, outcome: Outcome) {
+        hub::client(&env).end_game(&session_id, &outcome);
+    }
+
+    pub fn status(env: Env, session_id: u32) -> SessionStatus {
+        hub::client(&env).get_session_status(&session_id)
+    }
+}
+
+crate::impl_admin!(TestGame);
+crate::impl_hub_config!(TestGame);
+crate::impl_beacon_config!(TestGame);
+crate::impl_upgrade!(TestGame);
+
+/// Storage keys as games declared them before this crate existed
+#[contracttype]
+#[derive(Clone)]
+pub enum LegacyKey {
+    Game(u32),
+    GameHubAddress,
+    Admin,
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    TestGameClient<'static>,
+    MockGameHubClient<'static>,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let hub_admin = Address::generate(&env);
+    let hub_addr = env.register(MockGameHub, (&hub_admin,));
+    let game_hub = MockGameHubClient::new(&env, &hub_addr);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &hub_addr));
+    let client = TestGameClient::new(&env, &contract_id);
+    game_hub.add_game(&contract_id);
+
+    (env, client, game_hub, admin)
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_admin_functions() {
+    let (env, client, game_hub, admin) = setup_test();
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), game_hub.address);
+
+    let new_hub = Address::generate(&env);
+    client.set_hub(&admin, &new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+}
+
+#[test]
+fn test_admin_functions_require_admin_auth() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let hub_addr = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &hub_addr));
+    let client = TestGameClient::new(&env, &contract_id);
+
+    let other = Address::generate(&env);
+    assert!(client.try_propose_admin(&other, &100).is_err());
+    assert!(client.try_grant_role(&Role::HubManager, &other).is_err());
+    assert!(client.try_set_hub(&admin, &other).is_err());
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), hub_addr);
+}
+
+// ============================================================================
+// Admin Transfer Tests
+// ============================================================================
+
+#[test]
+fn test_admin_transfer() {
+    let (env, client, _game_hub, admin) = setup_test();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin, &100);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [AdminProposed {
+            new_admin: new_admin.clone(),
+            live_until_ledger: 100,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    assert_eq!(
+        client.get_pending_admin(),
+        Some(PendingAdmin {
+            address: new_admin.clone(),
+            live_until_ledger: 100,
+        })
+    );
+    assert_eq!(client.get_admin(), admin);
+
+    assert_eq!(client.accept_admin(), new_admin);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [AdminAccepted {
+            previous_admin: admin,
+            new_admin: new_admin.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_accept_admin_requires_new_admin_auth() {
+    let (env, client, _game_hub, admin) = setup_test();
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin, &100);
+
+    env.set_auths(&[]);
+    assert!(client.try_accept_admin().is_err());
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_admin_proposal_expiry() {
+    let (env, client, _game_hub, _admin) = setup_test();
+    let new_admin = Address::generate(&env);
+    env.ledger().set_sequence_number(50);
+
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(AdminError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_propose_admin(&new_admin, &50),
+        Err(Ok(AdminError::InvalidExpiry))
+    );
+
+    client.propose_admin(&new_admin, &60);
+    env.ledger().set_sequence_number(61);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(AdminError::AdminProposalExpired))
+    );
+}
+
+// ============================================================================
+// Role Tests
+// ============================================================================
+
+#[test]
+fn test_roles_gate_routine_operations() {
+    let (env, client, _game_hub, _admin) = setup_test();
+    let ops = Address::generate(&env);
+    let new_hub = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+    assert_eq!(
+        client.try_set_hub(&ops, &new_hub),
+        Err(Ok(AdminError::MissingRole.into()))
+    );
+
+    client.grant_role(&Role::HubManager, &ops);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [RoleGranted {
+            role: Role::HubManager,
+            account: ops.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    assert!(client.has_role(&Role::HubManager, &ops));
+    assert!(!client.has_role(&Role::Upgrader, &ops));
+    client.set_hub(&ops, &new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+
+    // Holding one role grants nothing else
+    assert!(client.try_upgrade(&ops, &wasm_hash).is_err());
+
+    client.revoke_role(&Role::HubManager, &ops);
+    assert_eq!(
+        env.events().all().filter_by_contract(&client.address),
+        [RoleRevoked {
+            role: Role::HubManager,
+            account: ops.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+    assert!(!client.has_role(&Role::HubManager, &ops));
+    assert_eq!(
+        client.try_set_hub(&ops, &new_hub),
+        Err(Ok(AdminError::MissingRole.into()))
+    );
+}
+
+#[test]
+fn test_beacon_address_is_managed_like_the_hub() {
+    let (env, client, _game_hub, admin) = setup_test();
+    let ops = Address::generate(&env);
+    let beacon = Address::generate(&env);
+
+    // Games deployed before the beacon have none until it is set
+    assert!(client.try_get_beacon().is_err());
+
+    client.set_beacon(&admin, &beacon);
+    assert_eq!(client.get_beacon(), beacon);
+
+    let new_beacon = Address::generate(&env);
+    assert!(client.try_set_beacon(&ops, &new_beacon).is_err());
+    client.grant_role(&Role::HubManager, &ops);
+    client.set_beacon(&ops, &new_beacon);
+    assert_eq!(client.get_beacon(), new_beacon);
+}
+
+// ============================================================================
+// Storage Layout Tests
+// ============================================================================
+
+#[test]
+fn test_keys_match_legacy_storage_layout() {
+    let (env, client, game_hub, admin) = setup_test();
+
+    env.as_contract(&client.address, || {
+        let legacy_admin: Address = env.storage().instance().get(&LegacyKey::Admin).unwrap();
+        let legacy_hub: Address = env
+            .storage()
+            .instance()
+            .get(&LegacyKey::GameHubAddress)
+            .unwrap();
+        assert_eq!(legacy_admin, admin);
+        assert_eq!(legacy_hub, game_hub.address);
+        assert!(env.storage().instance().has(&CommonKey::Admin));
+    });
+}
+
+// ============================================================================
+// Hub Client Tests
+// ============================================================================
+
+#[test]
+fn test_hub_client_round_trip() {
+    let (env, client, game_hub, _admin) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    game_hub.add_points(&player1, &1000);
+    game_hub.add_points(&player2, &1000);
+
+    let session_id = client.play(&player1, &player2);
+    assert_eq!(client.status(&session_id), SessionStatus::Active);
+
+    client.finish(&session_id, &Outcome::Player2);
+    assert_eq!(
+        client.status(&session_id),
+        SessionStatus::Ended(Outcome::Player2)
+    );
+    assert_eq!(game_hub.get_balance(&player2), 1100);
+}
+
+#[test]
+fn test_find_session_only_finds_the_games_own_sessions() {
+    let (env, client, game_hub, _admin) = setup_test();
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    game_hub.add_points(&player1, &1000);
+    game_hub.add_points(&player2, &1000);
+    let session_id = client.play(&player1, &player2);
+
+    // Another game's session and an ID the hub never issued are not found
+    let other_game = Address::generate(&env);
+    game_hub.add_game(&other_game);
+    let other_session = game_hub.start_game(&other_game, &player1, &player2, &100, &100);
+
+    env.as_contract(&client.address, || {
+        let session = hub::find_session(&env, session_id).unwrap();
+        assert_eq!(session.players, vec![&env, player1, player2]);
+        assert!(hub::find_session(&env, other_session).is_none());
+        assert!(hub::find_session(&env, 999).is_none());
+    });
+}
+
+// ============================================================================
+// Schema Tests
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordV1 {
+    pub value: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordV2 {
+    pub value: u32,
+    pub flagged: bool,
+}
+
+#[test]
+fn test_schema_version() {
+    let (env, client, _game_hub, _admin) = setup_test();
+
+    env.as_contract(&client.address, || {
+        assert_eq!(schema::read_version(&env), schema::UNVERSIONED);
+        schema::init(&env, 3);
+        assert_eq!(schema::read_version(&env), 3);
+    });
+}
+
+#[test]
+fn test_migrate_temporary() {
+    let (env, client, _game_hub, _admin) = setup_test();
+    let upgrade = |old: RecordV1| RecordV2 {
+        value: old.value,
+        flagged: false,
+    };
+
+    env.as_contract(&client.address, || {
+        let legacy = LegacyKey::Game(1);
+        let current = LegacyKey::Game(2);
+        env.storage()
+            .temporary()
+            .set(&legacy, &RecordV1 { value: 7 });
+        env.storage().temporary().set(
+            &current,
+            &RecordV2 {
+                value: 8,
+                flagged: true,
+            },
+        );
+
+        assert!(schema::migrate_temporary(&env, &legacy, "flagged", upgrade));
+        assert!(!schema::migrate_temporary(
+            &env, &legacy, "flagged", upgrade
+        ));
+        assert!(!schema::migrate_temporary(
+            &env, &current, "flagged", upgrade
+        ));
+        assert!(!schema::migrate_temporary(
+            &env,
+            &LegacyKey::Game(3),
+            "flagged",
+            upgrade
+        ));
+
+        let migrated: RecordV2 = env.storage().temporary().get(&legacy).unwrap();
+        assert_eq!(
+            migrated,
+            RecordV2 {
+                value: 7,
+                flagged: false
+            }
+        );
+        let untouched: RecordV2 = env.storage().temporary().get(&current).unwrap();
+        assert!(untouched.flagged);
+    });
+}
+
+// ============================================================================
+// TTL Tests
+// ============================================================================
+
+#[test]
+fn test_extend_ttl_helpers() {
+    let (env, client, _game_hub, _admin) = setup_test();
+
+    env.as_contract(&client.address, || {
+        let key = LegacyKey::Game(1);
+        env.storage().temporary().set(&key, &1u32);
+        ttl::extend_temporary(&env, &key);
+        assert_eq!(
+            env.storage().temporary().get_ttl(&key),
+            ttl::GAME_TTL_LEDGERS
+        );
+
+        env.storage().persistent().set(&key, &1u32);
+        ttl::extend_persistent(&env, &key);
+        assert_eq!(
+            env.storage().persistent().get_ttl(&key),
+            ttl::TTL_EXTEND_LEDGERS
+        );
+    });
+}
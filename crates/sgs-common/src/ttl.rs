@@ -0,0 +1,44 @@
+//! Storage TTL management.
+//!
+//! TTL (Time To Live) ensures game data doesn't expire unexpectedly. Active
+//! games sit in temporary storage with a minimum 30-day retention; anything
+//! holding funds belongs in persistent storage and is bumped when touched.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Extend TTL once it drops below ~1 day (17,280 ledgers)
+pub const TTL_THRESHOLD_LEDGERS: u32 = 17_280;
+
+/// Extend TTL to 30 days (518,400 ledgers)
+pub const TTL_EXTEND_LEDGERS: u32 = 518_400;
+
+/// Keep a temporary entry alive for at least [`GAME_TTL_LEDGERS`]
+pub fn extend_temporary<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Bump a persistent entry to [`TTL_EXTEND_LEDGERS`] once it runs low
+pub fn extend_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Bump the contract instance to [`TTL_EXTEND_LEDGERS`] once it runs low
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
@@ -277,7 +277,7 @@ if (shouldEnsureMock) {
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -300,17 +300,55 @@ for (const contract of contracts) {
     console.log(`  WASM hash: ${wasmHash}`);
 
     console.log("  Deploying and initializing...");
-    const deployResult =
-      await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
+    const deployResult = contract.isBeacon
+      ? await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text()
+      : await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
-    console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
+    console.log(`✅ ${contract.packageName} deployed: ${contractId}`);
+
+    // The beacon is not a game, so it does not report to the hub
+    if (contract.isBeacon) {
+      console.log("");
+      continue;
+    }
+
+    // The hub only accepts sessions from registered games. Registration needs the
+    // hub admin, which is only known when the hub was deployed in this run.
+    console.log("  Registering with Game Hub...");
+    try {
+      await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- add_game --game_id ${contractId}`.text();
+      console.log(`✅ ${contract.packageName} registered with Game Hub\n`);
+    } catch (error) {
+      console.warn(`⚠️  Warning: Could not register ${contract.packageName} with Game Hub ${mockGameHubId}.`);
+      console.warn("   Ask the hub admin to call add_game for this contract.\n");
+    }
   } catch (error) {
     console.error(`❌ Failed to deploy ${contract.packageName}:`, error);
     process.exit(1);
   }
 }
 
+// Games that draw from the randomness beacon need its address before they can start games
+const beaconGames = ["dice-duel", "number-guess", "twenty-one"];
+const beacon = allContracts.find((c) => c.isBeacon);
+const beaconId = beacon ? deployed[beacon.packageName] || existingContractIds[beacon.packageName] : "";
+for (const contract of contracts) {
+  if (!beaconGames.includes(contract.packageName)) continue;
+  if (!beaconId) {
+    console.warn(`⚠️  Warning: No randomness beacon deployed; ${contract.packageName} cannot start games until set_beacon is called.\n`);
+    continue;
+  }
+  console.log(`Pointing ${contract.packageName} at randomness beacon ${beaconId}...`);
+  try {
+    await $`stellar contract invoke --id ${deployed[contract.packageName]} --source-account ${adminSecret} --network ${NETWORK} -- set_beacon --caller ${adminAddress} --new_beacon ${beaconId}`.text();
+    console.log(`✅ ${contract.packageName} beacon set\n`);
+  } catch (error) {
+    console.warn(`⚠️  Warning: Could not set the beacon on ${contract.packageName}.`);
+    console.warn("   Ask the game admin to call set_beacon for this contract.\n");
+  }
+}
+
 console.log("🎉 Deployment complete!\n");
 console.log("Contract IDs:");
 const outputContracts = new Set<string>();
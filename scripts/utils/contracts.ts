@@ -9,6 +9,7 @@ export type ContractInfo = {
   envKey: string;
   bindingsOutDir: string;
   isMockHub: boolean;
+  isBeacon: boolean;
 };
 
 export type ContractSelection = {
@@ -65,6 +66,7 @@ export async function getWorkspaceContracts(): Promise<ContractInfo[]> {
       envKey,
       bindingsOutDir: `bindings/${wasmName}`,
       isMockHub: packageName === "mock-game-hub" || wasmName === "mock_game_hub",
+      isBeacon: packageName === "randomness",
     });
   }
 
@@ -314,17 +314,17 @@ function CreateGameSection() {
             <pre>
               <code>{`#[contractclient(name = "GameHubClient")]
 pub trait GameHub {
+    // Returns the session ID allocated by the hub
     fn start_game(
         env: Env,
         game_id: Address,
-        session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    );
+    ) -> u32;
 
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn end_game(env: Env, session_id: u32, outcome: Outcome);
 }`}</code>
             </pre>
           </div>
@@ -40,16 +40,33 @@ export const networks = {
 
 
 export interface Game {
+  is_draw: boolean;
   player1: string;
+  /**
+   * `keccak256(secret)` committed by player 1's roll
+   */
+  player1_commitment: Option<Buffer>;
   player1_die1: Option<u32>;
   player1_die2: Option<u32>;
   player1_points: i128;
-  player1_rolled: boolean;
+  /**
+   * Player 1's secret, once revealed
+   */
+  player1_secret: Option<Buffer>;
   player2: string;
+  player2_commitment: Option<Buffer>;
   player2_die1: Option<u32>;
   player2_die2: Option<u32>;
   player2_points: i128;
-  player2_rolled: boolean;
+  player2_secret: Option<Buffer>;
+  /**
+   * Last ledger on which secrets can be revealed, set once both players have rolled
+   */
+  reveal_deadline: Option<u32>;
+  /**
+   * Beacon output the dice were rolled from
+   */
+  seed: Option<Buffer>;
   winner: Option<string>;
 }
 
@@ -58,49 +75,131 @@ export const Errors = {
   2: {message:"NotPlayer"},
   3: {message:"AlreadyRolled"},
   4: {message:"BothPlayersNotRolled"},
-  5: {message:"GameAlreadyEnded"}
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"SessionAlreadyExists"},
+  7: {message:"GameInProgress"},
+  8: {message:"InvalidReveal"},
+  9: {message:"AlreadyRevealed"},
+  10: {message:"RevealWindowClosed"},
+  11: {message:"RevealWindowOpen"},
+  12: {message:"SessionExpiring"},
+  100: {message:"NoPendingAdmin"},
+  101: {message:"AdminProposalExpired"},
+  102: {message:"InvalidExpiry"},
+  103: {message:"MissingRole"}
+}
+
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+/**
+ * Admin transfer waiting for the new admin to accept
+ */
+export interface PendingAdmin {
+  address: string;
+  /**
+   * Last ledger on which the proposal can be accepted
+   */
+  live_until_ledger: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Permission that can be granted to an account besides the admin
+ */
+export type Role = {tag: "Upgrader", values: void} | {tag: "HubManager", values: void} | {tag: "Pauser", values: void};
 
 export interface Client {
   /**
-   * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Commit a roll for the current game.
-   * Both players must roll before the winner can be revealed.
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player rolling the dice
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * 
+   * # Returns
+   * * `u32` - Session ID allocated by the Game Hub
    */
-  roll: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_game: ({player1, player2, player1_points, player2_points}: {player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a start_series transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a best-of-N series between two players and its first game.
+   * The points are locked once by the Game Hub for the whole series and
+   * paid to the series winner; the games themselves stake nothing.
+   * 
+   * # Arguments
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points committed by player 1 to the series
+   * * `player2_points` - Points committed by player 2 to the series
+   * * `best_of` - Number of games in the series (odd)
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `u32` - Session ID of the first game
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_series: ({player1, player2, player1_points, player2_points, best_of}: {player1: string, player2: string, player1_points: i128, player2_points: i128, best_of: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game with the same players as a finished one.
+   * Inside a series this is the series' next game and needs no player
+   * authorization; otherwise it replays the stakes and both players must
+   * authorize it.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the finished game
+   * 
+   * # Returns
+   * * `u32` - Session ID of the new game
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  rematch: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit a roll for the current game.
+   * The roll is a commitment to a secret that the player reveals with
+   * `reveal_roll` once both players have rolled.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player rolling the dice
+   * * `commitment` - `keccak256(secret)` for the player's 32-byte secret
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  roll: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the secret behind a roll.
+   * Can only be called after both players have rolled and before the reveal
+   * deadline. The second reveal derives both players' dice from the two
+   * secrets, determines the winner and ends the session.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `secret` - The secret committed to in `roll`
+   */
+  reveal_roll: ({session_id, player, secret}: {session_id: u32, player: string, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game whose reveal deadline has passed without both reveals.
+   * A player who revealed wins against one who did not; if neither
+   * revealed, the game is a draw. Anyone can call this.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
+   */
+  claim_forfeit: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -115,53 +214,144 @@ export interface Client {
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the schema version of the stored data
    * 
    * # Returns
-   * * `Address` - The admin address
+   * * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Convert games stored by an earlier build to the current layout (admin or upgrader).
+   * Run after `upgrade`, once the beacon is set. Missing or already converted
+   * games are skipped, so a large backlog can be migrated in batches; the
+   * version only moves once the last batch is sent. Those builds chose their
+   * own session IDs and the hub kept no record of them, so their games play
+   * on without a hub session and end without settling anything at the hub.
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `session_ids` - Session IDs of in-flight games
+   * * `complete` - True on the last batch; records this build's schema version
+   * 
+   * # Returns
+   * * `u32` - Number of games converted
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  migrate: ({caller, session_ids, complete}: {caller: string, session_ids: Array<u32>, complete: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin transfer waiting to be accepted, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingAdmin>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (admin or upgrader)
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Arguments
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `new_wasm_hash` - The hash of the new WASM binary
+   */
+  upgrade: ({caller, new_wasm_hash}: {caller: string, new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin proposal (proposed admin only)
+   * 
+   * # Returns
+   * * `Address` - The new admin address
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   * 
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the randomness beacon contract address (admin or hub manager)
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_beacon` - The new randomness beacon contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_beacon: ({caller, new_beacon}: {caller: string, new_beacon: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have rolled.
-   * This generates dice rolls for both players, determines the winner, and ends the session.
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account (admin only)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
+   * * `role` - The role to revoke
+   * * `account` - The account losing it
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address (admin or hub manager)
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_hub` - The new GameHub contract address
+   */
+  set_hub: ({caller, new_hub}: {caller: string, new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the randomness beacon contract address
+   * 
+   * # Returns
+   * * `Address` - The randomness beacon contract address
+   */
+  get_beacon: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only). The transfer completes when
+   * `new_admin` calls `accept_admin`; proposing again replaces it.
+   * 
+   * # Arguments
+   * * `new_admin` - The proposed admin address
+   * * `live_until_ledger` - Last ledger on which the proposal can be accepted
+   */
+  propose_admin: ({new_admin, live_until_ledger}: {new_admin: string, live_until_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `Address` - The admin address
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account (admin only)
+   * 
+   * # Arguments
+   * * `role` - The role to grant
+   * * `account` - The account receiving it
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account was granted a role
+   * 
+   * # Returns
+   * * `bool` - True if granted (the admin holds every role implicitly)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
 }
 export class Client extends ContractClient {
@@ -183,31 +373,58 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAU=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAMpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlAAAAAAAEcm9sbAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAAFFNlc3Npb25BbHJlYWR5RXhpc3RzAAAABgAAAAAAAAAOR2FtZUluUHJvZ3Jlc3MAAAAAAAcAAAAAAAAADUludmFsaWRSZXZlYWwAAAAAAAAIAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAACQAAAAAAAAASUmV2ZWFsV2luZG93Q2xvc2VkAAAAAAAKAAAAAAAAABBSZXZlYWxXaW5kb3dPcGVuAAAACwAAAAAAAAAPU2Vzc2lvbkV4cGlyaW5nAAAAAAw=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAQAAAAAAAAAAdpc19kcmF3AAAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAADBga2VjY2FrMjU2KHNlY3JldClgIGNvbW1pdHRlZCBieSBwbGF5ZXIgMSdzIHJvbGwAAAAScGxheWVyMV9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAgUGxheWVyIDEncyBzZWNyZXQsIG9uY2UgcmV2ZWFsZWQAAAAOcGxheWVyMV9zZWNyZXQAAAAAA+gAAAPuAAAAIAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABJwbGF5ZXIyX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAMcGxheWVyMl9kaWUxAAAD6AAAAAQAAAAAAAAADHBsYXllcjJfZGllMgAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9zZWNyZXQAAAAAA+gAAAPuAAAAIAAAAE9MYXN0IGxlZGdlciBvbiB3aGljaCBzZWNyZXRzIGNhbiBiZSByZXZlYWxlZCwgc2V0IG9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkAAAAAA9yZXZlYWxfZGVhZGxpbmUAAAAD6AAAAAQAAAAnQmVhY29uIG91dHB1dCB0aGUgZGljZSB3ZXJlIHJvbGxlZCBmcm9tAAAAAARzZWVkAAAD6AAAA+4AAAAgAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAAAAAAAAAAAACkFkbWluRXJyb3IAAAAAAAQAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAABkAAAAAAAAABRBZG1pblByb3Bvc2FsRXhwaXJlZAAAAGUAAAAAAAAADUludmFsaWRFeHBpcnkAAAAAAABmAAAAAAAAAAtNaXNzaW5nUm9sZQAAAABn",
+        "AAAAAQAAADJBZG1pbiB0cmFuc2ZlciB3YWl0aW5nIGZvciB0aGUgbmV3IGFkbWluIHRvIGFjY2VwdAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAxTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAgAAAD5QZXJtaXNzaW9uIHRoYXQgY2FuIGJlIGdyYW50ZWQgdG8gYW4gYWNjb3VudCBiZXNpZGVzIHRoZSBhZG1pbgAAAAAAAAAAAARSb2xlAAAAAwAAAAAAAAAdQ2FuIHJlcGxhY2UgdGhlIGNvbnRyYWN0IFdBU00AAAAAAAAIVXBncmFkZXIAAAAAAAAAWENhbiBjaGFuZ2UgdGhlIEdhbWVIdWIgYWRkcmVzcyBhIGdhbWUgcmVwb3J0cyB0byBhbmQgdGhlIHJhbmRvbW5lc3MKYmVhY29uIGl0IGRyYXdzIGZyb20AAAAKSHViTWFuYWdlcgAAAAAAAAAAABpDYW4gcGF1c2UgYW5kIHVucGF1c2UgcGxheQAAAAAABlBhdXNlcgAA",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAjNTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCgojIFJldHVybnMKKiBgdTMyYCAtIFNlc3Npb24gSUQgYWxsb2NhdGVkIGJ5IHRoZSBHYW1lIEh1YgAAAAAKc3RhcnRfZ2FtZQAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAgFTdGFydCBhIGJlc3Qtb2YtTiBzZXJpZXMgYmV0d2VlbiB0d28gcGxheWVycyBhbmQgaXRzIGZpcnN0IGdhbWUuClRoZSBwb2ludHMgYXJlIGxvY2tlZCBvbmNlIGJ5IHRoZSBHYW1lIEh1YiBmb3IgdGhlIHdob2xlIHNlcmllcyBhbmQKcGFpZCB0byB0aGUgc2VyaWVzIHdpbm5lcjsgdGhlIGdhbWVzIHRoZW1zZWx2ZXMgc3Rha2Ugbm90aGluZy4KCiMgQXJndW1lbnRzCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcGxheWVyMV9wb2ludHNgIC0gUG9pbnRzIGNvbW1pdHRlZCBieSBwbGF5ZXIgMSB0byB0aGUgc2VyaWVzCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBjb21taXR0ZWQgYnkgcGxheWVyIDIgdG8gdGhlIHNlcmllcwoqIGBiZXN0X29mYCAtIE51bWJlciBvZiBnYW1lcyBpbiB0aGUgc2VyaWVzIChvZGQpCgojIFJldHVybnMKKiBgdTMyYCAtIFNlc3Npb24gSUQgb2YgdGhlIGZpcnN0IGdhbWUAAAAAAAAMc3RhcnRfc2VyaWVzAAAABQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAHYmVzdF9vZgAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAUBTdGFydCBhIG5ldyBnYW1lIHdpdGggdGhlIHNhbWUgcGxheWVycyBhcyBhIGZpbmlzaGVkIG9uZS4KSW5zaWRlIGEgc2VyaWVzIHRoaXMgaXMgdGhlIHNlcmllcycgbmV4dCBnYW1lIGFuZCBuZWVkcyBubyBwbGF5ZXIKYXV0aG9yaXphdGlvbjsgb3RoZXJ3aXNlIGl0IHJlcGxheXMgdGhlIHN0YWtlcyBhbmQgYm90aCBwbGF5ZXJzIG11c3QKYXV0aG9yaXplIGl0LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZmluaXNoZWQgZ2FtZQoKIyBSZXR1cm5zCiogYHUzMmAgLSBTZXNzaW9uIElEIG9mIHRoZSBuZXcgZ2FtZQAAAAdyZW1hdGNoAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAURDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpUaGUgcm9sbCBpcyBhIGNvbW1pdG1lbnQgdG8gYSBzZWNyZXQgdGhhdCB0aGUgcGxheWVyIHJldmVhbHMgd2l0aApgcmV2ZWFsX3JvbGxgIG9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJvbGxpbmcgdGhlIGRpY2UKKiBgY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KHNlY3JldClgIGZvciB0aGUgcGxheWVyJ3MgMzItYnl0ZSBzZWNyZXQAAAAEcm9sbAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAXZSZXZlYWwgdGhlIHNlY3JldCBiZWhpbmQgYSByb2xsLgpDYW4gb25seSBiZSBjYWxsZWQgYWZ0ZXIgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkIGFuZCBiZWZvcmUgdGhlIHJldmVhbApkZWFkbGluZS4gVGhlIHNlY29uZCByZXZlYWwgZGVyaXZlcyBib3RoIHBsYXllcnMnIGRpY2UgZnJvbSB0aGUgdHdvCnNlY3JldHMsIGRldGVybWluZXMgdGhlIHdpbm5lciBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHJldmVhbGluZyBwbGF5ZXIKKiBgc2VjcmV0YCAtIFRoZSBzZWNyZXQgY29tbWl0dGVkIHRvIGluIGByb2xsYAAAAAAAC3JldmVhbF9yb2xsAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGc2VjcmV0AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAUJFbmQgYSBnYW1lIHdob3NlIHJldmVhbCBkZWFkbGluZSBoYXMgcGFzc2VkIHdpdGhvdXQgYm90aCByZXZlYWxzLgpBIHBsYXllciB3aG8gcmV2ZWFsZWQgd2lucyBhZ2FpbnN0IG9uZSB3aG8gZGlkIG5vdDsgaWYgbmVpdGhlcgpyZXZlYWxlZCwgdGhlIGdhbWUgaXMgYSBkcmF3LiBBbnlvbmUgY2FuIGNhbGwgdGhpcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBmb3IgYSBkcmF3AAAAAAANY2xhaW1fZm9yZmVpdAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
         "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAI5HZXQgdGhlIHNjaGVtYSB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgZGF0YQoKIyBSZXR1cm5zCiogYHUzMmAgLSBTY2hlbWEgdmVyc2lvbjsgbGFncyB0aGlzIGJ1aWxkJ3MgdmVyc2lvbiB1bnRpbCBgbWlncmF0ZWAgcnVucyBhZnRlciBhbiB1cGdyYWRlAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAqtDb252ZXJ0IGdhbWVzIHN0b3JlZCBieSBhbiBlYXJsaWVyIGJ1aWxkIHRvIHRoZSBjdXJyZW50IGxheW91dCAoYWRtaW4gb3IgdXBncmFkZXIpLgpSdW4gYWZ0ZXIgYHVwZ3JhZGVgLCBvbmNlIHRoZSBiZWFjb24gaXMgc2V0LiBNaXNzaW5nIG9yIGFscmVhZHkgY29udmVydGVkCmdhbWVzIGFyZSBza2lwcGVkLCBzbyBhIGxhcmdlIGJhY2tsb2cgY2FuIGJlIG1pZ3JhdGVkIGluIGJhdGNoZXM7IHRoZQp2ZXJzaW9uIG9ubHkgbW92ZXMgb25jZSB0aGUgbGFzdCBiYXRjaCBpcyBzZW50LiBUaG9zZSBidWlsZHMgY2hvc2UgdGhlaXIKb3duIHNlc3Npb24gSURzIGFuZCB0aGUgaHViIGtlcHQgbm8gcmVjb3JkIG9mIHRoZW0sIHNvIHRoZWlyIGdhbWVzIHBsYXkKb24gd2l0aG91dCBhIGh1YiBzZXNzaW9uIGFuZCBlbmQgd2l0aG91dCBzZXR0bGluZyBhbnl0aGluZyBhdCB0aGUgaHViLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYFVwZ3JhZGVyYAoqIGBzZXNzaW9uX2lkc2AgLSBTZXNzaW9uIElEcyBvZiBpbi1mbGlnaHQgZ2FtZXMKKiBgY29tcGxldGVgIC0gVHJ1ZSBvbiB0aGUgbGFzdCBiYXRjaDsgcmVjb3JkcyB0aGlzIGJ1aWxkJ3Mgc2NoZW1hIHZlcnNpb24KCiMgUmV0dXJucwoqIGB1MzJgIC0gTnVtYmVyIG9mIGdhbWVzIGNvbnZlcnRlZAAAAAAHbWlncmF0ZQAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAAAAAAhjb21wbGV0ZQAAAAEAAAABAAAABA==",
+        "AAAAAAAAADVHZXQgdGhlIGFkbWluIHRyYW5zZmVyIHdhaXRpbmcgdG8gYmUgYWNjZXB0ZWQsIGlmIGFueQAAAAAAABFnZXRfcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMUGVuZGluZ0FkbWlu",
+        "AAAAAAAAAKpVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAoYWRtaW4gb3IgdXBncmFkZXIpCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGFuIGFjY291bnQgaG9sZGluZyBgVXBncmFkZXJgCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAB3VwZ3JhZGUAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAGRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHByb3Bvc2FsIChwcm9wb3NlZCBhZG1pbiBvbmx5KQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIG5ldyBhZG1pbiBhZGRyZXNzAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAABMAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAMRTZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MgKGFkbWluIG9yIGh1YiBtYW5hZ2VyKQoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYEh1Yk1hbmFnZXJgCiogYG5ld19iZWFjb25gIC0gVGhlIG5ldyByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAACnNldF9iZWFjb24AAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAKbmV3X2JlYWNvbgAAAAAAEwAAAAA=",
+        "AAAAAAAAAHlSZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gcmV2b2tlCiogYGFjY291bnRgIC0gVGhlIGFjY291bnQgbG9zaW5nIGl0AAAAAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAK9TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvciBodWIgbWFuYWdlcikKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYW4gYWNjb3VudCBob2xkaW5nIGBIdWJNYW5hZ2VyYAoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAdzZXRfaHViAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHbmV3X2h1YgAAAAATAAAAAA==",
+        "AAAAAAAAAGpHZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIFRoZSByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAAAAAKZ2V0X2JlYWNvbgAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAP5Qcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHRyYW5zZmVyIGNvbXBsZXRlcyB3aGVuCmBuZXdfYWRtaW5gIGNhbGxzIGBhY2NlcHRfYWRtaW5gOyBwcm9wb3NpbmcgYWdhaW4gcmVwbGFjZXMgaXQuCgojIEFyZ3VtZW50cwoqIGBuZXdfYWRtaW5gIC0gVGhlIHByb3Bvc2VkIGFkbWluIGFkZHJlc3MKKiBgbGl2ZV91bnRpbF9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAADXByb3Bvc2VfYWRtaW4AAAAAAAACAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAfQAAAACkFkbWluRXJyb3IAAA==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAThSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZC4KVGhpcyBnZW5lcmF0ZXMgZGljZSByb2xscyBmb3IgYm90aCBwbGF5ZXJzLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD" ]),
+        "AAAAAAAAAHhHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gZ3JhbnQKKiBgYWNjb3VudGAgLSBUaGUgYWNjb3VudCByZWNlaXZpbmcgaXQAAAAKZ3JhbnRfcm9sZQAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAAHlDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgd2FzIGdyYW50ZWQgYSByb2xlCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGdyYW50ZWQgKHRoZSBhZG1pbiBob2xkcyBldmVyeSByb2xlIGltcGxpY2l0bHkpAAAAAAAACGhhc19yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    roll: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<u32>>,
+        start_series: this.txFromJSON<Result<u32>>,
+        rematch: this.txFromJSON<Result<u32>>,
+        roll: this.txFromJSON<Result<void>>,
+        reveal_roll: this.txFromJSON<Result<void>>,
+        claim_forfeit: this.txFromJSON<Result<Option<string>>>,
+        get_game: this.txFromJSON<Result<Game>>,
+        version: this.txFromJSON<u32>,
+        migrate: this.txFromJSON<u32>,
+        get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
+        upgrade: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<string>>,
         get_hub: this.txFromJSON<string>,
+        set_beacon: this.txFromJSON<null>,
+        revoke_role: this.txFromJSON<null>,
         set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
-        get_game: this.txFromJSON<Result<Game>>,
+        get_beacon: this.txFromJSON<string>,
+        propose_admin: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        grant_role: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>
   }
 }
\ No newline at end of file
@@ -4,23 +4,14 @@ import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { getLocationSearch } from '@/utils/location';
 import { useWallet } from '@/hooks/useWallet';
 import { NUMBER_GUESS_CONTRACT } from '@/utils/constants';
-import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
+import { randomSecret, saveSecret, loadSecret, clearSecret } from '@/utils/commitUtils';
+import { getLatestLedgerSequence, findStartedSession, getAuthEntrySignedLedger } from '@/utils/sessionUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
+import { StrKey } from '@stellar/stellar-sdk';
+import { Buffer } from 'buffer';
 import type { Game } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
+const isSet = (value: unknown): boolean => value !== null && value !== undefined;
 
 // Create service instance with the contract ID
 const numberGuessService = new NumberGuessService(NUMBER_GUESS_CONTRACT);
@@ -47,9 +38,10 @@ export function NumberGuessGame({
 }: NumberGuessGameProps) {
   const DEFAULT_POINTS = '0.1';
   const { getContractSigner, walletType } = useWallet();
-  // Use a random session ID that fits in u32 (avoid 0 because UI validation treats <=0 as invalid)
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Session IDs are allocated by the Game Hub when the game starts (0 until then)
+  const [sessionId, setSessionId] = useState<number>(0);
   const [player1Address, setPlayer1Address] = useState(userAddress);
+  const [player2Address, setPlayer2Address] = useState('');
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
   const [guess, setGuess] = useState<number | null>(null);
   const [gameState, setGameState] = useState<Game | null>(null);
@@ -61,7 +53,7 @@ export function NumberGuessGame({
   const [createMode, setCreateMode] = useState<'create' | 'import' | 'load'>('create');
   const [exportedAuthEntryXDR, setExportedAuthEntryXDR] = useState<string | null>(null);
   const [importAuthEntryXDR, setImportAuthEntryXDR] = useState('');
-  const [importSessionId, setImportSessionId] = useState('');
+  const [importPlayer2, setImportPlayer2] = useState('');
   const [importPlayer1, setImportPlayer1] = useState('');
   const [importPlayer1Points, setImportPlayer1Points] = useState('');
   const [importPlayer2Points, setImportPlayer2Points] = useState(DEFAULT_POINTS);
@@ -122,10 +114,9 @@ export function NumberGuessGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && (isSet(game.winner) || game.is_draw)) {
         setGamePhase('complete');
-      } else if (game && game.player1_guess !== null && game.player1_guess !== undefined &&
-                 game.player2_guess !== null && game.player2_guess !== undefined) {
+      } else if (game && isSet(game.player1_commitment) && isSet(game.player2_commitment)) {
         setGamePhase('reveal');
       } else {
         setGamePhase('guess');
@@ -137,81 +128,73 @@ export function NumberGuessGame({
   };
 
   useEffect(() => {
-    if (gamePhase !== 'create') {
+    if (gamePhase !== 'create' && sessionId > 0) {
       loadGameState();
       const interval = setInterval(loadGameState, 5000); // Poll every 5 seconds
       return () => clearInterval(interval);
     }
   }, [sessionId, gamePhase]);
 
-  // Auto-refresh standings when game completes (for passive player who didn't call reveal_winner)
+  // Auto-refresh standings when game completes (for passive player who didn't send the final reveal)
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && (gameState?.winner || gameState?.is_draw)) {
       console.log('Game completed! Refreshing standings and dashboard data...');
       onStandingsRefresh(); // Refresh standings and available points; don't call onGameComplete() here or it will close the game!
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.winner, gameState?.is_draw]);
+
+  // Open a shared auth entry: load the game if Player 2 already started it, otherwise import it
+  const openAuthEntry = (authEntry: string) => {
+    try {
+      const parsed = numberGuessService.parseAuthEntry(authEntry);
+      const enterImportMode = () => {
+        setCreateMode('import');
+        setImportAuthEntryXDR(authEntry);
+        setImportPlayer2(parsed.player2);
+        setImportPlayer1(parsed.player1);
+        setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
+        setImportPlayer2Points('0.1');
+      };
+
+      // The auth entry carries no session ID; the hub's game_started event has it
+      findStartedSession(NUMBER_GUESS_CONTRACT, parsed.player1, parsed.player2, getAuthEntrySignedLedger(authEntry))
+        .then(async (startedSessionId) => {
+          const game = startedSessionId ? await numberGuessService.getGame(startedSessionId) : null;
+          if (startedSessionId && game) {
+            // Game exists! Load it directly instead of going to import mode
+            console.log('[Deep Link] Game already exists, loading session', startedSessionId);
+            setSessionId(startedSessionId);
+            setGameState(game);
+            setGamePhase('guess');
+          } else {
+            // Game doesn't exist yet, go to import mode
+            console.log('[Deep Link] Game not found, entering import mode');
+            enterImportMode();
+          }
+        })
+        .catch((err) => {
+          console.error('[Deep Link] Error checking game existence:', err);
+          // If we can't check, default to import mode
+          enterImportMode();
+        });
+    } catch (err) {
+      console.log('[Deep Link] Failed to parse auth entry, will retry on import');
+      setCreateMode('import');
+      setImportAuthEntryXDR(authEntry);
+      setImportPlayer2Points('0.1');
+    }
+  };
 
   // Handle initial values from URL deep linking or props
   // Expected URL formats:
-  //   - With auth entry: ?game=number-guess&auth=AAAA... (Session ID, P1 address, P1 points parsed from auth entry)
+  //   - With auth entry: ?game=number-guess&auth=AAAA... (Players and P1 points parsed from auth entry)
   //   - With session ID: ?game=number-guess&session-id=123 (Load existing game)
   // Note: GamesCatalog cleans URL params, so we prioritize props over URL
   useEffect(() => {
     // Priority 1: Check initialXDR prop (from GamesCatalog after URL cleanup)
     if (initialXDR) {
       console.log('[Deep Link] Using initialXDR prop from GamesCatalog');
-
-      try {
-        const parsed = numberGuessService.parseAuthEntry(initialXDR);
-        const sessionId = parsed.sessionId;
-
-        console.log('[Deep Link] Parsed session ID from initialXDR:', sessionId);
-
-        // Check if game already exists (both players have signed)
-        numberGuessService.getGame(sessionId)
-          .then((game) => {
-            if (game) {
-              // Game exists! Load it directly instead of going to import mode
-              console.log('[Deep Link] Game already exists, loading directly to guess phase');
-              console.log('[Deep Link] Game data:', game);
-
-              // Auto-load the game - bypass create phase entirely
-              setGameState(game);
-              setGamePhase('guess');
-              setSessionId(sessionId); // Set session ID for the game
-            } else {
-              // Game doesn't exist yet, go to import mode
-              console.log('[Deep Link] Game not found, entering import mode');
-              setCreateMode('import');
-              setImportAuthEntryXDR(initialXDR);
-              setImportSessionId(sessionId.toString());
-              setImportPlayer1(parsed.player1);
-              setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-              setImportPlayer2Points('0.1');
-            }
-          })
-          .catch((err) => {
-            console.error('[Deep Link] Error checking game existence:', err);
-            console.error('[Deep Link] Error details:', {
-              message: err?.message,
-              stack: err?.stack,
-              sessionId: sessionId,
-            });
-            // If we can't check, default to import mode
-            setCreateMode('import');
-            setImportAuthEntryXDR(initialXDR);
-            setImportSessionId(parsed.sessionId.toString());
-            setImportPlayer1(parsed.player1);
-            setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-            setImportPlayer2Points('0.1');
-          });
-      } catch (err) {
-        console.log('[Deep Link] Failed to parse initialXDR, will retry on import');
-        setCreateMode('import');
-        setImportAuthEntryXDR(initialXDR);
-        setImportPlayer2Points('0.1');
-      }
+      openAuthEntry(initialXDR);
       return; // Exit early - we processed initialXDR
     }
 
@@ -222,60 +205,9 @@ export function NumberGuessGame({
 
     if (authEntry) {
       // Simplified URL format - only auth entry is needed
-      // Session ID, Player 1 address, and points are parsed from auth entry
+      // Player addresses and Player 1's points are parsed from auth entry
       console.log('[Deep Link] Auto-populating game from URL with auth entry');
-
-      // Try to parse auth entry to get session ID
-      try {
-        const parsed = numberGuessService.parseAuthEntry(authEntry);
-        const sessionId = parsed.sessionId;
-
-        console.log('[Deep Link] Parsed session ID from URL auth entry:', sessionId);
-
-        // Check if game already exists (both players have signed)
-        numberGuessService.getGame(sessionId)
-          .then((game) => {
-            if (game) {
-              // Game exists! Load it directly instead of going to import mode
-              console.log('[Deep Link] Game already exists (URL), loading directly to guess phase');
-              console.log('[Deep Link] Game data:', game);
-
-              // Auto-load the game - bypass create phase entirely
-              setGameState(game);
-              setGamePhase('guess');
-              setSessionId(sessionId); // Set session ID for the game
-            } else {
-              // Game doesn't exist yet, go to import mode
-              console.log('[Deep Link] Game not found (URL), entering import mode');
-              setCreateMode('import');
-              setImportAuthEntryXDR(authEntry);
-              setImportSessionId(sessionId.toString());
-              setImportPlayer1(parsed.player1);
-              setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-              setImportPlayer2Points('0.1');
-            }
-          })
-          .catch((err) => {
-            console.error('[Deep Link] Error checking game existence (URL):', err);
-            console.error('[Deep Link] Error details:', {
-              message: err?.message,
-              stack: err?.stack,
-              sessionId: sessionId,
-            });
-            // If we can't check, default to import mode
-            setCreateMode('import');
-            setImportAuthEntryXDR(authEntry);
-            setImportSessionId(parsed.sessionId.toString());
-            setImportPlayer1(parsed.player1);
-            setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-            setImportPlayer2Points('0.1');
-          });
-      } catch (err) {
-        console.log('[Deep Link] Failed to parse auth entry from URL, will retry on import');
-        setCreateMode('import');
-        setImportAuthEntryXDR(authEntry);
-        setImportPlayer2Points('0.1');
-      }
+      openAuthEntry(authEntry);
     } else if (urlSessionId) {
       // Load existing game by session ID
       console.log('[Deep Link] Auto-populating game from URL with session ID');
@@ -297,7 +229,7 @@ export function NumberGuessGame({
         setXdrParsing(false);
         setXdrParseError(null);
         setXdrParseSuccess(false);
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
       }
@@ -319,14 +251,19 @@ export function NumberGuessGame({
           throw new Error('You cannot play against yourself. This auth entry was created by you (Player 1).');
         }
 
+        // Player 1 signed for a specific opponent
+        if (gameParams.player2 !== userAddress) {
+          throw new Error(`This auth entry invites ${gameParams.player2}. Connect that wallet to accept it.`);
+        }
+
         // Successfully parsed - auto-fill fields
-        setImportSessionId(gameParams.sessionId.toString());
+        setImportPlayer2(gameParams.player2);
         setImportPlayer1(gameParams.player1);
         setImportPlayer1Points((Number(gameParams.player1Points) / 10_000_000).toString());
         setXdrParseSuccess(true);
         console.log('[Auto-Parse] Successfully parsed auth entry:', {
-          sessionId: gameParams.sessionId,
           player1: gameParams.player1,
+          player2: gameParams.player2,
           player1Points: (Number(gameParams.player1Points) / 10_000_000).toString(),
         });
       } catch (err) {
@@ -334,7 +271,7 @@ export function NumberGuessGame({
         const errorMsg = err instanceof Error ? err.message : 'Invalid auth entry XDR';
         setXdrParseError(errorMsg);
         // Clear auto-filled fields on error
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
       } finally {
@@ -359,19 +296,26 @@ export function NumberGuessGame({
           throw new Error('Enter a valid points amount');
         }
 
+        // Player 1's auth entry binds the opponent's address
+        if (!StrKey.isValidEd25519PublicKey(player2Address)) {
+          throw new Error('Enter a valid opponent address (Player 2)');
+        }
+        if (player2Address === player1Address) {
+          throw new Error('Cannot play against yourself. Player 2 must be different from Player 1.');
+        }
+
         const signer = getContractSigner();
 
-        // Use placeholder values for Player 2 (they'll rebuild with their own values).
-        // We still need a real, funded account as the transaction source for build/simulation.
-        const placeholderPlayer2Address = await getFundedSimulationSourceAddress([player1Address, userAddress]);
-        const placeholderP2Points = p1Points; // Same as P1 for simulation
+        // Player 2 picks their own points when they import; use Player 1's for simulation.
+        const placeholderP2Points = p1Points;
+
+        // The hub allocates the session ID, so look for the game_started event from here on
+        const startLedger = await getLatestLedgerSequence();
 
         console.log('Preparing transaction for Player 1 to sign...');
-        console.log('Using placeholder Player 2 values for simulation only');
         const authEntryXDR = await numberGuessService.prepareStartGame(
-          sessionId,
           player1Address,
-          placeholderPlayer2Address,
+          player2Address,
           p1Points,
           placeholderP2Points,
           signer
@@ -384,13 +328,20 @@ export function NumberGuessGame({
         // Start polling for the game to be created by Player 2
         const pollInterval = setInterval(async () => {
           try {
-            // Try to load the game
-            const game = await numberGuessService.getGame(sessionId);
-            if (game) {
+            // Try to find the session the hub allocated, then load the game
+            const startedSessionId = await findStartedSession(
+              NUMBER_GUESS_CONTRACT,
+              player1Address,
+              player2Address,
+              startLedger
+            );
+            const game = startedSessionId ? await numberGuessService.getGame(startedSessionId) : null;
+            if (startedSessionId && game) {
               console.log('Game found! Player 2 has finalized the transaction. Transitioning to guess phase...');
               clearInterval(pollInterval);
 
               // Update game state
+              setSessionId(startedSessionId);
               setGameState(game);
               setExportedAuthEntryXDR(null);
               setSuccess('Game created! Player 2 has signed and submitted.');
@@ -486,27 +437,20 @@ export function NumberGuessGame({
           throw new Error('Quickstart requires two different dev wallets.');
         }
 
-        const quickstartSessionId = createRandomSessionId();
-        setSessionId(quickstartSessionId);
         setPlayer1Address(player1AddressQuickstart);
+        setPlayer2Address(player2AddressQuickstart);
         setCreateMode('create');
         setExportedAuthEntryXDR(null);
         setImportAuthEntryXDR('');
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
         setImportPlayer2Points(DEFAULT_POINTS);
         setLoadSessionId('');
 
-        const placeholderPlayer2Address = await getFundedSimulationSourceAddress([
-          player1AddressQuickstart,
-          player2AddressQuickstart,
-        ]);
-
         const authEntryXDR = await numberGuessService.prepareStartGame(
-          quickstartSessionId,
           player1AddressQuickstart,
-          placeholderPlayer2Address,
+          player2AddressQuickstart,
           p1Points,
           p1Points,
           player1Signer
@@ -519,11 +463,13 @@ export function NumberGuessGame({
           player2Signer
         );
 
-        await numberGuessService.finalizeStartGame(
+        const startResult = await numberGuessService.finalizeStartGame(
           fullySignedTxXDR,
           player2AddressQuickstart,
           player2Signer
         );
+        const quickstartSessionId = startResult.unwrap();
+        setSessionId(quickstartSessionId);
 
         try {
           const game = await numberGuessService.getGame(quickstartSessionId);
@@ -565,18 +511,18 @@ export function NumberGuessGame({
         }
 
         // Parse auth entry to extract game parameters
-        // The auth entry contains: session_id, player1, player1_points
+        // The auth entry contains: player1, player2, player1_points
         console.log('Parsing auth entry to extract game parameters...');
         const gameParams = numberGuessService.parseAuthEntry(importAuthEntryXDR.trim());
 
         console.log('Extracted from auth entry:', {
-          sessionId: gameParams.sessionId,
           player1: gameParams.player1,
+          player2: gameParams.player2,
           player1Points: gameParams.player1Points.toString(),
         });
 
         // Auto-populate read-only fields from parsed auth entry (for display)
-        setImportSessionId(gameParams.sessionId.toString());
+        setImportPlayer2(gameParams.player2);
         setImportPlayer1(gameParams.player1);
         setImportPlayer1Points((Number(gameParams.player1Points) / 10_000_000).toString());
 
@@ -585,10 +531,9 @@ export function NumberGuessGame({
           throw new Error('Invalid game: You cannot play against yourself (you are Player 1 in this auth entry)');
         }
 
-        // Additional validation: Ensure Player 2 address is different from Player 1
-        // (In case user manually edits the Player 2 field)
-        if (userAddress === gameParams.player1) {
-          throw new Error('Cannot play against yourself. Player 2 must be different from Player 1.');
+        // Player 1 signed for a specific opponent; only that wallet can accept
+        if (userAddress !== gameParams.player2) {
+          throw new Error(`This auth entry invites ${gameParams.player2}. Connect that wallet to accept it.`);
         }
 
         const signer = getContractSigner();
@@ -605,27 +550,28 @@ export function NumberGuessGame({
 
         // Step 2: Player 2 finalizes and submits (they are the transaction source)
         console.log('Simulating and submitting transaction...');
-        await numberGuessService.finalizeStartGame(
+        const startResult = await numberGuessService.finalizeStartGame(
           fullySignedTxXDR,
           userAddress,
           signer
         );
 
         // If we get here, transaction succeeded! Now update state.
-        console.log('Transaction submitted successfully! Updating state...');
-        setSessionId(gameParams.sessionId);
-        setSuccess('Game created successfully! Both players signed.');
+        const newSessionId = startResult.unwrap();
+        console.log('Transaction submitted successfully! Hub session ID:', newSessionId);
+        setSessionId(newSessionId);
+        setSuccess(`Game created successfully! Both players signed. Session ID: ${newSessionId}`);
         setGamePhase('guess');
 
         // Clear import fields
         setImportAuthEntryXDR('');
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
         setImportPlayer2Points(DEFAULT_POINTS);
 
         // Load the newly created game state
-        await loadGameState();
+        setGameState(await numberGuessService.getGame(newSessionId));
 
         // Refresh dashboard to show updated available points (locked in game)
         onStandingsRefresh();
@@ -690,16 +636,15 @@ export function NumberGuessGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
-          // Game is complete - show reveal phase with winner
-          setGamePhase('reveal');
+        if (isSet(game.winner) || game.is_draw) {
+          // Game is complete - show the result
+          setGamePhase('complete');
           const isWinner = game.winner === userAddress;
-          setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_guess !== null && game.player1_guess !== undefined &&
-            game.player2_guess !== null && game.player2_guess !== undefined) {
-          // Both players guessed, waiting for reveal
+          setSuccess(isWinner ? '🎉 You won this game!' : game.is_draw ? 'Game complete. It was a draw.' : 'Game complete. Winner revealed.');
+        } else if (isSet(game.player1_commitment) && isSet(game.player2_commitment)) {
+          // Both players guessed, waiting for reveals
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have guessed. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have guessed. Reveal your guess.');
         } else {
           // Still in guessing phase
           setGamePhase('guess');
@@ -777,10 +722,20 @@ export function NumberGuessGame({
         setError(null);
         setSuccess(null);
 
+        // Seal the guess with a fresh salt, kept in this browser until the reveal
+        const salt = randomSecret();
+        saveSecret(
+          NUMBER_GUESS_CONTRACT,
+          sessionId,
+          userAddress,
+          'guess',
+          JSON.stringify({ guess, salt: salt.toString('hex') })
+        );
+
         const signer = getContractSigner();
-        await numberGuessService.makeGuess(sessionId, userAddress, guess, signer);
+        await numberGuessService.makeGuess(sessionId, userAddress, guess, salt, signer);
 
-        setSuccess(`Guess submitted: ${guess}`);
+        setSuccess(`Guess sealed: ${guess}. You'll reveal it once both players have guessed.`);
         await loadGameState();
       } catch (err) {
         console.error('Make guess error:', err);
@@ -791,43 +746,72 @@ export function NumberGuessGame({
     });
   };
 
-  const waitForWinner = async () => {
-    let updatedGame = await numberGuessService.getGame(sessionId);
-    let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
-      await new Promise((resolve) => setTimeout(resolve, 1000));
-      updatedGame = await numberGuessService.getGame(sessionId);
-      attempts += 1;
+  const finishIfComplete = (updatedGame: Game | null) => {
+    setGameState(updatedGame);
+    if (updatedGame && (isSet(updatedGame.winner) || updatedGame.is_draw)) {
+      clearSecret(NUMBER_GUESS_CONTRACT, sessionId, userAddress, 'guess');
+      setGamePhase('complete');
+
+      const isWinner = updatedGame.winner === userAddress;
+      setSuccess(isWinner ? '🎉 You won!' : updatedGame.is_draw ? 'Game complete! It was a draw.' : 'Game complete! Winner revealed.');
+
+      // Refresh standings immediately (without navigating away)
+      onStandingsRefresh();
     }
-    return updatedGame;
   };
 
-  const handleRevealWinner = async () => {
+  const handleRevealGuess = async () => {
     await runAction(async () => {
       try {
         setLoading(true);
         setError(null);
         setSuccess(null);
 
-        const signer = getContractSigner();
-        await numberGuessService.revealWinner(sessionId, userAddress, signer);
-
-        // Fetch updated on-chain state and derive the winner from it (avoid type mismatches from tx result decoding).
-        const updatedGame = await waitForWinner();
-        setGameState(updatedGame);
-        setGamePhase('complete');
+        const stored = loadSecret(NUMBER_GUESS_CONTRACT, sessionId, userAddress, 'guess');
+        if (!stored) {
+          throw new Error('Your guess was sealed in another browser. Reveal it from there before the reveal window closes.');
+        }
+        const sealed = JSON.parse(stored) as { guess: number; salt: string };
 
-        const isWinner = updatedGame?.winner === userAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        const signer = getContractSigner();
+        await numberGuessService.revealGuess(
+          sessionId,
+          userAddress,
+          sealed.guess,
+          Buffer.from(sealed.salt, 'hex'),
+          signer
+        );
 
-        // Refresh standings immediately (without navigating away)
-        onStandingsRefresh();
+        // The second reveal draws the winning number and ends the game
+        const updatedGame = await numberGuessService.getGame(sessionId);
+        setSuccess(`Guess revealed: ${sealed.guess}`);
+        finishIfComplete(updatedGame);
 
         // DON'T call onGameComplete() immediately - let user see the results
         // User can click "Back to Games" button when ready
       } catch (err) {
-        console.error('Reveal winner error:', err);
-        setError(err instanceof Error ? err.message : 'Failed to reveal winner');
+        console.error('Reveal guess error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal guess');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleClaimForfeit = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await numberGuessService.claimForfeit(sessionId, userAddress, signer);
+
+        finishIfComplete(await numberGuessService.getGame(sessionId));
+      } catch (err) {
+        console.error('Claim forfeit error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to claim forfeit');
       } finally {
         setLoading(false);
       }
@@ -836,8 +820,10 @@ export function NumberGuessGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasGuessed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
-                     isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  const hasGuessed = isPlayer1 ? isSet(gameState?.player1_commitment) :
+                     isPlayer2 ? isSet(gameState?.player2_commitment) : false;
+  const hasRevealed = isPlayer1 ? isSet(gameState?.player1_salt) :
+                      isPlayer2 ? isSet(gameState?.player2_salt) : false;
 
   const winningNumber = gameState?.winning_number;
   const player1Guess = gameState?.player1_guess;
@@ -862,13 +848,13 @@ export function NumberGuessGame({
             Guess a number 1-10. Closest guess wins!
           </p>
           <p className="text-xs text-gray-500 font-mono mt-1">
-            Session ID: {sessionId}
+            Session ID: {sessionId || 'assigned when the game starts'}
           </p>
         </div>
         <button
           onClick={() => {
-            // If game is complete (has winner), refresh stats before going back
-            if (gameState?.winner) {
+            // If game is complete (winner or draw), refresh stats before going back
+            if (gameState?.winner || gameState?.is_draw) {
               onGameComplete();
             }
             onBack();
@@ -901,7 +887,7 @@ export function NumberGuessGame({
                 setCreateMode('create');
                 setExportedAuthEntryXDR(null);
                 setImportAuthEntryXDR('');
-                setImportSessionId('');
+                setImportPlayer2('');
                 setImportPlayer1('');
                 setImportPlayer1Points('');
                 setImportPlayer2Points(DEFAULT_POINTS);
@@ -934,7 +920,7 @@ export function NumberGuessGame({
                 setCreateMode('load');
                 setExportedAuthEntryXDR(null);
                 setImportAuthEntryXDR('');
-                setImportSessionId('');
+                setImportPlayer2('');
                 setImportPlayer1('');
                 setImportPlayer1Points('');
                 setImportPlayer2Points(DEFAULT_POINTS);
@@ -986,6 +972,22 @@ export function NumberGuessGame({
               </p>
             </div>
 
+            <div>
+              <label className="block text-sm font-bold text-gray-700 mb-2">
+                Opponent Address (Player 2)
+              </label>
+              <input
+                type="text"
+                value={player2Address}
+                onChange={(e) => setPlayer2Address(e.target.value.trim())}
+                placeholder="G..."
+                className="w-full px-4 py-3 rounded-xl bg-white border-2 border-gray-200 focus:outline-none focus:border-purple-400 focus:ring-4 focus:ring-purple-100 text-sm font-medium text-gray-700"
+              />
+              <p className="text-xs font-semibold text-gray-600 mt-1">
+                Your signature only lets this address accept the game.
+              </p>
+            </div>
+
             <div>
               <label className="block text-sm font-bold text-gray-700 mb-2">
                 Your Points
@@ -1004,20 +1006,16 @@ export function NumberGuessGame({
 
             <div className="p-3 bg-blue-50 border-2 border-blue-200 rounded-xl">
               <p className="text-xs font-semibold text-blue-800">
-                ℹ️ Player 2 will specify their own address and points when they import your auth entry. You only need to prepare and export your signature.
+                ℹ️ Player 2 will specify their own points when they import your auth entry. The session ID is assigned by the Game Hub once they submit.
               </p>
             </div>
           </div>
 
           <div className="pt-4 border-t-2 border-gray-100 space-y-4">
-            <p className="text-xs font-semibold text-gray-600">
-              Session ID: {sessionId}
-            </p>
-
             {!exportedAuthEntryXDR ? (
               <button
                 onClick={handlePrepareTransaction}
-                disabled={isBusy}
+                disabled={isBusy || !player2Address}
                 className="w-full py-4 rounded-xl font-bold text-white text-sm bg-gradient-to-r from-purple-500 to-pink-500 hover:from-purple-600 hover:to-pink-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-lg hover:shadow-xl transform hover:scale-105 disabled:transform-none"
               >
                 {loading ? 'Preparing...' : 'Prepare & Export Auth Entry'}
@@ -1063,7 +1061,7 @@ export function NumberGuessGame({
                   📥 Import Auth Entry from Player 1
                 </p>
                 <p className="text-xs text-gray-700 mb-4">
-                  Paste the auth entry XDR from Player 1. Session ID, Player 1 address, and their points will be auto-extracted. You only need to enter your points amount.
+                  Paste the auth entry XDR from Player 1. The player addresses and Player 1's points will be auto-extracted. You only need to enter your points amount.
                 </p>
                 <div className="space-y-3">
                   <div>
@@ -1101,10 +1099,10 @@ export function NumberGuessGame({
                   {/* Auto-populated fields from auth entry (read-only) */}
                   <div className="grid grid-cols-2 gap-3">
                     <div>
-                      <label className="block text-xs font-bold text-gray-500 mb-1">Session ID (auto-filled)</label>
+                      <label className="block text-xs font-bold text-gray-500 mb-1">Invited Player 2 (auto-filled)</label>
                       <input
                         type="text"
-                        value={importSessionId}
+                        value={importPlayer2}
                         readOnly
                         placeholder="Auto-filled from auth entry"
                         className="w-full px-4 py-2 rounded-xl bg-gray-50 border-2 border-gray-200 text-xs font-mono text-gray-600 cursor-not-allowed"
@@ -1231,7 +1229,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player1_guess !== null && gameState.player1_guess !== undefined ? (
+                {isSet(gameState.player1_commitment) ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1252,7 +1250,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player2_guess !== null && gameState.player2_guess !== undefined ? (
+                {isSet(gameState.player2_commitment) ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1298,7 +1296,7 @@ export function NumberGuessGame({
           {hasGuessed && (
             <div className="p-4 bg-gradient-to-r from-blue-50 to-cyan-50 border-2 border-blue-200 rounded-xl">
               <p className="text-sm font-semibold text-blue-700">
-                ✓ You've made your guess. Waiting for other player...
+                ✓ You've sealed your guess. Waiting for other player...
               </p>
             </div>
           )}
@@ -1313,16 +1311,35 @@ export function NumberGuessGame({
             <h3 className="text-2xl font-black text-gray-900 mb-3">
               Both Players Have Guessed!
             </h3>
-            <p className="text-sm font-semibold text-gray-700 mb-6">
-              Click below to reveal the winner
+            <p className="text-sm font-semibold text-gray-700 mb-2">
+              Reveal your guess. The winning number is drawn once both guesses are revealed.
             </p>
-            <button
-              onClick={handleRevealWinner}
-              disabled={isBusy}
-              className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
-            >
-              {loading ? 'Revealing...' : 'Reveal Winner'}
-            </button>
+            <p className="text-xs font-semibold text-gray-600 mb-6">
+              Player 1: {isSet(gameState.player1_salt) ? '✓ Revealed' : 'Waiting...'} · Player 2: {isSet(gameState.player2_salt) ? '✓ Revealed' : 'Waiting...'}
+              {isSet(gameState.reveal_deadline) && ` · Reveal window closes at ledger ${gameState.reveal_deadline}`}
+            </p>
+            {(isPlayer1 || isPlayer2) && !hasRevealed ? (
+              <button
+                onClick={handleRevealGuess}
+                disabled={isBusy}
+                className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal Guess'}
+              </button>
+            ) : (
+              <div className="space-y-3">
+                <p className="text-sm font-semibold text-blue-700">
+                  Waiting for the other player to reveal. If they miss the reveal window, claim the win.
+                </p>
+                <button
+                  onClick={handleClaimForfeit}
+                  disabled={isBusy}
+                  className="px-8 py-3 rounded-xl font-bold text-white text-sm bg-gradient-to-r from-gray-500 to-gray-600 hover:from-gray-600 hover:to-gray-700 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-md hover:shadow-lg transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Claiming...' : 'Claim Forfeit'}
+                </button>
+              </div>
+            )}
           </div>
         </div>
       )}
@@ -1336,7 +1353,7 @@ export function NumberGuessGame({
               Game Complete!
             </h3>
             <div className="text-2xl font-black text-green-700 mb-6">
-              Winning Number: {gameState.winning_number}
+              Winning Number: {gameState.winning_number ?? '—'}
             </div>
             <div className="space-y-3 mb-6">
               <div className="p-4 bg-white/70 border border-green-200 rounded-xl">
@@ -1361,6 +1378,11 @@ export function NumberGuessGame({
                 </p>
               </div>
             </div>
+            {gameState.is_draw && (
+              <div className="mt-6 p-5 bg-white border-2 border-green-200 rounded-xl shadow-lg">
+                <p className="text-lg font-black text-gray-800">🤝 It's a draw!</p>
+              </div>
+            )}
             {gameState.winner && (
               <div className="mt-6 p-5 bg-white border-2 border-green-200 rounded-xl shadow-lg">
                 <p className="text-xs font-bold uppercase tracking-wide text-gray-600 mb-2">Winner</p>
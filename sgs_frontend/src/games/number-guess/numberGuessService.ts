@@ -5,6 +5,7 @@ import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
+import { keccak256, u32Bytes, addressXdr } from '@/utils/commitUtils';
 
 type ClientOptions = contract.ClientOptions;
 
@@ -69,9 +70,9 @@ export class NumberGuessService {
   /**
    * Start a new game (requires multi-sig authorization)
    * Note: This requires both players to sign the transaction
+   * Returns the session ID allocated by the Game Hub
    */
   async startGame(
-    sessionId: number,
     player1: string,
     player2: string,
     player1Points: bigint,
@@ -81,7 +82,6 @@ export class NumberGuessService {
   ) {
     const client = this.createSigningClient(player1, signer);
     const tx = await client.start_game({
-      session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
@@ -109,10 +109,11 @@ export class NumberGuessService {
    *
    * Uses extended TTL (60 minutes) for multi-sig flow to allow time for both players to sign
    *
-   * Player 2 will later import this auth entry and rebuild the transaction
+   * Player 1's auth entry binds Player 2's address, so the opponent must be known up front.
+   * Player 2 will later import this auth entry and rebuild the transaction.
+   * The session ID is allocated by the Game Hub when the transaction is submitted.
    */
   async prepareStartGame(
-    sessionId: number,
     player1: string,
     player2: string,
     player1Points: bigint,
@@ -129,7 +130,6 @@ export class NumberGuessService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
@@ -233,12 +233,12 @@ export class NumberGuessService {
    *
    * Auth entries from require_auth_for_args only contain the args that player is authorizing:
    * - Player address (from credentials)
-   * - Session ID (arg 0)
+   * - Opponent's address (arg 0)
    * - Player's points (arg 1)
    */
   parseAuthEntry(authEntryXdr: string): {
-    sessionId: number;
     player1: string;
+    player2: string;
     player1Points: bigint;
     functionName: string;
   } {
@@ -279,7 +279,7 @@ export class NumberGuessService {
 
       // Extract arguments from the invocation
       // For start_game with require_auth_for_args, we have:
-      // 0: session_id (u32)
+      // 0: opponent (Address)
       // 1: player_points (i128)
       const args = contractFn.args();
       console.log('[parseAuthEntry] Number of args:', args.length);
@@ -288,18 +288,18 @@ export class NumberGuessService {
         throw new Error(`Expected 2 arguments for start_game auth entry, got ${args.length}`);
       }
 
-      const sessionId = args[0].u32();
+      const player2 = Address.fromScVal(args[0]).toString();
       const player1Points = args[1].i128().lo().toBigInt();
 
       console.log('[parseAuthEntry] Extracted:', {
-        sessionId,
         player1,
+        player2,
         player1Points: player1Points.toString(),
       });
 
       return {
-        sessionId,
         player1,
+        player2,
         player1Points,
         functionName,
       };
@@ -312,7 +312,7 @@ export class NumberGuessService {
   /**
    * STEP 2 (Player 2): Import Player 1's signed auth entry and rebuild transaction
    * - Parses Player 1's signed auth entry to extract game parameters
-   * - Validates that the current user is Player 2
+   * - Validates that the current user is the Player 2 the auth entry was signed for
    * - Rebuilds the transaction with Player 2 as source
    * - Injects Player 1's signed auth entry (replacing the stub)
    * - Signs Player 2's auth entry if needed
@@ -339,8 +339,8 @@ export class NumberGuessService {
     const gameParams = this.parseAuthEntry(player1SignedAuthEntryXdr);
 
     console.log('[importAndSignAuthEntry] Parsed game parameters:', {
-      sessionId: gameParams.sessionId,
       player1: gameParams.player1,
+      player2: gameParams.player2,
       player1Points: gameParams.player1Points.toString(),
     });
 
@@ -354,6 +354,11 @@ export class NumberGuessService {
       throw new Error('Cannot play against yourself. Player 2 must be different from Player 1.');
     }
 
+    // Player 1 signed for a specific opponent; nobody else can complete the start
+    if (player2Address !== gameParams.player2) {
+      throw new Error(`This invite is for ${gameParams.player2}. Connect that wallet to accept it.`);
+    }
+
     // Step 1: Build a new transaction with Player 2 as the source
     // Use parsed parameters from auth entry + provided Player 2 params
     const buildClient = new NumberGuessClient({
@@ -364,9 +369,8 @@ export class NumberGuessService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: gameParams.sessionId,
       player1: gameParams.player1,        // From auth entry
-      player2: player2Address,             // Provided by Player 2
+      player2: player2Address,             // Validated against auth entry
       player1_points: gameParams.player1Points, // From auth entry
       player2_points: player2Points,         // Provided by Player 2
     }, DEFAULT_METHOD_OPTIONS);
@@ -443,6 +447,7 @@ export class NumberGuessService {
    * - Signs transaction envelope and submits to network
    *
    * Can be called by either player, but typically Player 2 (the transaction source)
+   * Returns the session ID allocated by the Game Hub
    */
   async finalizeStartGame(
     xdr: string,
@@ -494,11 +499,10 @@ export class NumberGuessService {
 
   /**
    * Parse transaction XDR to extract game details
-   * Returns session ID, player addresses, points, and transaction source
+   * Returns player addresses, points, and transaction source
    * Uses proper SDK methods to extract contract invocation parameters
    */
   parseTransactionXDR(xdr: string): {
-    sessionId: number;
     player1: string;
     player2: string;
     player1Points: bigint;
@@ -530,42 +534,37 @@ export class NumberGuessService {
     const args = invokeContractArgs.args();
 
     // For start_game, the arguments are:
-    // 0: session_id (u32)
-    // 1: player1 (Address)
-    // 2: player2 (Address)
-    // 3: player1_points (i128)
-    // 4: player2_points (i128)
+    // 0: player1 (Address)
+    // 1: player2 (Address)
+    // 2: player1_points (i128)
+    // 3: player2_points (i128)
 
     if (functionName !== 'start_game') {
       throw new Error(`Unexpected function: ${functionName}. Expected start_game.`);
     }
 
-    if (args.length !== 5) {
-      throw new Error(`Expected 5 arguments for start_game, got ${args.length}`);
+    if (args.length !== 4) {
+      throw new Error(`Expected 4 arguments for start_game, got ${args.length}`);
     }
 
-    // Extract session_id (u32)
-    const sessionId = args[0].u32();
-
     // Extract player1 (Address)
-    const player1ScVal = args[1];
+    const player1ScVal = args[0];
     const player1Address = player1ScVal.address().accountId().ed25519();
     const player1 = StrKey.encodeEd25519PublicKey(player1Address);
 
     // Extract player2 (Address)
-    const player2ScVal = args[2];
+    const player2ScVal = args[1];
     const player2Address = player2ScVal.address().accountId().ed25519();
     const player2 = StrKey.encodeEd25519PublicKey(player2Address);
 
     // Extract points (i128)
-    const player1PointsScVal = args[3];
+    const player1PointsScVal = args[2];
     const player1Points = player1PointsScVal.i128().lo().toBigInt();
 
-    const player2PointsScVal = args[4];
+    const player2PointsScVal = args[3];
     const player2Points = player2PointsScVal.i128().lo().toBigInt();
 
     return {
-      sessionId,
       player1,
       player2,
       player1Points,
@@ -576,12 +575,22 @@ export class NumberGuessService {
   }
 
   /**
-   * Make a guess (1-10)
+   * Seal a guess for `make_guess`
+   * Matches the contract's `keccak256(session_id || player || guess || salt)`
+   */
+  sealGuess(sessionId: number, playerAddress: string, guess: number, salt: Buffer): Buffer {
+    return keccak256(u32Bytes(sessionId), addressXdr(playerAddress), u32Bytes(guess), salt);
+  }
+
+  /**
+   * Make a sealed guess (1-10)
+   * The salt must be kept until `revealGuess`; without it the guess cannot be revealed
    */
   async makeGuess(
     sessionId: number,
     playerAddress: string,
     guess: number,
+    salt: Buffer,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
@@ -593,45 +602,74 @@ export class NumberGuessService {
     const tx = await client.make_guess({
       session_id: sessionId,
       player: playerAddress,
-      guess,
+      commitment: this.sealGuess(sessionId, playerAddress, guess, salt),
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
-    const validUntilLedgerSeq = authTtlMinutes
-      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
-      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
-
-    try {
-      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+    return this.submit(
+      tx,
+      authTtlMinutes,
+      'Transaction failed - check if the game is still active and you haven\'t already guessed'
+    );
+  }
 
-      if (sentTx.getTransactionResponse?.status === 'FAILED') {
-        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
-        throw new Error(`Transaction failed: ${errorMessage}`);
-      }
+  /**
+   * Reveal a sealed guess after both players have guessed
+   * The second reveal draws the winning number and ends the game
+   */
+  async revealGuess(
+    sessionId: number,
+    playerAddress: string,
+    guess: number,
+    salt: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_guess({
+      session_id: sessionId,
+      player: playerAddress,
+      guess,
+      salt,
+    }, DEFAULT_METHOD_OPTIONS);
+    // NOTE: Contract methods automatically simulate - footprint already includes all required storage keys
+    // (the final reveal calls the Game Hub end_game() hook)
 
-      return sentTx.result;
-    } catch (err) {
-      if (err instanceof Error && err.message.includes('Transaction failed!')) {
-        throw new Error('Transaction failed - check if the game is still active and you haven\'t already guessed');
-      }
-      throw err;
-    }
+    return this.submit(
+      tx,
+      authTtlMinutes,
+      'Transaction failed - check if both players have guessed and the reveal window is still open'
+    );
   }
 
   /**
-   * Reveal the winner after both players have guessed
+   * End a game whose reveal window closed without both reveals
+   * A player who revealed wins against one who did not; anyone can call this
    */
-  async revealWinner(
+  async claimForfeit(
     sessionId: number,
     callerAddress: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
     const client = this.createSigningClient(callerAddress, signer);
-    const tx = await client.reveal_winner({ session_id: sessionId }, DEFAULT_METHOD_OPTIONS);
-    // NOTE: Contract methods automatically simulate - footprint already includes all required storage keys
-    // (reveal_winner calls the Game Hub end_game() hook)
+    const tx = await client.claim_forfeit({ session_id: sessionId }, DEFAULT_METHOD_OPTIONS);
 
+    return this.submit(
+      tx,
+      authTtlMinutes,
+      'Transaction failed - check that the reveal window has closed and the game is still active'
+    );
+  }
+
+  /**
+   * Sign and submit a move, surfacing contract errors
+   */
+  private async submit<T>(
+    tx: contract.AssembledTransaction<T>,
+    authTtlMinutes: number | undefined,
+    failureHint: string
+  ) {
     const validUntilLedgerSeq = authTtlMinutes
       ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
       : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
@@ -655,7 +693,7 @@ export class NumberGuessService {
 
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
         // This is the SDK error when trying to access .result on a failed transaction
-        throw new Error('Transaction failed - check if both players have guessed and the game is still active');
+        throw new Error(failureHint);
       }
 
       throw err;
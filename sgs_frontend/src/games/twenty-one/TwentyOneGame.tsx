@@ -3,22 +3,18 @@ import { twentyOneService } from './twentyOneService';
 import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { getLocationSearch } from '@/utils/location';
 import { useWallet } from '@/hooks/useWallet';
-import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
+import { TWENTY_ONE_CONTRACT } from '@/utils/constants';
+import { randomSecret, saveSecret, loadSecret, clearSecret } from '@/utils/commitUtils';
+import { getLatestLedgerSequence, findStartedSession, getAuthEntrySignedLedger } from '@/utils/sessionUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
+import { StrKey } from '@stellar/stellar-sdk';
+import { Buffer } from 'buffer';
+import type { Game } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
+const isSet = (value: unknown): boolean => value !== null && value !== undefined;
 
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
+// Jack, Queen and King are worth 10 (matches the contract's `card_value`)
+const cardValue = (card: number): number => (card >= 10 ? 10 : card);
 
 interface TwentyOneGameProps {
   userAddress: string;
@@ -31,19 +27,6 @@ interface TwentyOneGameProps {
   onGameComplete: () => void;
 }
 
-interface GameState {
-  player1: string;
-  player2: string;
-  player1_points: bigint;
-  player2_points: bigint;
-  player1_hand: Uint8Array;
-  player2_hand: Uint8Array;
-  player1_stuck: boolean;
-  player2_stuck: boolean;
-  winner: string | null;
-  round: number;
-}
-
 // Card component with suit and value
 const PlayingCard = ({ value, isHidden = false }: { value: number; isHidden?: boolean }) => {
   const getCardDisplay = (val: number) => {
@@ -109,19 +92,21 @@ export function TwentyOneGame({
   const { getContractSigner, walletType } = useWallet();
   const normalizedUserAddress = userAddress.trim().toUpperCase();
   const normalizeAddress = (address: string) => address.trim().toUpperCase();
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Allocated by the Game Hub when the game starts; 0 until then
+  const [sessionId, setSessionId] = useState<number>(0);
   const [player1Address, setPlayer1Address] = useState(userAddress);
+  const [player2Address, setPlayer2Address] = useState('');
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
-  const [gameState, setGameState] = useState<GameState | null>(null);
+  const [gameState, setGameState] = useState<Game | null>(null);
   const [loading, setLoading] = useState(false);
   const [quickstartLoading, setQuickstartLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [success, setSuccess] = useState<string | null>(null);
-  const [gamePhase, setGamePhase] = useState<'create' | 'play' | 'reveal' | 'complete'>('create');
+  const [gamePhase, setGamePhase] = useState<'create' | 'deal' | 'play' | 'reveal' | 'complete'>('create');
   const [createMode, setCreateMode] = useState<'create' | 'import' | 'load'>('create');
   const [exportedAuthEntryXDR, setExportedAuthEntryXDR] = useState<string | null>(null);
   const [importAuthEntryXDR, setImportAuthEntryXDR] = useState('');
-  const [importSessionId, setImportSessionId] = useState('');
+  const [importPlayer2, setImportPlayer2] = useState('');
   const [importPlayer1, setImportPlayer1] = useState('');
   const [importPlayer1Points, setImportPlayer1Points] = useState('');
   const [importPlayer2Points, setImportPlayer2Points] = useState(DEFAULT_POINTS);
@@ -177,6 +162,20 @@ export function TwentyOneGame({
     }
   };
 
+  // The deal comes first, then hits and sticks, then the hole cards are revealed
+  const phaseFor = (game: Game): 'deal' | 'play' | 'reveal' | 'complete' => {
+    if (isSet(game.winner) || game.is_draw) {
+      return 'complete';
+    }
+    if (!isSet(game.deal_seed)) {
+      return 'deal';
+    }
+    if (game.player1_stuck && game.player2_stuck) {
+      return 'reveal';
+    }
+    return 'play';
+  };
+
   const loadGameState = async () => {
     try {
       // Always fetch latest game state to avoid stale cached results after transactions.
@@ -195,14 +194,7 @@ export function TwentyOneGame({
           console.log('Error loading hand values:', err);
         }
 
-        // Determine game phase
-        if (game.winner) {
-          setGamePhase('complete');
-        } else if (game.player1_stuck && game.player2_stuck) {
-          setGamePhase('reveal');
-        } else {
-          setGamePhase('play');
-        }
+        setGamePhase(phaseFor(game));
       }
     } catch (err) {
       setGameState(null);
@@ -218,44 +210,51 @@ export function TwentyOneGame({
   }, [sessionId, gamePhase]);
 
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && (gameState?.winner || gameState?.is_draw)) {
       console.log('Game completed! Refreshing standings...');
       onStandingsRefresh();
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.winner, gameState?.is_draw]);
+
+  // Open a shared auth entry: load the game if Player 2 already started it, otherwise import it
+  const openAuthEntry = (authEntry: string) => {
+    try {
+      const parsed = twentyOneService.parseAuthEntry(authEntry);
+      const enterImportMode = () => {
+        setCreateMode('import');
+        setImportAuthEntryXDR(authEntry);
+        setImportPlayer2(parsed.player2);
+        setImportPlayer1(parsed.player1);
+        setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
+        setImportPlayer2Points('0.1');
+      };
+
+      // The auth entry carries no session ID; the hub's game_started event has it
+      findStartedSession(TWENTY_ONE_CONTRACT, parsed.player1, parsed.player2, getAuthEntrySignedLedger(authEntry))
+        .then(async (startedSessionId) => {
+          const game = startedSessionId ? await twentyOneService.getGame(startedSessionId) : null;
+          if (startedSessionId && game) {
+            setSessionId(startedSessionId);
+            setGameState(game);
+            setGamePhase(phaseFor(game));
+          } else {
+            enterImportMode();
+          }
+        })
+        .catch(() => {
+          enterImportMode();
+        });
+    } catch (err) {
+      setCreateMode('import');
+      setImportAuthEntryXDR(authEntry);
+      setImportPlayer2Points('0.1');
+    }
+  };
 
   // Handle initial values (similar to NumberGuess)
   useEffect(() => {
     if (initialXDR) {
-      try {
-        const parsed = twentyOneService.parseAuthEntry(initialXDR);
-        const sessionId = parsed.sessionId;
-
-        twentyOneService.getGame(sessionId)
-          .then((game) => {
-            if (game) {
-              setGameState(game);
-              setGamePhase('play');
-              setSessionId(sessionId);
-            } else {
-              setCreateMode('import');
-              setImportAuthEntryXDR(initialXDR);
-              setImportSessionId(sessionId.toString());
-              setImportPlayer1(parsed.player1);
-              setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-              setImportPlayer2Points('0.1');
-            }
-          })
-          .catch(() => {
-            setCreateMode('import');
-            setImportAuthEntryXDR(initialXDR);
-            setImportPlayer2Points('0.1');
-          });
-      } catch (err) {
-        setCreateMode('import');
-        setImportAuthEntryXDR(initialXDR);
-        setImportPlayer2Points('0.1');
-      }
+      openAuthEntry(initialXDR);
       return;
     }
 
@@ -264,28 +263,7 @@ export function TwentyOneGame({
     const urlSessionId = urlParams.get('session-id');
 
     if (authEntry) {
-      try {
-        const parsed = twentyOneService.parseAuthEntry(authEntry);
-        twentyOneService.getGame(parsed.sessionId)
-          .then((game) => {
-            if (game) {
-              setGameState(game);
-              setGamePhase('play');
-              setSessionId(parsed.sessionId);
-            } else {
-              setCreateMode('import');
-              setImportAuthEntryXDR(authEntry);
-              setImportSessionId(parsed.sessionId.toString());
-              setImportPlayer1(parsed.player1);
-              setImportPlayer1Points((Number(parsed.player1Points) / 10_000_000).toString());
-              setImportPlayer2Points('0.1');
-            }
-          });
-      } catch (err) {
-        setCreateMode('import');
-        setImportAuthEntryXDR(authEntry);
-        setImportPlayer2Points('0.1');
-      }
+      openAuthEntry(authEntry);
     } else if (urlSessionId) {
       setCreateMode('load');
       setLoadSessionId(urlSessionId);
@@ -302,7 +280,7 @@ export function TwentyOneGame({
         setXdrParsing(false);
         setXdrParseError(null);
         setXdrParseSuccess(false);
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
       }
@@ -321,14 +299,14 @@ export function TwentyOneGame({
           throw new Error('You cannot play against yourself. This auth entry was created by you (Player 1).');
         }
 
-        setImportSessionId(gameParams.sessionId.toString());
+        setImportPlayer2(gameParams.player2);
         setImportPlayer1(gameParams.player1);
         setImportPlayer1Points((Number(gameParams.player1Points) / 10_000_000).toString());
         setXdrParseSuccess(true);
       } catch (err) {
         const errorMsg = err instanceof Error ? err.message : 'Invalid auth entry XDR';
         setXdrParseError(errorMsg);
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
       } finally {
@@ -351,14 +329,23 @@ export function TwentyOneGame({
           throw new Error('Enter a valid points amount');
         }
 
+        // Player 1's auth entry binds the opponent's address
+        if (!StrKey.isValidEd25519PublicKey(player2Address)) {
+          throw new Error('Enter a valid opponent address (Player 2)');
+        }
+        if (player2Address === player1Address) {
+          throw new Error('Cannot play against yourself. Player 2 must be different from Player 1.');
+        }
+
         const signer = getContractSigner();
-        const placeholderPlayer2Address = await getFundedSimulationSourceAddress([player1Address, userAddress]);
         const placeholderP2Points = p1Points;
 
+        // The hub allocates the session ID, so look for the game_started event from here on
+        const startLedger = await getLatestLedgerSequence();
+
         const authEntryXDR = await twentyOneService.prepareStartGame(
-          sessionId,
           player1Address,
-          placeholderPlayer2Address,
+          player2Address,
           p1Points,
           placeholderP2Points,
           signer
@@ -369,13 +356,20 @@ export function TwentyOneGame({
 
         const pollInterval = setInterval(async () => {
           try {
-            const game = await twentyOneService.getGame(sessionId);
-            if (game) {
+            const startedSessionId = await findStartedSession(
+              TWENTY_ONE_CONTRACT,
+              player1Address,
+              player2Address,
+              startLedger
+            );
+            const game = startedSessionId ? await twentyOneService.getGame(startedSessionId) : null;
+            if (startedSessionId && game) {
               clearInterval(pollInterval);
+              setSessionId(startedSessionId);
               setGameState(game);
               setExportedAuthEntryXDR(null);
               setSuccess('Game created! Player 2 has signed and submitted.');
-              setGamePhase('play');
+              setGamePhase('deal');
               onStandingsRefresh();
               setTimeout(() => setSuccess(null), 2000);
             }
@@ -441,27 +435,20 @@ export function TwentyOneGame({
           throw new Error('Quickstart requires two different dev wallets.');
         }
 
-        const quickstartSessionId = createRandomSessionId();
-        setSessionId(quickstartSessionId);
         setPlayer1Address(player1AddressQuickstart);
+        setPlayer2Address(player2AddressQuickstart);
         setCreateMode('create');
         setExportedAuthEntryXDR(null);
         setImportAuthEntryXDR('');
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
         setImportPlayer2Points(DEFAULT_POINTS);
         setLoadSessionId('');
 
-        const placeholderPlayer2Address = await getFundedSimulationSourceAddress([
-          player1AddressQuickstart,
-          player2AddressQuickstart,
-        ]);
-
         const authEntryXDR = await twentyOneService.prepareStartGame(
-          quickstartSessionId,
           player1AddressQuickstart,
-          placeholderPlayer2Address,
+          player2AddressQuickstart,
           p1Points,
           p1Points,
           player1Signer
@@ -474,11 +461,13 @@ export function TwentyOneGame({
           player2Signer
         );
 
-        await twentyOneService.finalizeStartGame(
+        const startResult = await twentyOneService.finalizeStartGame(
           fullySignedTxXDR,
           player2AddressQuickstart,
           player2Signer
         );
+        const quickstartSessionId = startResult.unwrap();
+        setSessionId(quickstartSessionId);
 
         try {
           const game = await twentyOneService.getGame(quickstartSessionId);
@@ -486,7 +475,7 @@ export function TwentyOneGame({
         } catch (err) {
           console.log('Quickstart game not available yet:', err);
         }
-        setGamePhase('play');
+        setGamePhase('deal');
         onStandingsRefresh();
         setSuccess('Quickstart complete! Both players signed and the game is ready.');
         setTimeout(() => setSuccess(null), 2000);
@@ -520,7 +509,7 @@ export function TwentyOneGame({
 
         const gameParams = twentyOneService.parseAuthEntry(importAuthEntryXDR.trim());
 
-        setImportSessionId(gameParams.sessionId.toString());
+        setImportPlayer2(gameParams.player2);
         setImportPlayer1(gameParams.player1);
         setImportPlayer1Points((Number(gameParams.player1Points) / 10_000_000).toString());
 
@@ -528,6 +517,11 @@ export function TwentyOneGame({
           throw new Error('Invalid game: You cannot play against yourself');
         }
 
+        // Player 1 signed for a specific opponent; only that wallet can accept
+        if (normalizeAddress(gameParams.player2) !== normalizedUserAddress) {
+          throw new Error(`This auth entry invites ${gameParams.player2}. Connect that wallet to accept it.`);
+        }
+
         const signer = getContractSigner();
 
         const fullySignedTxXDR = await twentyOneService.importAndSignAuthEntry(
@@ -537,23 +531,24 @@ export function TwentyOneGame({
           signer
         );
 
-        await twentyOneService.finalizeStartGame(
+        const startResult = await twentyOneService.finalizeStartGame(
           fullySignedTxXDR,
           userAddress,
           signer
         );
 
-        setSessionId(gameParams.sessionId);
-        setSuccess('Game created successfully! Both players signed.');
-        setGamePhase('play');
+        const newSessionId = startResult.unwrap();
+        setSessionId(newSessionId);
+        setSuccess(`Game created successfully! Both players signed. Session ID: ${newSessionId}`);
+        setGamePhase('deal');
 
         setImportAuthEntryXDR('');
-        setImportSessionId('');
+        setImportPlayer2('');
         setImportPlayer1('');
         setImportPlayer1Points('');
         setImportPlayer2Points(DEFAULT_POINTS);
 
-        await loadGameState();
+        setGameState(await twentyOneService.getGame(newSessionId));
         onStandingsRefresh();
         setTimeout(() => setSuccess(null), 2000);
       } catch (err) {
@@ -594,15 +589,14 @@ export function TwentyOneGame({
         setGameState(game);
         setLoadSessionId('');
 
-        if (game.winner) {
-          setGamePhase('complete');
-          const isWinner = normalizeAddress(game.winner) === normalizedUserAddress;
-          setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_stuck && game.player2_stuck) {
-          setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have stuck. You can reveal the winner.');
+        const phase = phaseFor(game);
+        setGamePhase(phase);
+        if (phase === 'complete') {
+          const isWinner = !!game.winner && normalizeAddress(game.winner) === normalizedUserAddress;
+          setSuccess(isWinner ? '🎉 You won this game!' : game.is_draw ? 'Game complete. It was a draw.' : 'Game complete. Winner revealed.');
+        } else if (phase === 'reveal') {
+          setSuccess('Game loaded! Both players have stuck. Reveal your hole card.');
         } else {
-          setGamePhase('play');
           setSuccess('Game loaded! Continue playing.');
         }
 
@@ -646,26 +640,79 @@ export function TwentyOneGame({
     }
   };
 
-  const handleHit = async () => {
+  // Secrets are keyed by beacon request, which is fresh for every deal and every hit
+  const roundSecretName = (game: Game) => `secret:${game.beacon_request}`;
+  const hitSecretName = (game: Game) => `hit:${game.hit_request}`;
+
+  const handleCommitSecret = async () => {
     await runAction(async () => {
       try {
         setLoading(true);
         setError(null);
         setSuccess(null);
+        if (!gameState) {
+          throw new Error('Game not loaded');
+        }
+
+        // Commit to a fresh secret, kept in this browser until the hole cards are revealed
+        const secret = randomSecret();
+        saveSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, roundSecretName(gameState), secret.toString('hex'));
 
         const signer = getContractSigner();
-        await twentyOneService.hit(sessionId, userAddress, signer);
+        await twentyOneService.commitSecret(sessionId, userAddress, secret, signer);
 
-        setSuccess('Card drawn!');
+        setSuccess('Secret committed! Waiting for the other player...');
         await loadGameState();
+      } catch (err) {
+        console.error('Commit secret error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to commit secret');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleRevealDeal = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+        if (!gameState) {
+          throw new Error('Game not loaded');
+        }
 
-        // Check if player busted
-        if (gameState && gameState.winner) {
-          setTimeout(() => {
-            setSuccess(null);
-            setGamePhase('complete');
-          }, 1000);
+        const secret = loadSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, roundSecretName(gameState));
+        if (!secret) {
+          throw new Error('Your secret was committed in another browser. Reveal it from there before the reveal window closes.');
         }
+
+        const signer = getContractSigner();
+        await twentyOneService.revealDeal(sessionId, userAddress, Buffer.from(secret, 'hex'), signer);
+
+        setSuccess('Deal secret revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal deal error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal deal secret');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleHit = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await twentyOneService.hit(sessionId, userAddress, signer);
+
+        setSuccess('Hit requested! Both players now commit and reveal a hit secret to deal the card.');
+        await loadGameState();
       } catch (err) {
         console.error('Hit error:', err);
         setError(err instanceof Error ? err.message : 'Failed to draw card');
@@ -675,6 +722,65 @@ export function TwentyOneGame({
     });
   };
 
+  const handleCommitHit = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+        if (!gameState) {
+          throw new Error('Game not loaded');
+        }
+
+        const hitSecret = randomSecret();
+        saveSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, hitSecretName(gameState), hitSecret.toString('hex'));
+
+        const signer = getContractSigner();
+        await twentyOneService.commitHit(sessionId, userAddress, hitSecret, signer);
+
+        setSuccess('Hit secret committed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Commit hit error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to commit hit secret');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleRevealHit = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+        if (!gameState) {
+          throw new Error('Game not loaded');
+        }
+
+        const name = hitSecretName(gameState);
+        const hitSecret = loadSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, name);
+        if (!hitSecret) {
+          throw new Error('Your hit secret was committed in another browser. Reveal it from there before the reveal window closes.');
+        }
+
+        const signer = getContractSigner();
+        await twentyOneService.revealHit(sessionId, userAddress, Buffer.from(hitSecret, 'hex'), signer);
+        clearSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, name);
+
+        // The second reveal deals the card, which may bust the player who hit
+        setSuccess('Hit secret revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal hit error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal hit secret');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const handleStick = async () => {
     await runAction(async () => {
       try {
@@ -696,38 +802,62 @@ export function TwentyOneGame({
     });
   };
 
-  const waitForWinner = async () => {
-    let updatedGame = await twentyOneService.getGame(sessionId);
-    let attempts = 0;
-    while (attempts < 5 && (!updatedGame || !updatedGame.winner)) {
-      await new Promise((resolve) => setTimeout(resolve, 1000));
-      updatedGame = await twentyOneService.getGame(sessionId);
-      attempts += 1;
-    }
-    return updatedGame;
-  };
-
-  const handleRevealWinner = async () => {
+  const handleRevealHand = async () => {
     await runAction(async () => {
       try {
         setLoading(true);
         setError(null);
         setSuccess(null);
+        if (!gameState) {
+          throw new Error('Game not loaded');
+        }
+
+        const name = roundSecretName(gameState);
+        const secret = loadSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, name);
+        if (!secret) {
+          throw new Error('Your secret was committed in another browser. Reveal it from there before the reveal window closes.');
+        }
 
         const signer = getContractSigner();
-        const winnerResult = await twentyOneService.revealWinner(sessionId, userAddress, signer);
+        await twentyOneService.revealHand(sessionId, userAddress, Buffer.from(secret, 'hex'), signer);
+        clearSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, name);
 
-        await waitForWinner();
+        // The second reveal settles the round; a drawn round is dealt again
+        const updatedGame = await twentyOneService.getGame(sessionId);
         await loadGameState();
+        if (updatedGame && isSet(updatedGame.winner)) {
+          const isWinner = normalizeAddress(updatedGame.winner!) === normalizedUserAddress;
+          setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+          onStandingsRefresh();
+        } else if (updatedGame && updatedGame.round > gameState.round) {
+          setSuccess('The round was a draw! Commit a new secret for the next deal.');
+        } else {
+          setSuccess('Hole card revealed! Waiting for the other player...');
+        }
+      } catch (err) {
+        console.error('Reveal hand error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal hand');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleClaimForfeit = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
 
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
-        const isWinner = normalizeAddress(winner) === normalizedUserAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        const signer = getContractSigner();
+        await twentyOneService.claimForfeit(sessionId, userAddress, signer);
 
+        await loadGameState();
         onStandingsRefresh();
       } catch (err) {
-        console.error('Reveal winner error:', err);
-        setError(err instanceof Error ? err.message : 'Failed to reveal winner');
+        console.error('Claim forfeit error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to claim forfeit');
       } finally {
         setLoading(false);
       }
@@ -736,7 +866,30 @@ export function TwentyOneGame({
 
   const isPlayer1 = !!gameState && normalizeAddress(gameState.player1) === normalizedUserAddress;
   const isPlayer2 = !!gameState && normalizeAddress(gameState.player2) === normalizedUserAddress;
-  const canAct = (isPlayer1 && !gameState?.player1_stuck) || (isPlayer2 && !gameState?.player2_stuck);
+  const hitPending = isSet(gameState?.hitter);
+  const canAct = !hitPending && ((isPlayer1 && !gameState?.player1_stuck) || (isPlayer2 && !gameState?.player2_stuck));
+  const hasCommitted = isPlayer1 ? isSet(gameState?.player1_commitment) : isPlayer2 ? isSet(gameState?.player2_commitment) : false;
+  const hasRevealedDeal = isPlayer1 ? isSet(gameState?.player1_deal_secret) : isPlayer2 ? isSet(gameState?.player2_deal_secret) : false;
+  const hasCommittedHit = isPlayer1 ? isSet(gameState?.player1_hit_commitment) : isPlayer2 ? isSet(gameState?.player2_hit_commitment) : false;
+  const hasRevealedHit = isPlayer1 ? isSet(gameState?.player1_hit_secret) : isPlayer2 ? isSet(gameState?.player2_hit_secret) : false;
+  const hasRevealedHand = isPlayer1 ? isSet(gameState?.player1_secret) : isPlayer2 ? isSet(gameState?.player2_secret) : false;
+
+  // Only this player can work out their hole card until it is revealed
+  const mySecret = gameState && (isPlayer1 || isPlayer2)
+    ? loadSecret(TWENTY_ONE_CONTRACT, sessionId, userAddress, roundSecretName(gameState))
+    : null;
+  const myHoleCard = isPlayer1 || isPlayer2
+    ? (isPlayer1 ? gameState?.player1_hole_card : gameState?.player2_hole_card)
+      ?? (gameState?.deal_seed && mySecret
+        ? twentyOneService.holeCard(gameState.deal_seed, Buffer.from(mySecret, 'hex'), isPlayer1 ? 1 : 2)
+        : null)
+    : null;
+  const opponentHoleCard = isPlayer1 ? gameState?.player2_hole_card : gameState?.player1_hole_card;
+  const myRevealedHole = isPlayer1 ? isSet(gameState?.player1_hole_card) : isSet(gameState?.player2_hole_card);
+  const myHandValue = isPlayer1 ? player1HandValue : player2HandValue;
+  const myHandTotal = myHandValue !== null && isSet(myHoleCard) && !myRevealedHole
+    ? myHandValue + cardValue(myHoleCard!)
+    : myHandValue;
 
   return (
     <div className="min-h-screen bg-gradient-to-br from-green-800 via-green-700 to-green-900 p-8">
@@ -767,12 +920,12 @@ export function TwentyOneGame({
               Get as close to 21 as you can without going over!
             </p>
             <p className="text-sm text-green-300 font-mono mt-1">
-              Session ID: {sessionId}
+              Session ID: {sessionId || 'assigned when the game starts'}
             </p>
           </div>
           <button
             onClick={() => {
-              if (gameState?.winner) {
+              if (gameState?.winner || gameState?.is_draw) {
                 onGameComplete();
               }
               onBack();
@@ -883,6 +1036,22 @@ export function TwentyOneGame({
                     </p>
                   </div>
 
+                  <div>
+                    <label className="block text-sm font-bold text-gray-700 mb-2">
+                      Opponent Address (Player 2)
+                    </label>
+                    <input
+                      type="text"
+                      value={player2Address}
+                      onChange={(e) => setPlayer2Address(e.target.value.trim())}
+                      placeholder="G..."
+                      className="w-full px-4 py-3 rounded-xl bg-white border-2 border-gray-200 focus:outline-none focus:border-purple-400 focus:ring-4 focus:ring-purple-100 text-sm font-medium text-gray-700"
+                    />
+                    <p className="text-xs font-semibold text-gray-600 mt-1">
+                      Your signature only lets this address accept the game.
+                    </p>
+                  </div>
+
                   <div>
                     <label className="block text-sm font-bold text-gray-700 mb-2">
                       Your Points
@@ -903,7 +1072,7 @@ export function TwentyOneGame({
                 {!exportedAuthEntryXDR ? (
                   <button
                     onClick={handlePrepareTransaction}
-                    disabled={isBusy}
+                    disabled={isBusy || !player2Address}
                     className="w-full py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-purple-500 to-pink-500 hover:from-purple-600 hover:to-pink-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-lg hover:shadow-xl transform hover:scale-105 disabled:transform-none"
                   >
                     {loading ? 'Preparing...' : '🎴 Prepare & Export Auth Entry'}
@@ -961,9 +1130,9 @@ export function TwentyOneGame({
                     <div className="grid grid-cols-2 gap-3">
                       <input
                         type="text"
-                        value={importSessionId}
+                        value={importPlayer2}
                         readOnly
-                        placeholder="Session ID (auto)"
+                        placeholder="Player 2 (auto)"
                         className="px-4 py-2 rounded-xl bg-gray-50 border-2 border-gray-200 text-xs font-mono text-gray-600 cursor-not-allowed"
                       />
                       <input
@@ -1011,6 +1180,55 @@ export function TwentyOneGame({
           </div>
         )}
 
+        {/* DEAL PHASE */}
+        {gamePhase === 'deal' && gameState && (
+          <div className="bg-white/95 backdrop-blur-xl rounded-2xl p-8 shadow-2xl text-center">
+            <div className="text-7xl mb-4">🎴</div>
+            <h3 className="text-3xl font-black text-gray-900 mb-3">
+              {gameState.round > 0 ? `Round ${gameState.round + 1}: Shuffle Up` : 'Shuffle Up'}
+            </h3>
+            <p className="text-lg font-semibold text-gray-700 mb-2">
+              Both players commit to a secret, then reveal it. The cards are dealt once both are revealed.
+            </p>
+            <p className="text-sm font-semibold text-gray-600 mb-6">
+              Player 1: {isSet(gameState.player1_deal_secret) ? '✓ Revealed' : isSet(gameState.player1_commitment) ? '✓ Committed' : 'Waiting...'} · Player 2: {isSet(gameState.player2_deal_secret) ? '✓ Revealed' : isSet(gameState.player2_commitment) ? '✓ Committed' : 'Waiting...'}
+              {isSet(gameState.reveal_deadline) && ` · Reveal window closes at ledger ${gameState.reveal_deadline}`}
+            </p>
+            {(isPlayer1 || isPlayer2) && !hasCommitted ? (
+              <button
+                onClick={handleCommitSecret}
+                disabled={isBusy}
+                className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-purple-500 to-pink-500 hover:from-purple-600 hover:to-pink-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Committing...' : '🔒 Commit Secret'}
+              </button>
+            ) : (isPlayer1 || isPlayer2) && !hasRevealedDeal && isSet(gameState.player1_commitment) && isSet(gameState.player2_commitment) ? (
+              <button
+                onClick={handleRevealDeal}
+                disabled={isBusy}
+                className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : '🃏 Reveal & Deal'}
+              </button>
+            ) : (
+              <div className="space-y-3">
+                <p className="text-sm font-semibold text-gray-700">
+                  Waiting for the other player. If they miss the reveal window, claim the win.
+                </p>
+                {isSet(gameState.reveal_deadline) && (
+                  <button
+                    onClick={handleClaimForfeit}
+                    disabled={isBusy}
+                    className="px-8 py-3 rounded-xl font-bold text-white text-sm bg-gradient-to-r from-gray-500 to-gray-600 hover:from-gray-600 hover:to-gray-700 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-md"
+                  >
+                    {loading ? 'Claiming...' : 'Claim Forfeit'}
+                  </button>
+                )}
+              </div>
+            )}
+          </div>
+        )}
+
         {/* PLAY PHASE - Table View */}
         {gamePhase === 'play' && gameState && (
           <div className="space-y-8">
@@ -1035,6 +1253,7 @@ export function TwentyOneGame({
                 {Array.from(isPlayer1 ? gameState.player2_hand : gameState.player1_hand).map((card, idx) => (
                   <PlayingCard key={idx} value={card} />
                 ))}
+                <PlayingCard value={opponentHoleCard ?? 0} isHidden={!isSet(opponentHoleCard)} />
               </div>
 
               {/* Opponent's Score */}
@@ -1072,13 +1291,17 @@ export function TwentyOneGame({
                 {Array.from(isPlayer1 ? gameState.player1_hand : gameState.player2_hand).map((card, idx) => (
                   <PlayingCard key={idx} value={card} />
                 ))}
+                <PlayingCard value={myHoleCard ?? 0} isHidden={!isSet(myHoleCard)} />
               </div>
+              <p className="text-center text-xs font-semibold text-green-200 mb-4">
+                Your hole card is the last one; only you can see it until it is revealed.
+              </p>
 
               {/* Your Score */}
               <div className="text-center mb-6">
                 <div className="inline-block px-6 py-3 rounded-full bg-white/20 backdrop-blur-sm">
                   <span className="text-yellow-400 font-black text-4xl">
-                    {isPlayer1 ? (player1HandValue ?? '?') : (player2HandValue ?? '?')}
+                    {myHandTotal ?? '?'}
                   </span>
                 </div>
                 {(isPlayer1 ? gameState.player1_stuck : gameState.player2_stuck) && (
@@ -1108,7 +1331,44 @@ export function TwentyOneGame({
                 </div>
               )}
 
-              {!canAct && (
+              {hitPending && (
+                <div className="text-center p-4 bg-blue-500/20 rounded-xl border-2 border-blue-400/50 space-y-3">
+                  <p className="text-white font-semibold">
+                    {normalizeAddress(gameState.hitter!) === normalizedUserAddress ? 'You hit!' : 'Your opponent hit!'} Both players commit and reveal a hit secret to deal the card.
+                  </p>
+                  <p className="text-green-200 text-sm font-semibold">
+                    Player 1: {isSet(gameState.player1_hit_secret) ? '✓ Revealed' : isSet(gameState.player1_hit_commitment) ? '✓ Committed' : 'Waiting...'} · Player 2: {isSet(gameState.player2_hit_secret) ? '✓ Revealed' : isSet(gameState.player2_hit_commitment) ? '✓ Committed' : 'Waiting...'}
+                    {isSet(gameState.reveal_deadline) && ` · Reveal window closes at ledger ${gameState.reveal_deadline}`}
+                  </p>
+                  {(isPlayer1 || isPlayer2) && !hasCommittedHit ? (
+                    <button
+                      onClick={handleCommitHit}
+                      disabled={isBusy}
+                      className="px-8 py-3 rounded-xl font-bold text-white bg-gradient-to-r from-blue-600 to-blue-700 hover:from-blue-700 hover:to-blue-800 disabled:from-gray-400 disabled:to-gray-500 transition-all shadow-xl"
+                    >
+                      {loading ? 'Committing...' : '🔒 Commit Hit Secret'}
+                    </button>
+                  ) : (isPlayer1 || isPlayer2) && !hasRevealedHit && isSet(gameState.player1_hit_commitment) && isSet(gameState.player2_hit_commitment) ? (
+                    <button
+                      onClick={handleRevealHit}
+                      disabled={isBusy}
+                      className="px-8 py-3 rounded-xl font-bold text-white bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-400 disabled:to-gray-500 transition-all shadow-xl"
+                    >
+                      {loading ? 'Revealing...' : '🎴 Reveal Hit Secret'}
+                    </button>
+                  ) : (
+                    <button
+                      onClick={handleClaimForfeit}
+                      disabled={isBusy}
+                      className="px-8 py-3 rounded-xl font-bold text-white text-sm bg-gradient-to-r from-gray-500 to-gray-600 hover:from-gray-600 hover:to-gray-700 disabled:from-gray-400 disabled:to-gray-500 transition-all shadow-md"
+                    >
+                      {loading ? 'Claiming...' : 'Claim Forfeit (after the window closes)'}
+                    </button>
+                  )}
+                </div>
+              )}
+
+              {!canAct && !hitPending && (
                 <div className="text-center p-4 bg-blue-500/20 rounded-xl border-2 border-blue-400/50">
                   <p className="text-white font-semibold">
                     {(isPlayer1 ? gameState.player1_stuck : gameState.player2_stuck)
@@ -1128,16 +1388,35 @@ export function TwentyOneGame({
             <h3 className="text-3xl font-black text-gray-900 mb-3">
               Both Players Have Stuck!
             </h3>
-            <p className="text-lg font-semibold text-gray-700 mb-6">
-              Click below to reveal the winner
+            <p className="text-lg font-semibold text-gray-700 mb-2">
+              Reveal your hole card. The winner is settled once both are revealed.
             </p>
-            <button
-              onClick={handleRevealWinner}
-              disabled={isBusy}
-              className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
-            >
-              {loading ? 'Revealing...' : '🏆 Reveal Winner'}
-            </button>
+            <p className="text-sm font-semibold text-gray-600 mb-6">
+              Player 1: {isSet(gameState.player1_secret) ? '✓ Revealed' : 'Waiting...'} · Player 2: {isSet(gameState.player2_secret) ? '✓ Revealed' : 'Waiting...'}
+              {isSet(gameState.reveal_deadline) && ` · Reveal window closes at ledger ${gameState.reveal_deadline}`}
+            </p>
+            {(isPlayer1 || isPlayer2) && !hasRevealedHand ? (
+              <button
+                onClick={handleRevealHand}
+                disabled={isBusy}
+                className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : '🏆 Reveal Hole Card'}
+              </button>
+            ) : (
+              <div className="space-y-3">
+                <p className="text-sm font-semibold text-gray-700">
+                  Waiting for the other player to reveal. If they miss the reveal window, claim the win.
+                </p>
+                <button
+                  onClick={handleClaimForfeit}
+                  disabled={isBusy}
+                  className="px-8 py-3 rounded-xl font-bold text-white text-sm bg-gradient-to-r from-gray-500 to-gray-600 hover:from-gray-600 hover:to-gray-700 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-md"
+                >
+                  {loading ? 'Claiming...' : 'Claim Forfeit'}
+                </button>
+              </div>
+            )}
           </div>
         )}
 
@@ -1160,6 +1439,9 @@ export function TwentyOneGame({
                   {Array.from(gameState.player1_hand).map((card, idx) => (
                     <div key={idx} className="text-2xl">{card}</div>
                   ))}
+                  {isSet(gameState.player1_hole_card) && (
+                    <div className="text-2xl">{gameState.player1_hole_card}</div>
+                  )}
                 </div>
               </div>
               <div className="p-6 bg-gray-100 rounded-xl">
@@ -1171,10 +1453,22 @@ export function TwentyOneGame({
                   {Array.from(gameState.player2_hand).map((card, idx) => (
                     <div key={idx} className="text-2xl">{card}</div>
                   ))}
+                  {isSet(gameState.player2_hole_card) && (
+                    <div className="text-2xl">{gameState.player2_hole_card}</div>
+                  )}
                 </div>
               </div>
             </div>
 
+            {gameState.is_draw && (
+              <div className="p-6 bg-gradient-to-r from-gray-100 to-slate-100 border-2 border-gray-300 rounded-xl shadow-lg mb-6">
+                <p className="text-2xl font-black text-gray-800">🤝 It's a draw!</p>
+                <p className="mt-2 font-semibold text-gray-700">
+                  Neither player revealed in time.
+                </p>
+              </div>
+            )}
+
             {gameState.winner && (
               <div className="p-6 bg-gradient-to-r from-green-100 to-emerald-100 border-2 border-green-300 rounded-xl shadow-lg mb-6">
                 <p className="text-sm font-bold uppercase tracking-wide text-gray-600 mb-2">Winner</p>
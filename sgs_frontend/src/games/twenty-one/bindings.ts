@@ -40,14 +40,71 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Beacon request the round's deal seed comes from; each round opens its own
+   */
+  beacon_request: u32;
+  /**
+   * Beacon output once both deal secrets are revealed; seeds the round's deal
+   */
+  deal_seed: Option<Buffer>;
+  /**
+   * Beacon request the pending hit is dealt from
+   */
+  hit_request: u32;
+  /**
+   * Cards hit so far this round, by either player
+   */
+  hits: u32;
+  /**
+   * Player whose hit waits on its beacon round
+   */
+  hitter: Option<string>;
+  is_draw: boolean;
   player1: string;
+  /**
+   * `keccak256(keccak256(secret))` committed by player 1 for the round
+   */
+  player1_commitment: Option<Buffer>;
+  /**
+   * Player 1's deal secret, `keccak256(secret)`, once revealed
+   */
+  player1_deal_secret: Option<Buffer>;
   player1_hand: Buffer;
+  /**
+   * `keccak256(hit_secret)` committed by player 1 for the pending hit
+   */
+  player1_hit_commitment: Option<Buffer>;
+  /**
+   * Player 1's hit secret for the pending hit, once revealed
+   */
+  player1_hit_secret: Option<Buffer>;
+  /**
+   * Player 1's hole card (1-13), once revealed
+   */
+  player1_hole_card: Option<u32>;
   player1_points: i128;
+  /**
+   * Player 1's secret, once revealed
+   */
+  player1_secret: Option<Buffer>;
   player1_stuck: boolean;
   player2: string;
+  player2_commitment: Option<Buffer>;
+  player2_deal_secret: Option<Buffer>;
   player2_hand: Buffer;
+  player2_hit_commitment: Option<Buffer>;
+  player2_hit_secret: Option<Buffer>;
+  player2_hole_card: Option<u32>;
   player2_points: i128;
+  player2_secret: Option<Buffer>;
   player2_stuck: boolean;
+  /**
+   * Last ledger on which to reveal: the deal secrets once both players have
+   * committed, the hit secrets once a player hits, then the secrets once
+   * both have stuck
+   */
+  reveal_deadline: Option<u32>;
   round: u32;
   winner: Option<string>;
 }
@@ -63,16 +120,100 @@ export const Errors = {
   8: {message:"Draw"},
   9: {message:"SelfPlay"},
   10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+  11: {message:"InvalidHandData"},
+  12: {message:"SessionAlreadyExists"},
+  13: {message:"HandNotDealt"},
+  14: {message:"AlreadyCommitted"},
+  15: {message:"InvalidReveal"},
+  16: {message:"AlreadyRevealed"},
+  17: {message:"RevealWindowClosed"},
+  18: {message:"RevealWindowOpen"},
+  19: {message:"BothPlayersNotCommitted"},
+  20: {message:"SessionExpiring"},
+  21: {message:"HitPending"},
+  22: {message:"NoHitPending"},
+  100: {message:"NoPendingAdmin"},
+  101: {message:"AdminProposalExpired"},
+  102: {message:"InvalidExpiry"},
+  103: {message:"MissingRole"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "NextBeaconRequest", values: void};
+
+/**
+ * Admin transfer waiting for the new admin to accept
+ */
+export interface PendingAdmin {
+  address: string;
+  /**
+   * Last ledger on which the proposal can be accepted
+   */
+  live_until_ledger: u32;
+}
+
+/**
+ * Permission that can be granted to an account besides the admin
+ */
+export type Role = {tag: "Upgrader", values: void} | {tag: "HubManager", values: void} | {tag: "Pauser", values: void};
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * Cards are dealt once both players have committed with `commit_secret`
+   * and revealed their deal secrets with `reveal_deal`.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * 
+   * # Returns
+   * * `u32` - Session ID allocated by the Game Hub
+   */
+  start_game: ({player1, player2, player1_points, player2_points}: {player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a commit_secret transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to the player's secret for the current round.
+   * Once both players have committed, the window to reveal the deal
+   * secrets opens.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the committing player
+   * * `commitment` - `keccak256(keccak256(secret))` for the player's 32-byte secret
+   */
+  commit_secret: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_deal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the player's deal secret for the current round.
+   * Can only be called after both players have committed and before the
+   * reveal deadline. The second reveal finalizes the beacon output that
+   * seeds the deal, and each player is dealt a face-up card and a hole card
+   * that only they can work out.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `deal_secret` - `keccak256(secret)`, whose hash was committed in `commit_secret`
+   */
+  reveal_deal: ({session_id, player, deal_secret}: {session_id: u32, player: string, deal_secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Player draws another card ("hit").
-   * If the player's hand value exceeds 21, they bust and lose immediately.
+   * Player asks for another card ("hit").
+   * The card is dealt from a beacon round of its own, so it cannot be known
+   * before both players commit to and reveal a fresh hit secret with
+   * `commit_hit` and `reveal_hit`. Both must do so within the reveal window
+   * this opens, or the one who did not forfeits. No one can hit again until
+   * the card is dealt.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
@@ -80,10 +221,37 @@ export interface Client {
    */
   hit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a commit_hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to the player's hit secret for the pending hit.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the committing player
+   * * `commitment` - `keccak256(hit_secret)` for the player's 32-byte hit secret
+   */
+  commit_hit: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the player's hit secret for the pending hit.
+   * Can only be called after both players have committed. The second reveal
+   * finalizes the beacon round and deals the card face up to the player who
+   * hit. If their face-up cards alone then exceed 21 once the smallest
+   * possible hole card is added, they bust and lose immediately; any other
+   * bust is settled when the hole cards are revealed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `hit_secret` - The hit secret whose hash was committed in `commit_hit`
+   */
+  reveal_hit: ({session_id, player, hit_secret}: {session_id: u32, player: string, hit_secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a stick transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player chooses to stick (end their turn with current hand).
-   * If both players have stuck, the game can be revealed.
+   * Once both players have stuck, the reveal window opens.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
@@ -92,43 +260,100 @@ export interface Client {
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reveal_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the secret behind a player's hole card.
+   * Can only be called after both players have stuck and before the reveal
+   * deadline. The second reveal determines the winner (closest to 21
+   * without going over) and ends the session; a draw starts a new round
+   * for which both players commit again.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `secret` - The secret committed to in `commit_secret`
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reveal_hand: ({session_id, player, secret}: {session_id: u32, player: string, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a claim_forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game whose reveal deadline has passed without both reveals.
+   * A player who revealed wins against one who did not; if neither
+   * revealed, the game is a draw. This covers the deal secrets, the hit
+   * secrets of a pending hit and the secrets revealed after both players
+   * stick. Anyone can call this.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_forfeit: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game information.
+   * Hole cards are only present once revealed.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Game` - The game state (includes face-up hands, revealed hole cards and winner)
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information.
+   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current hand value for a player.
+   * Counts the face-up cards, plus the hole card once revealed.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
+   * * `player` - Address of the player
    * 
    * # Returns
-   * * `Game` - The game state (includes hands and winner after game ends)
+   * * `u32` - The total value of the player's known cards
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the schema version of the stored data
+   * 
+   * # Returns
+   * * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
+   */
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Convert games stored by an earlier build to the current layout (admin or upgrader).
+   * Run after `upgrade`, once the beacon is set. Missing or already converted
+   * games are skipped, so a large backlog can be migrated in batches; the
+   * version only moves once the last batch is sent. Those builds chose their
+   * own session IDs and the hub kept no record of them, so their games play
+   * on without a hub session and end without settling anything at the hub.
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `session_ids` - Session IDs of in-flight games
+   * * `complete` - True on the last batch; records this build's schema version
+   * 
+   * # Returns
+   * * `u32` - Number of games converted
+   */
+  migrate: ({caller, session_ids, complete}: {caller: string, session_ids: Array<u32>, complete: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account (admin only)
+   * 
+   * # Arguments
+   * * `role` - The role to grant
+   * * `account` - The account receiving it
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -140,59 +365,97 @@ export interface Client {
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a set_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the randomness beacon contract address (admin or hub manager)
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_beacon` - The new randomness beacon contract address
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_beacon: ({caller, new_beacon}: {caller: string, new_beacon: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address (admin or hub manager)
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({caller, new_hub}: {caller: string, new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
-   * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (admin or upgrader)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `new_wasm_hash` - The hash of the new WASM binary
+   */
+  upgrade: ({caller, new_wasm_hash}: {caller: string, new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account was granted a role
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `bool` - True if granted (the admin holds every role implicitly)
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current hand value for a player.
+   * Construct and simulate a get_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the randomness beacon contract address
+   * 
+   * # Returns
+   * * `Address` - The randomness beacon contract address
+   */
+  get_beacon: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account (admin only)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player
+   * * `role` - The role to revoke
+   * * `account` - The account losing it
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only). The transfer completes when
+   * `new_admin` calls `accept_admin`; proposing again replaces it.
+   * 
+   * # Arguments
+   * * `new_admin` - The proposed admin address
+   * * `live_until_ledger` - Last ledger on which the proposal can be accepted
+   */
+  propose_admin: ({new_admin, live_until_ledger}: {new_admin: string, live_until_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin proposal (proposed admin only)
    * 
    * # Returns
-   * * `u32` - The total value of the player's hand
+   * * `Address` - The new admin address
    */
-  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin transfer waiting to be accepted, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingAdmin>>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   * 
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
 }
 export class Client extends ContractClient {
@@ -214,35 +477,66 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAFgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACwAAAAAAAAAUU2Vzc2lvbkFscmVhZHlFeGlzdHMAAAAMAAAAAAAAAAxIYW5kTm90RGVhbHQAAAANAAAAAAAAABBBbHJlYWR5Q29tbWl0dGVkAAAADgAAAAAAAAANSW52YWxpZFJldmVhbAAAAAAAAA8AAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAQAAAAAAAAABJSZXZlYWxXaW5kb3dDbG9zZWQAAAAAABEAAAAAAAAAEFJldmVhbFdpbmRvd09wZW4AAAASAAAAAAAAABdCb3RoUGxheWVyc05vdENvbW1pdHRlZAAAAAATAAAAAAAAAA9TZXNzaW9uRXhwaXJpbmcAAAAAFAAAAAAAAAAKSGl0UGVuZGluZwAAAAAAFQAAAAAAAAAMTm9IaXRQZW5kaW5nAAAAFg==",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAdAAAASUJlYWNvbiByZXF1ZXN0IHRoZSByb3VuZCdzIGRlYWwgc2VlZCBjb21lcyBmcm9tOyBlYWNoIHJvdW5kIG9wZW5zIGl0cyBvd24AAAAAAAAOYmVhY29uX3JlcXVlc3QAAAAAAAQAAABJQmVhY29uIG91dHB1dCBvbmNlIGJvdGggZGVhbCBzZWNyZXRzIGFyZSByZXZlYWxlZDsgc2VlZHMgdGhlIHJvdW5kJ3MgZGVhbAAAAAAAAAlkZWFsX3NlZWQAAAAAAAPoAAAD7gAAACAAAAAsQmVhY29uIHJlcXVlc3QgdGhlIHBlbmRpbmcgaGl0IGlzIGRlYWx0IGZyb20AAAALaGl0X3JlcXVlc3QAAAAABAAAAC1DYXJkcyBoaXQgc28gZmFyIHRoaXMgcm91bmQsIGJ5IGVpdGhlciBwbGF5ZXIAAAAAAAAEaGl0cwAAAAQAAAAqUGxheWVyIHdob3NlIGhpdCB3YWl0cyBvbiBpdHMgYmVhY29uIHJvdW5kAAAAAAAGaGl0dGVyAAAAAAPoAAAAEwAAAAAAAAAHaXNfZHJhdwAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAABMAAABCYGtlY2NhazI1NihrZWNjYWsyNTYoc2VjcmV0KSlgIGNvbW1pdHRlZCBieSBwbGF5ZXIgMSBmb3IgdGhlIHJvdW5kAAAAAAAScGxheWVyMV9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAA6UGxheWVyIDEncyBkZWFsIHNlY3JldCwgYGtlY2NhazI1NihzZWNyZXQpYCwgb25jZSByZXZlYWxlZAAAAAAAE3BsYXllcjFfZGVhbF9zZWNyZXQAAAAD6AAAA+4AAAAgAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAQWBrZWNjYWsyNTYoaGl0X3NlY3JldClgIGNvbW1pdHRlZCBieSBwbGF5ZXIgMSBmb3IgdGhlIHBlbmRpbmcgaGl0AAAAAAAAFnBsYXllcjFfaGl0X2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAADhQbGF5ZXIgMSdzIGhpdCBzZWNyZXQgZm9yIHRoZSBwZW5kaW5nIGhpdCwgb25jZSByZXZlYWxlZAAAABJwbGF5ZXIxX2hpdF9zZWNyZXQAAAAAA+gAAAPuAAAAIAAAACpQbGF5ZXIgMSdzIGhvbGUgY2FyZCAoMS0xMyksIG9uY2UgcmV2ZWFsZWQAAAAAABFwbGF5ZXIxX2hvbGVfY2FyZAAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAACBQbGF5ZXIgMSdzIHNlY3JldCwgb25jZSByZXZlYWxlZAAAAA5wbGF5ZXIxX3NlY3JldAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABJwbGF5ZXIyX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAATcGxheWVyMl9kZWFsX3NlY3JldAAAAAPoAAAD7gAAACAAAAAAAAAADHBsYXllcjJfaGFuZAAAAA4AAAAAAAAAFnBsYXllcjJfaGl0X2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAScGxheWVyMl9oaXRfc2VjcmV0AAAAAAPoAAAD7gAAACAAAAAAAAAAEXBsYXllcjJfaG9sZV9jYXJkAAAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3NlY3JldAAAAAAD6AAAA+4AAAAgAAAAAAAAAA1wbGF5ZXIyX3N0dWNrAAAAAAAAAQAAAJxMYXN0IGxlZGdlciBvbiB3aGljaCB0byByZXZlYWw6IHRoZSBkZWFsIHNlY3JldHMgb25jZSBib3RoIHBsYXllcnMgaGF2ZQpjb21taXR0ZWQsIHRoZSBoaXQgc2VjcmV0cyBvbmNlIGEgcGxheWVyIGhpdHMsIHRoZW4gdGhlIHNlY3JldHMgb25jZQpib3RoIGhhdmUgc3R1Y2sAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAA+gAAAAEAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAgAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAxSUQgb2YgdGhlIG5leHQgYmVhY29uIHJlcXVlc3QgdGhpcyBjb250cmFjdCBvcGVucwAAAAAAABFOZXh0QmVhY29uUmVxdWVzdAAAAA==",
+        "AAAABAAAAAAAAAAAAAAACkFkbWluRXJyb3IAAAAAAAQAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAABkAAAAAAAAABRBZG1pblByb3Bvc2FsRXhwaXJlZAAAAGUAAAAAAAAADUludmFsaWRFeHBpcnkAAAAAAABmAAAAAAAAAAtNaXNzaW5nUm9sZQAAAABn",
+        "AAAAAQAAADJBZG1pbiB0cmFuc2ZlciB3YWl0aW5nIGZvciB0aGUgbmV3IGFkbWluIHRvIGFjY2VwdAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAxTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAgAAAD5QZXJtaXNzaW9uIHRoYXQgY2FuIGJlIGdyYW50ZWQgdG8gYW4gYWNjb3VudCBiZXNpZGVzIHRoZSBhZG1pbgAAAAAAAAAAAARSb2xlAAAAAwAAAAAAAAAdQ2FuIHJlcGxhY2UgdGhlIGNvbnRyYWN0IFdBU00AAAAAAAAIVXBncmFkZXIAAAAAAAAAWENhbiBjaGFuZ2UgdGhlIEdhbWVIdWIgYWRkcmVzcyBhIGdhbWUgcmVwb3J0cyB0byBhbmQgdGhlIHJhbmRvbW5lc3MKYmVhY29uIGl0IGRyYXdzIGZyb20AAAAKSHViTWFuYWdlcgAAAAAAAAAAABpDYW4gcGF1c2UgYW5kIHVucGF1c2UgcGxheQAAAAAABlBhdXNlcgAA",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAAq1TdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpDYXJkcyBhcmUgZGVhbHQgb25jZSBib3RoIHBsYXllcnMgaGF2ZSBjb21taXR0ZWQgd2l0aCBgY29tbWl0X3NlY3JldGAKYW5kIHJldmVhbGVkIHRoZWlyIGRlYWwgc2VjcmV0cyB3aXRoIGByZXZlYWxfZGVhbGAuCgoqKkNSSVRJQ0FMOioqIFRoaXMgbWV0aG9kIHJlcXVpcmVzIGF1dGhvcml6YXRpb24gZnJvbSBUSElTIGNvbnRyYWN0IChub3QgcGxheWVycykuClRoZSBHYW1lIEh1YiB3aWxsIGNhbGwgYGdhbWVfaWQucmVxdWlyZV9hdXRoKClgIHdoaWNoIGNoZWNrcyB0aGlzIGNvbnRyYWN0J3MgYWRkcmVzcy4KCiMgQXJndW1lbnRzCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcGxheWVyMV9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDEKKiBgcGxheWVyMl9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDIKCiMgUmV0dXJucwoqIGB1MzJgIC0gU2Vzc2lvbiBJRCBhbGxvY2F0ZWQgYnkgdGhlIEdhbWUgSHViAAAAAAAACnN0YXJ0X2dhbWUAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAATpDb21taXQgdG8gdGhlIHBsYXllcidzIHNlY3JldCBmb3IgdGhlIGN1cnJlbnQgcm91bmQuCk9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgY29tbWl0dGVkLCB0aGUgd2luZG93IHRvIHJldmVhbCB0aGUgZGVhbApzZWNyZXRzIG9wZW5zLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29tbWl0dGluZyBwbGF5ZXIKKiBgY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KGtlY2NhazI1NihzZWNyZXQpKWAgZm9yIHRoZSBwbGF5ZXIncyAzMi1ieXRlIHNlY3JldAAAAAAADWNvbW1pdF9zZWNyZXQAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAdxSZXZlYWwgdGhlIHBsYXllcidzIGRlYWwgc2VjcmV0IGZvciB0aGUgY3VycmVudCByb3VuZC4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZCBhbmQgYmVmb3JlIHRoZQpyZXZlYWwgZGVhZGxpbmUuIFRoZSBzZWNvbmQgcmV2ZWFsIGZpbmFsaXplcyB0aGUgYmVhY29uIG91dHB1dCB0aGF0CnNlZWRzIHRoZSBkZWFsLCBhbmQgZWFjaCBwbGF5ZXIgaXMgZGVhbHQgYSBmYWNlLXVwIGNhcmQgYW5kIGEgaG9sZSBjYXJkCnRoYXQgb25seSB0aGV5IGNhbiB3b3JrIG91dC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHJldmVhbGluZyBwbGF5ZXIKKiBgZGVhbF9zZWNyZXRgIC0gYGtlY2NhazI1NihzZWNyZXQpYCwgd2hvc2UgaGFzaCB3YXMgY29tbWl0dGVkIGluIGBjb21taXRfc2VjcmV0YAAAAAtyZXZlYWxfZGVhbAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAC2RlYWxfc2VjcmV0AAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAbxQbGF5ZXIgYXNrcyBmb3IgYW5vdGhlciBjYXJkICgiaGl0IikuClRoZSBjYXJkIGlzIGRlYWx0IGZyb20gYSBiZWFjb24gcm91bmQgb2YgaXRzIG93biwgc28gaXQgY2Fubm90IGJlIGtub3duCmJlZm9yZSBib3RoIHBsYXllcnMgY29tbWl0IHRvIGFuZCByZXZlYWwgYSBmcmVzaCBoaXQgc2VjcmV0IHdpdGgKYGNvbW1pdF9oaXRgIGFuZCBgcmV2ZWFsX2hpdGAuIEJvdGggbXVzdCBkbyBzbyB3aXRoaW4gdGhlIHJldmVhbCB3aW5kb3cKdGhpcyBvcGVucywgb3IgdGhlIG9uZSB3aG8gZGlkIG5vdCBmb3JmZWl0cy4gTm8gb25lIGNhbiBoaXQgYWdhaW4gdW50aWwKdGhlIGNhcmQgaXMgZGVhbHQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgZHJhd2luZyBhIGNhcmQAAAADaGl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAOpDb21taXQgdG8gdGhlIHBsYXllcidzIGhpdCBzZWNyZXQgZm9yIHRoZSBwZW5kaW5nIGhpdC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbW1pdHRpbmcgcGxheWVyCiogYGNvbW1pdG1lbnRgIC0gYGtlY2NhazI1NihoaXRfc2VjcmV0KWAgZm9yIHRoZSBwbGF5ZXIncyAzMi1ieXRlIGhpdCBzZWNyZXQAAAAAAApjb21taXRfaGl0AAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAi5SZXZlYWwgdGhlIHBsYXllcidzIGhpdCBzZWNyZXQgZm9yIHRoZSBwZW5kaW5nIGhpdC4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZC4gVGhlIHNlY29uZCByZXZlYWwKZmluYWxpemVzIHRoZSBiZWFjb24gcm91bmQgYW5kIGRlYWxzIHRoZSBjYXJkIGZhY2UgdXAgdG8gdGhlIHBsYXllciB3aG8KaGl0LiBJZiB0aGVpciBmYWNlLXVwIGNhcmRzIGFsb25lIHRoZW4gZXhjZWVkIDIxIG9uY2UgdGhlIHNtYWxsZXN0CnBvc3NpYmxlIGhvbGUgY2FyZCBpcyBhZGRlZCwgdGhleSBidXN0IGFuZCBsb3NlIGltbWVkaWF0ZWx5OyBhbnkgb3RoZXIKYnVzdCBpcyBzZXR0bGVkIHdoZW4gdGhlIGhvbGUgY2FyZHMgYXJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcmV2ZWFsaW5nIHBsYXllcgoqIGBoaXRfc2VjcmV0YCAtIFRoZSBoaXQgc2VjcmV0IHdob3NlIGhhc2ggd2FzIGNvbW1pdHRlZCBpbiBgY29tbWl0X2hpdGAAAAAAAApyZXZlYWxfaGl0AAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmhpdF9zZWNyZXQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAANdQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpPbmNlIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLCB0aGUgcmV2ZWFsIHdpbmRvdyBvcGVucy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBzdGlja2luZwAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAb1SZXZlYWwgdGhlIHNlY3JldCBiZWhpbmQgYSBwbGF5ZXIncyBob2xlIGNhcmQuCkNhbiBvbmx5IGJlIGNhbGxlZCBhZnRlciBib3RoIHBsYXllcnMgaGF2ZSBzdHVjayBhbmQgYmVmb3JlIHRoZSByZXZlYWwKZGVhZGxpbmUuIFRoZSBzZWNvbmQgcmV2ZWFsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMQp3aXRob3V0IGdvaW5nIG92ZXIpIGFuZCBlbmRzIHRoZSBzZXNzaW9uOyBhIGRyYXcgc3RhcnRzIGEgbmV3IHJvdW5kCmZvciB3aGljaCBib3RoIHBsYXllcnMgY29tbWl0IGFnYWluLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcmV2ZWFsaW5nIHBsYXllcgoqIGBzZWNyZXRgIC0gVGhlIHNlY3JldCBjb21taXR0ZWQgdG8gaW4gYGNvbW1pdF9zZWNyZXRgAAAAAAAAC3JldmVhbF9oYW5kAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGc2VjcmV0AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAbRFbmQgYSBnYW1lIHdob3NlIHJldmVhbCBkZWFkbGluZSBoYXMgcGFzc2VkIHdpdGhvdXQgYm90aCByZXZlYWxzLgpBIHBsYXllciB3aG8gcmV2ZWFsZWQgd2lucyBhZ2FpbnN0IG9uZSB3aG8gZGlkIG5vdDsgaWYgbmVpdGhlcgpyZXZlYWxlZCwgdGhlIGdhbWUgaXMgYSBkcmF3LiBUaGlzIGNvdmVycyB0aGUgZGVhbCBzZWNyZXRzLCB0aGUgaGl0CnNlY3JldHMgb2YgYSBwZW5kaW5nIGhpdCBhbmQgdGhlIHNlY3JldHMgcmV2ZWFsZWQgYWZ0ZXIgYm90aCBwbGF5ZXJzCnN0aWNrLiBBbnlvbmUgY2FuIGNhbGwgdGhpcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBmb3IgYSBkcmF3AAAADWNsYWltX2ZvcmZlaXQAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAANdHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KSG9sZSBjYXJkcyBhcmUgb25seSBwcmVzZW50IG9uY2UgcmV2ZWFsZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgR2FtZWAgLSBUaGUgZ2FtZSBzdGF0ZSAoaW5jbHVkZXMgZmFjZS11cCBoYW5kcywgcmV2ZWFsZWQgaG9sZSBjYXJkcyBhbmQgd2lubmVyKQAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAAQFHZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCkNvdW50cyB0aGUgZmFjZS11cCBjYXJkcywgcGx1cyB0aGUgaG9sZSBjYXJkIG9uY2UgcmV2ZWFsZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBrbm93biBjYXJkcwAAAAAAAA5nZXRfaGFuZF92YWx1ZQAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAI5HZXQgdGhlIHNjaGVtYSB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgZGF0YQoKIyBSZXR1cm5zCiogYHUzMmAgLSBTY2hlbWEgdmVyc2lvbjsgbGFncyB0aGlzIGJ1aWxkJ3MgdmVyc2lvbiB1bnRpbCBgbWlncmF0ZWAgcnVucyBhZnRlciBhbiB1cGdyYWRlAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAqtDb252ZXJ0IGdhbWVzIHN0b3JlZCBieSBhbiBlYXJsaWVyIGJ1aWxkIHRvIHRoZSBjdXJyZW50IGxheW91dCAoYWRtaW4gb3IgdXBncmFkZXIpLgpSdW4gYWZ0ZXIgYHVwZ3JhZGVgLCBvbmNlIHRoZSBiZWFjb24gaXMgc2V0LiBNaXNzaW5nIG9yIGFscmVhZHkgY29udmVydGVkCmdhbWVzIGFyZSBza2lwcGVkLCBzbyBhIGxhcmdlIGJhY2tsb2cgY2FuIGJlIG1pZ3JhdGVkIGluIGJhdGNoZXM7IHRoZQp2ZXJzaW9uIG9ubHkgbW92ZXMgb25jZSB0aGUgbGFzdCBiYXRjaCBpcyBzZW50LiBUaG9zZSBidWlsZHMgY2hvc2UgdGhlaXIKb3duIHNlc3Npb24gSURzIGFuZCB0aGUgaHViIGtlcHQgbm8gcmVjb3JkIG9mIHRoZW0sIHNvIHRoZWlyIGdhbWVzIHBsYXkKb24gd2l0aG91dCBhIGh1YiBzZXNzaW9uIGFuZCBlbmQgd2l0aG91dCBzZXR0bGluZyBhbnl0aGluZyBhdCB0aGUgaHViLgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYFVwZ3JhZGVyYAoqIGBzZXNzaW9uX2lkc2AgLSBTZXNzaW9uIElEcyBvZiBpbi1mbGlnaHQgZ2FtZXMKKiBgY29tcGxldGVgIC0gVHJ1ZSBvbiB0aGUgbGFzdCBiYXRjaDsgcmVjb3JkcyB0aGlzIGJ1aWxkJ3Mgc2NoZW1hIHZlcnNpb24KCiMgUmV0dXJucwoqIGB1MzJgIC0gTnVtYmVyIG9mIGdhbWVzIGNvbnZlcnRlZAAAAAAHbWlncmF0ZQAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAAAAAAhjb21wbGV0ZQAAAAEAAAABAAAABA==",
+        "AAAAAAAAAHhHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gZ3JhbnQKKiBgYWNjb3VudGAgLSBUaGUgYWNjb3VudCByZWNlaXZpbmcgaXQAAAAKZ3JhbnRfcm9sZQAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAMRTZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MgKGFkbWluIG9yIGh1YiBtYW5hZ2VyKQoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYEh1Yk1hbmFnZXJgCiogYG5ld19iZWFjb25gIC0gVGhlIG5ldyByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAACnNldF9iZWFjb24AAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAKbmV3X2JlYWNvbgAAAAAAEwAAAAA=",
+        "AAAAAAAAAK9TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvciBodWIgbWFuYWdlcikKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYW4gYWNjb3VudCBob2xkaW5nIGBIdWJNYW5hZ2VyYAoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAdzZXRfaHViAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHbmV3X2h1YgAAAAATAAAAAA==",
+        "AAAAAAAAAKpVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAoYWRtaW4gb3IgdXBncmFkZXIpCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGFuIGFjY291bnQgaG9sZGluZyBgVXBncmFkZXJgCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAB3VwZ3JhZGUAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAHlDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgd2FzIGdyYW50ZWQgYSByb2xlCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGdyYW50ZWQgKHRoZSBhZG1pbiBob2xkcyBldmVyeSByb2xlIGltcGxpY2l0bHkpAAAAAAAACGhhc19yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAGpHZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIFRoZSByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAAAAAKZ2V0X2JlYWNvbgAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAHlSZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gcmV2b2tlCiogYGFjY291bnRgIC0gVGhlIGFjY291bnQgbG9zaW5nIGl0AAAAAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAP5Qcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHRyYW5zZmVyIGNvbXBsZXRlcyB3aGVuCmBuZXdfYWRtaW5gIGNhbGxzIGBhY2NlcHRfYWRtaW5gOyBwcm9wb3NpbmcgYWdhaW4gcmVwbGFjZXMgaXQuCgojIEFyZ3VtZW50cwoqIGBuZXdfYWRtaW5gIC0gVGhlIHByb3Bvc2VkIGFkbWluIGFkZHJlc3MKKiBgbGl2ZV91bnRpbF9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAADXByb3Bvc2VfYWRtaW4AAAAAAAACAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAAGRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHByb3Bvc2FsIChwcm9wb3NlZCBhZG1pbiBvbmx5KQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIG5ldyBhZG1pbiBhZGRyZXNzAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAABMAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAADVHZXQgdGhlIGFkbWluIHRyYW5zZmVyIHdhaXRpbmcgdG8gYmUgYWNjZXB0ZWQsIGlmIGFueQAAAAAAABFnZXRfcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMUGVuZGluZ0FkbWlu",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    hit: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<u32>>,
+        commit_secret: this.txFromJSON<Result<void>>,
+        reveal_deal: this.txFromJSON<Result<void>>,
+        hit: this.txFromJSON<Result<void>>,
+        commit_hit: this.txFromJSON<Result<void>>,
+        reveal_hit: this.txFromJSON<Result<void>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        reveal_hand: this.txFromJSON<Result<void>>,
+        claim_forfeit: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_hand_value: this.txFromJSON<Result<u32>>,
+        version: this.txFromJSON<u32>,
+        migrate: this.txFromJSON<u32>,
+        grant_role: this.txFromJSON<null>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
-        get_hand_value: this.txFromJSON<Result<u32>>
+        set_beacon: this.txFromJSON<null>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>,
+        get_beacon: this.txFromJSON<string>,
+        revoke_role: this.txFromJSON<null>,
+        propose_admin: this.txFromJSON<Result<void>>,
+        accept_admin: this.txFromJSON<Result<string>>,
+        get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
+        get_hub: this.txFromJSON<string>
   }
 }
\ No newline at end of file
@@ -1,9 +1,10 @@
-import { Client as TwentyOneClient } from './bindings';
+import { Client as TwentyOneClient, type Game } from './bindings';
 import { TWENTY_ONE_CONTRACT, NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
+import { keccak256 } from '@/utils/commitUtils';
 
 type ClientOptions = contract.ClientOptions;
 
@@ -43,7 +44,7 @@ export class TwentyOneService {
    * Get game state
    * Returns null if game doesn't exist (instead of throwing)
    */
-  async getGame(sessionId: number): Promise<any | null> {
+  async getGame(sessionId: number): Promise<Game | null> {
     try {
       const tx = await this.baseClient.get_game({ session_id: sessionId });
       const result = await tx.simulate();
@@ -89,9 +90,9 @@ export class TwentyOneService {
   /**
    * STEP 1 (Player 1): Prepare a start game transaction and export signed auth entry
    * Uses extended TTL (60 minutes) for multi-sig flow
+   * The auth entry binds Player 2's address; the Game Hub allocates the session ID
    */
   async prepareStartGame(
-    sessionId: number,
     player1: string,
     player2: string,
     player1Points: bigint,
@@ -108,7 +109,6 @@ export class TwentyOneService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
@@ -211,6 +211,11 @@ export class TwentyOneService {
     // Parse the auth entry to extract game parameters
     const gameParams = this.parseAuthEntry(player1AuthEntryXDR);
 
+    // Player 1 signed for a specific opponent; nobody else can complete the start
+    if (player2 !== gameParams.player2) {
+      throw new Error(`This invite is for ${gameParams.player2}. Connect that wallet to accept it.`);
+    }
+
     // Rebuild the transaction with Player 2 as source
     const client = new TwentyOneClient({
       contractId: TWENTY_ONE_CONTRACT,
@@ -220,7 +225,6 @@ export class TwentyOneService {
     });
 
     const tx = await client.start_game({
-      session_id: gameParams.sessionId,
       player1: gameParams.player1,
       player2,
       player1_points: gameParams.player1Points,
@@ -236,6 +240,7 @@ export class TwentyOneService {
 
   /**
    * STEP 3 (Player 2): Finalize and submit the fully signed transaction
+   * Returns the session ID allocated by the Game Hub
    */
   async finalizeStartGame(
     fullySignedTxXDR: string,
@@ -263,8 +268,8 @@ export class TwentyOneService {
    * Parse auth entry XDR to extract game parameters
    */
   parseAuthEntry(authEntryXDR: string): {
-    sessionId: number;
     player1: string;
+    player2: string;
     player1Points: bigint;
   } {
     try {
@@ -272,7 +277,7 @@ export class TwentyOneService {
 
       // Auth entries from `require_auth_for_args` only contain the args the signer authorized:
       // - signer address (from credentials)
-      // - session_id (arg 0)
+      // - opponent address (arg 0)
       // - signer points (arg 1)
       const credentials = authEntry.credentials();
       if (credentials.switch().name !== 'sorobanCredentialsAddress') {
@@ -294,12 +299,12 @@ export class TwentyOneService {
         throw new Error(`Invalid number of arguments: ${args.length}. Expected 2`);
       }
 
-      const sessionId = args[0].u32();
+      const player2 = Address.fromScVal(args[0]).toString();
       const player1Points = args[1].i128().lo().toBigInt();
 
       return {
-        sessionId,
         player1,
+        player2,
         player1Points,
       };
     } catch (err) {
@@ -309,7 +314,48 @@ export class TwentyOneService {
   }
 
   /**
-   * Player hits (draws a card)
+   * Commit to the round's secret
+   * The secret must be kept until `revealHand`; it also derives the deal secret
+   */
+  async commitSecret(
+    sessionId: number,
+    player: string,
+    secret: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.commit_secret({
+      session_id: sessionId,
+      player,
+      commitment: keccak256(keccak256(secret)),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    return this.submit(tx);
+  }
+
+  /**
+   * Reveal the deal secret once both players have committed
+   * The second reveal deals the round
+   */
+  async revealDeal(
+    sessionId: number,
+    player: string,
+    secret: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_deal({
+      session_id: sessionId,
+      player,
+      deal_secret: keccak256(secret),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    return this.submit(tx);
+  }
+
+  /**
+   * Player hits (asks for a card)
+   * The card is dealt once both players commit and reveal a hit secret
    */
   async hit(
     sessionId: number,
@@ -322,13 +368,46 @@ export class TwentyOneService {
       player,
     }, DEFAULT_METHOD_OPTIONS);
 
-    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
-    const sentTx = await signAndSendViaLaunchtube(
-      tx,
-      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
-      validUntilLedgerSeq
-    );
-    return sentTx.result;
+    return this.submit(tx);
+  }
+
+  /**
+   * Commit to a hit secret for the pending hit (both players)
+   */
+  async commitHit(
+    sessionId: number,
+    player: string,
+    hitSecret: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.commit_hit({
+      session_id: sessionId,
+      player,
+      commitment: keccak256(hitSecret),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    return this.submit(tx);
+  }
+
+  /**
+   * Reveal the hit secret once both players have committed
+   * The second reveal deals the card to the player who hit
+   */
+  async revealHit(
+    sessionId: number,
+    player: string,
+    hitSecret: Buffer,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_hit({
+      session_id: sessionId,
+      player,
+      hit_secret: hitSecret,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    return this.submit(tx);
   }
 
   /**
@@ -345,28 +424,58 @@ export class TwentyOneService {
       player,
     }, DEFAULT_METHOD_OPTIONS);
 
-    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
-    const sentTx = await signAndSendViaLaunchtube(
-      tx,
-      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
-      validUntilLedgerSeq
-    );
-    return sentTx.result;
+    return this.submit(tx);
   }
 
   /**
-   * Reveal the winner (can be called by either player)
+   * Reveal the secret behind the player's hole card once both have stuck
+   * The second reveal settles the round
    */
-  async revealWinner(
+  async revealHand(
     sessionId: number,
     player: string,
+    secret: Buffer,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
     const client = this.createSigningClient(player, signer);
-    const tx = await client.reveal_winner({
+    const tx = await client.reveal_hand({
+      session_id: sessionId,
+      player,
+      secret,
+    }, DEFAULT_METHOD_OPTIONS);
+
+    return this.submit(tx);
+  }
+
+  /**
+   * End a game whose reveal window closed without both reveals (anyone can call)
+   */
+  async claimForfeit(
+    sessionId: number,
+    caller: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const client = this.createSigningClient(caller, signer);
+    const tx = await client.claim_forfeit({
       session_id: sessionId,
     }, DEFAULT_METHOD_OPTIONS);
 
+    return this.submit(tx);
+  }
+
+  /**
+   * Work out a player's hole card (1-13) from the deal seed and their secret
+   * Matches the contract's `hole_card`; `seat` is 1 or 2
+   */
+  holeCard(dealSeed: Buffer, secret: Buffer, seat: number): number {
+    const hash = keccak256(dealSeed, secret, Buffer.from([1, seat]));
+    return Number(hash.readBigUInt64BE(0) % 13n) + 1;
+  }
+
+  /**
+   * Sign and submit a move
+   */
+  private async submit<T>(tx: contract.AssembledTransaction<T>) {
     const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
     const sentTx = await signAndSendViaLaunchtube(
       tx,
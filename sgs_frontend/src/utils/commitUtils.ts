@@ -0,0 +1,71 @@
+/**
+ * Commit-reveal utilities for games that seal moves behind keccak256 commitments
+ */
+
+import { Buffer } from 'buffer';
+import { Address } from '@stellar/stellar-sdk';
+import { keccak_256 } from '@noble/hashes/sha3';
+
+/**
+ * keccak256 of the concatenated parts (matches `env.crypto().keccak256`)
+ */
+export function keccak256(...parts: Uint8Array[]): Buffer {
+  return Buffer.from(keccak_256(Buffer.concat(parts)));
+}
+
+/**
+ * Fresh 32-byte secret from the browser's CSPRNG
+ */
+export function randomSecret(): Buffer {
+  const bytes = new Uint8Array(32);
+  crypto.getRandomValues(bytes);
+  return Buffer.from(bytes);
+}
+
+/**
+ * A u32 as 4 big-endian bytes (matches `u32::to_be_bytes`)
+ */
+export function u32Bytes(value: number): Buffer {
+  const bytes = Buffer.alloc(4);
+  bytes.writeUInt32BE(value);
+  return bytes;
+}
+
+/**
+ * An address as its `ScVal` XDR (matches `Address::to_xdr`)
+ */
+export function addressXdr(address: string): Buffer {
+  return Address.fromString(address).toScVal().toXDR();
+}
+
+/**
+ * Local storage for secrets a player must reveal later.
+ * Secrets only live in this browser: clearing site data before revealing
+ * forfeits the game once the reveal window closes.
+ */
+function secretStorageKey(contractId: string, sessionId: number, player: string, name: string): string {
+  return `sgs:secret:${contractId}:${sessionId}:${player}:${name}`;
+}
+
+export function saveSecret(
+  contractId: string,
+  sessionId: number,
+  player: string,
+  name: string,
+  value: string
+): void {
+  localStorage.setItem(secretStorageKey(contractId, sessionId, player, name), value);
+}
+
+export function loadSecret(
+  contractId: string,
+  sessionId: number,
+  player: string,
+  name: string
+): string | null {
+  return localStorage.getItem(secretStorageKey(contractId, sessionId, player, name));
+}
+
+export function clearSecret(contractId: string, sessionId: number, player: string, name: string): void {
+  localStorage.removeItem(secretStorageKey(contractId, sessionId, player, name));
+}
@@ -40,12 +40,34 @@ export const networks = {
 
 
 export interface Game {
+  is_draw: boolean;
   player1: string;
+  /**
+   * `keccak256(session_id || player || guess || salt)` sealed by player 1
+   */
+  player1_commitment: Option<Buffer>;
+  /**
+   * Player 1's guess, once revealed
+   */
   player1_guess: Option<u32>;
   player1_points: i128;
+  /**
+   * Player 1's salt, once revealed
+   */
+  player1_salt: Option<Buffer>;
   player2: string;
+  player2_commitment: Option<Buffer>;
   player2_guess: Option<u32>;
   player2_points: i128;
+  player2_salt: Option<Buffer>;
+  /**
+   * Last ledger on which guesses can be revealed, set once both players have guessed
+   */
+  reveal_deadline: Option<u32>;
+  /**
+   * Beacon output the winning number was drawn from
+   */
+  seed: Option<Buffer>;
   winner: Option<string>;
   winning_number: Option<u32>;
 }
@@ -55,38 +77,100 @@ export const Errors = {
   2: {message:"NotPlayer"},
   3: {message:"AlreadyGuessed"},
   4: {message:"BothPlayersNotGuessed"},
-  5: {message:"GameAlreadyEnded"}
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"SessionAlreadyExists"},
+  7: {message:"InvalidReveal"},
+  8: {message:"AlreadyRevealed"},
+  9: {message:"RevealWindowClosed"},
+  10: {message:"RevealWindowOpen"},
+  11: {message:"SessionExpiring"},
+  12: {message:"InvalidGuess"},
+  100: {message:"NoPendingAdmin"},
+  101: {message:"AdminProposalExpired"},
+  102: {message:"InvalidExpiry"},
+  103: {message:"MissingRole"}
+}
+
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+/**
+ * Admin transfer waiting for the new admin to accept
+ */
+export interface PendingAdmin {
+  address: string;
+  /**
+   * Last ledger on which the proposal can be accepted
+   */
+  live_until_ledger: u32;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Permission that can be granted to an account besides the admin
+ */
+export type Role = {tag: "Upgrader", values: void} | {tag: "HubManager", values: void} | {tag: "Pauser", values: void};
 
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `u32` - Session ID allocated by the Game Hub
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_game: ({player1, player2, player1_points, player2_points}: {player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seal a guess for the current game.
+   * The guess stays hidden until both players have guessed and reveal it
+   * with `reveal_guess`.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player making the guess
+   * * `commitment` - `keccak256(session_id || player || guess || salt)` for a guess
+   * between 1 and 10
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  make_guess: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a sealed guess.
+   * Can only be called after both players have guessed and before the
+   * reveal deadline. The second reveal generates the winning number,
+   * determines the winner and ends the session.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the revealing player
+   * * `guess` - The guessed number (1-10)
+   * * `salt` - The salt sealed with the guess in `make_guess`
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_guess: ({session_id, player, guess, salt}: {session_id: u32, player: string, guess: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game whose reveal deadline has passed without both reveals.
+   * A player who revealed wins against one who did not; if neither
+   * revealed, the game is a draw. Anyone can call this.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
+   */
+  claim_forfeit: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -100,6 +184,68 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the schema version of the stored data
+   * 
+   * # Returns
+   * * `u32` - Schema version; lags this build's version until `migrate` runs after an upgrade
+   */
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Convert games stored by an earlier build to the current layout (admin or upgrader).
+   * Run after `upgrade`, once the beacon is set. Missing or already converted
+   * games are skipped, so a large backlog can be migrated in batches; the
+   * version only moves once the last batch is sent.
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `session_ids` - Session IDs of in-flight games
+   * * `complete` - True on the last batch; records this build's schema version
+   * 
+   * # Returns
+   * * `u32` - Number of games converted
+   */
+  migrate: ({caller, session_ids, complete}: {caller: string, session_ids: Array<u32>, complete: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin transfer waiting to be accepted, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingAdmin>>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address (admin or hub manager)
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_hub` - The new GameHub contract address
+   */
+  set_hub: ({caller, new_hub}: {caller: string, new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   * 
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only). The transfer completes when
+   * `new_admin` calls `accept_admin`; proposing again replaces it.
+   * 
+   * # Arguments
+   * * `new_admin` - The proposed admin address
+   * * `live_until_ledger` - Last ledger on which the proposal can be accepted
+   */
+  propose_admin: ({new_admin, live_until_ledger}: {new_admin: string, live_until_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -110,56 +256,71 @@ export interface Client {
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (admin or upgrader)
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  upgrade: ({caller, new_wasm_hash}: {caller: string, new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Make a guess for the current game.
-   * Players can guess a number between 1 and 10.
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin proposal (proposed admin only)
    * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player making the guess
-   * * `guess` - The guessed number (1-10)
+   * # Returns
+   * * `Address` - The new admin address
    */
-  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
+   * Construct and simulate a get_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the randomness beacon contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The randomness beacon contract address
+   */
+  get_beacon: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account (admin only)
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `role` - The role to revoke
+   * * `account` - The account losing it
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have made their guesses.
-   * This generates the winning number, determines the winner, and ends the session.
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account (admin only)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
+   * * `role` - The role to grant
+   * * `account` - The account receiving it
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the randomness beacon contract address (admin or hub manager)
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_beacon` - The new randomness beacon contract address
+   */
+  set_beacon: ({caller, new_beacon}: {caller: string, new_beacon: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account was granted a role
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `bool` - True if granted (the admin holds every role implicitly)
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
 }
 export class Client extends ContractClient {
@@ -181,31 +342,54 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAIAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAF",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAABRTZXNzaW9uQWxyZWFkeUV4aXN0cwAAAAYAAAAAAAAADUludmFsaWRSZXZlYWwAAAAAAAAHAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAACAAAAAAAAAASUmV2ZWFsV2luZG93Q2xvc2VkAAAAAAAJAAAAAAAAABBSZXZlYWxXaW5kb3dPcGVuAAAACgAAAAAAAAAPU2Vzc2lvbkV4cGlyaW5nAAAAAAsAAAAAAAAADEludmFsaWRHdWVzcwAAAAw=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAPAAAAAAAAAAdpc19kcmF3AAAAAAEAAAAAAAAAB3BsYXllcjEAAAAAEwAAAEVga2VjY2FrMjU2KHNlc3Npb25faWQgfHwgcGxheWVyIHx8IGd1ZXNzIHx8IHNhbHQpYCBzZWFsZWQgYnkgcGxheWVyIDEAAAAAAAAScGxheWVyMV9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAfUGxheWVyIDEncyBndWVzcywgb25jZSByZXZlYWxlZAAAAAANcGxheWVyMV9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAB5QbGF5ZXIgMSdzIHNhbHQsIG9uY2UgcmV2ZWFsZWQAAAAAAAxwbGF5ZXIxX3NhbHQAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADXBsYXllcjJfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjJfc2FsdAAAA+gAAAPuAAAAIAAAAFBMYXN0IGxlZGdlciBvbiB3aGljaCBndWVzc2VzIGNhbiBiZSByZXZlYWxlZCwgc2V0IG9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgZ3Vlc3NlZAAAAA9yZXZlYWxfZGVhZGxpbmUAAAAD6AAAAAQAAAAvQmVhY29uIG91dHB1dCB0aGUgd2lubmluZyBudW1iZXIgd2FzIGRyYXduIGZyb20AAAAABHNlZWQAAAPoAAAD7gAAACAAAAAAAAAABndpbm5lcgAAAAAD6AAAABMAAAAAAAAADndpbm5pbmdfbnVtYmVyAAAAAAPoAAAABA==",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAAAAAAAAAAAACkFkbWluRXJyb3IAAAAAAAQAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAABkAAAAAAAAABRBZG1pblByb3Bvc2FsRXhwaXJlZAAAAGUAAAAAAAAADUludmFsaWRFeHBpcnkAAAAAAABmAAAAAAAAAAtNaXNzaW5nUm9sZQAAAABn",
+        "AAAAAQAAADJBZG1pbiB0cmFuc2ZlciB3YWl0aW5nIGZvciB0aGUgbmV3IGFkbWluIHRvIGFjY2VwdAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAxTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAgAAAD5QZXJtaXNzaW9uIHRoYXQgY2FuIGJlIGdyYW50ZWQgdG8gYW4gYWNjb3VudCBiZXNpZGVzIHRoZSBhZG1pbgAAAAAAAAAAAARSb2xlAAAAAwAAAAAAAAAdQ2FuIHJlcGxhY2UgdGhlIGNvbnRyYWN0IFdBU00AAAAAAAAIVXBncmFkZXIAAAAAAAAAWENhbiBjaGFuZ2UgdGhlIEdhbWVIdWIgYWRkcmVzcyBhIGdhbWUgcmVwb3J0cyB0byBhbmQgdGhlIHJhbmRvbW5lc3MKYmVhY29uIGl0IGRyYXdzIGZyb20AAAAKSHViTWFuYWdlcgAAAAAAAAAAABpDYW4gcGF1c2UgYW5kIHVucGF1c2UgcGxheQAAAAAABlBhdXNlcgAA",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAAjNTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCgojIFJldHVybnMKKiBgdTMyYCAtIFNlc3Npb24gSUQgYWxsb2NhdGVkIGJ5IHRoZSBHYW1lIEh1YgAAAAAKc3RhcnRfZ2FtZQAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAUpTZWFsIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClRoZSBndWVzcyBzdGF5cyBoaWRkZW4gdW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgZ3Vlc3NlZCBhbmQgcmV2ZWFsIGl0CndpdGggYHJldmVhbF9ndWVzc2AuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBjb21taXRtZW50YCAtIGBrZWNjYWsyNTYoc2Vzc2lvbl9pZCB8fCBwbGF5ZXIgfHwgZ3Vlc3MgfHwgc2FsdClgIGZvciBhIGd1ZXNzCmJldHdlZW4gMSBhbmQgMTAAAAAAAAptYWtlX2d1ZXNzAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAYtSZXZlYWwgYSBzZWFsZWQgZ3Vlc3MuCkNhbiBvbmx5IGJlIGNhbGxlZCBhZnRlciBib3RoIHBsYXllcnMgaGF2ZSBndWVzc2VkIGFuZCBiZWZvcmUgdGhlCnJldmVhbCBkZWFkbGluZS4gVGhlIHNlY29uZCByZXZlYWwgZ2VuZXJhdGVzIHRoZSB3aW5uaW5nIG51bWJlciwKZGV0ZXJtaW5lcyB0aGUgd2lubmVyIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcmV2ZWFsaW5nIHBsYXllcgoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApCiogYHNhbHRgIC0gVGhlIHNhbHQgc2VhbGVkIHdpdGggdGhlIGd1ZXNzIGluIGBtYWtlX2d1ZXNzYAAAAAAMcmV2ZWFsX2d1ZXNzAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAUJFbmQgYSBnYW1lIHdob3NlIHJldmVhbCBkZWFkbGluZSBoYXMgcGFzc2VkIHdpdGhvdXQgYm90aCByZXZlYWxzLgpBIHBsYXllciB3aG8gcmV2ZWFsZWQgd2lucyBhZ2FpbnN0IG9uZSB3aG8gZGlkIG5vdDsgaWYgbmVpdGhlcgpyZXZlYWxlZCwgdGhlIGdhbWUgaXMgYSBkcmF3LiBBbnlvbmUgY2FuIGNhbGwgdGhpcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBmb3IgYSBkcmF3AAAAAAANY2xhaW1fZm9yZmVpdAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
         "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAI5HZXQgdGhlIHNjaGVtYSB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgZGF0YQoKIyBSZXR1cm5zCiogYHUzMmAgLSBTY2hlbWEgdmVyc2lvbjsgbGFncyB0aGlzIGJ1aWxkJ3MgdmVyc2lvbiB1bnRpbCBgbWlncmF0ZWAgcnVucyBhZnRlciBhbiB1cGdyYWRlAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAgNDb252ZXJ0IGdhbWVzIHN0b3JlZCBieSBhbiBlYXJsaWVyIGJ1aWxkIHRvIHRoZSBjdXJyZW50IGxheW91dCAoYWRtaW4gb3IgdXBncmFkZXIpLgpSdW4gYWZ0ZXIgYHVwZ3JhZGVgLCBvbmNlIHRoZSBiZWFjb24gaXMgc2V0LiBNaXNzaW5nIG9yIGFscmVhZHkgY29udmVydGVkCmdhbWVzIGFyZSBza2lwcGVkLCBzbyBhIGxhcmdlIGJhY2tsb2cgY2FuIGJlIG1pZ3JhdGVkIGluIGJhdGNoZXM7IHRoZQp2ZXJzaW9uIG9ubHkgbW92ZXMgb25jZSB0aGUgbGFzdCBiYXRjaCBpcyBzZW50LgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYFVwZ3JhZGVyYAoqIGBzZXNzaW9uX2lkc2AgLSBTZXNzaW9uIElEcyBvZiBpbi1mbGlnaHQgZ2FtZXMKKiBgY29tcGxldGVgIC0gVHJ1ZSBvbiB0aGUgbGFzdCBiYXRjaDsgcmVjb3JkcyB0aGlzIGJ1aWxkJ3Mgc2NoZW1hIHZlcnNpb24KCiMgUmV0dXJucwoqIGB1MzJgIC0gTnVtYmVyIG9mIGdhbWVzIGNvbnZlcnRlZAAAAAAHbWlncmF0ZQAAAAADAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAAAAAAhjb21wbGV0ZQAAAAEAAAABAAAABA==",
+        "AAAAAAAAADVHZXQgdGhlIGFkbWluIHRyYW5zZmVyIHdhaXRpbmcgdG8gYmUgYWNjZXB0ZWQsIGlmIGFueQAAAAAAABFnZXRfcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMUGVuZGluZ0FkbWlu",
+        "AAAAAAAAAK9TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvciBodWIgbWFuYWdlcikKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYW4gYWNjb3VudCBob2xkaW5nIGBIdWJNYW5hZ2VyYAoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAdzZXRfaHViAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHbmV3X2h1YgAAAAATAAAAAA==",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAP5Qcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHRyYW5zZmVyIGNvbXBsZXRlcyB3aGVuCmBuZXdfYWRtaW5gIGNhbGxzIGBhY2NlcHRfYWRtaW5gOyBwcm9wb3NpbmcgYWdhaW4gcmVwbGFjZXMgaXQuCgojIEFyZ3VtZW50cwoqIGBuZXdfYWRtaW5gIC0gVGhlIHByb3Bvc2VkIGFkbWluIGFkZHJlc3MKKiBgbGl2ZV91bnRpbF9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAADXByb3Bvc2VfYWRtaW4AAAAAAAACAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAfQAAAACkFkbWluRXJyb3IAAA==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAOJNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApAAAAAAAKbWFrZV9ndWVzcwAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAATtSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
+        "AAAAAAAAAKpVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAoYWRtaW4gb3IgdXBncmFkZXIpCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGFuIGFjY291bnQgaG9sZGluZyBgVXBncmFkZXJgCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAB3VwZ3JhZGUAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAGRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHByb3Bvc2FsIChwcm9wb3NlZCBhZG1pbiBvbmx5KQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIG5ldyBhZG1pbiBhZGRyZXNzAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAABMAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAAGpHZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIFRoZSByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAAAAAKZ2V0X2JlYWNvbgAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAHlSZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gcmV2b2tlCiogYGFjY291bnRgIC0gVGhlIGFjY291bnQgbG9zaW5nIGl0AAAAAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAHhHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gZ3JhbnQKKiBgYWNjb3VudGAgLSBUaGUgYWNjb3VudCByZWNlaXZpbmcgaXQAAAAKZ3JhbnRfcm9sZQAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
+        "AAAAAAAAAMRTZXQgdGhlIHJhbmRvbW5lc3MgYmVhY29uIGNvbnRyYWN0IGFkZHJlc3MgKGFkbWluIG9yIGh1YiBtYW5hZ2VyKQoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIFRoZSBhZG1pbiBvciBhbiBhY2NvdW50IGhvbGRpbmcgYEh1Yk1hbmFnZXJgCiogYG5ld19iZWFjb25gIC0gVGhlIG5ldyByYW5kb21uZXNzIGJlYWNvbiBjb250cmFjdCBhZGRyZXNzAAAACnNldF9iZWFjb24AAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAKbmV3X2JlYWNvbgAAAAAAEwAAAAA=",
+        "AAAAAAAAAHlDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgd2FzIGdyYW50ZWQgYSByb2xlCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGdyYW50ZWQgKHRoZSBhZG1pbiBob2xkcyBldmVyeSByb2xlIGltcGxpY2l0bHkpAAAAAAAACGhhc19yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<u32>>,
+        make_guess: this.txFromJSON<Result<void>>,
+        reveal_guess: this.txFromJSON<Result<void>>,
+        claim_forfeit: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        version: this.txFromJSON<u32>,
+        migrate: this.txFromJSON<u32>,
+        get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
+        set_hub: this.txFromJSON<null>,
+        get_hub: this.txFromJSON<string>,
+        propose_admin: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        make_guess: this.txFromJSON<Result<void>>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        upgrade: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<string>>,
+        get_beacon: this.txFromJSON<string>,
+        revoke_role: this.txFromJSON<null>,
+        grant_role: this.txFromJSON<null>,
+        set_beacon: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>
   }
 }
\ No newline at end of file
@@ -2,23 +2,11 @@ import { useState, useEffect, useRef, useMemo } from 'react';
 import { ZkMemoryService } from './zkMemoryService';
 import { useWallet } from '@/hooks/useWallet';
 import { ZK_MEMORY_CONTRACT } from '@/utils/constants';
+import { getLatestLedgerSequence, findStartedSession } from '@/utils/sessionUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
 import { createCommittedDeck, hexToBuffer, generateMockProof, generateMockPublicInputs } from './deckUtils';
 import type { GameState, CardState } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
-
 const zkMemoryService = new ZkMemoryService(ZK_MEMORY_CONTRACT);
 
 interface ZkMemoryGameProps {
@@ -43,7 +31,8 @@ export function ZkMemoryGame({
   const { getContractSigner, walletType } = useWallet();
   
   // Game state
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Allocated by the Game Hub when the game starts; 0 until then
+  const [sessionId, setSessionId] = useState<number>(0);
   const [gameState, setGameState] = useState<GameState | null>(null);
   const [deck, setDeck] = useState<number[]>([]);
   const [salt, setSalt] = useState<string>('');
@@ -154,9 +143,11 @@ export function ZkMemoryGame({
       const signer = await getContractSigner();
       const commitmentBuffer = hexToBuffer(newCommitment);
 
+      // The hub allocates the session ID, so look for the game_started event from here on
+      const startLedger = await getLatestLedgerSequence();
+
       // Prepare auth entry for Player 1 with embedded encrypted deck data
       const authEntryXDR = await zkMemoryService.prepareStartGame(
-        sessionId,
         player1Address,
         player2Address,
         p1Points,
@@ -169,6 +160,33 @@ export function ZkMemoryGame({
       setExportedAuthEntryXDR(authEntryXDR);
       
       setSuccess('Game prepared! Player 1 (you) will go first. Share the auth entry with Player 2.');
+
+      // Wait for Player 2 to submit, then pick up the session the hub allocated
+      const pollInterval = setInterval(async () => {
+        try {
+          const startedSessionId = await findStartedSession(
+            ZK_MEMORY_CONTRACT,
+            player1Address,
+            player2Address,
+            startLedger
+          );
+          const game = startedSessionId ? await zkMemoryService.getGame(startedSessionId) : null;
+          if (startedSessionId && game) {
+            clearInterval(pollInterval);
+            setSessionId(startedSessionId);
+            setGameState(game);
+            setExportedAuthEntryXDR(null);
+            setGamePhase('playing');
+            setSuccess('Game started! Player 2 has signed and submitted.');
+            onStandingsRefresh();
+          }
+        } catch (err) {
+          console.log('Polling for game creation...', err);
+        }
+      }, 3000);
+
+      // Stop polling after 5 minutes
+      setTimeout(() => clearInterval(pollInterval), 300000);
     } catch (err: any) {
       setError(err.message || 'Failed to create game');
       console.error('Create game error:', err);
@@ -194,6 +212,11 @@ export function ZkMemoryGame({
         throw new Error('Invalid points amount');
       }
 
+      // Player 1 signed for a specific opponent; only that wallet can accept
+      if (parsed.player2 !== userAddress) {
+        throw new Error(`This auth entry invites ${parsed.player2}. Connect that wallet to accept it.`);
+      }
+
       const signer = await getContractSigner();
 
       // Import and sign (this also decrypts the embedded deck data)
@@ -212,13 +235,14 @@ export function ZkMemoryGame({
 
       // Finalize and submit
       console.log('[ImportAndStart] Calling finalizeStartGame...');
-      await zkMemoryService.finalizeStartGame(txXdr, userAddress, signer);
-      console.log('[ImportAndStart] Game successfully created on blockchain!');
+      const startResult = await zkMemoryService.finalizeStartGame(txXdr, userAddress, signer);
+      const newSessionId = startResult.unwrap();
+      console.log('[ImportAndStart] Game successfully created on blockchain! Hub session ID:', newSessionId);
 
-      setSessionId(parsed.sessionId);
+      setSessionId(newSessionId);
       
       // Load initial game state
-      const game = await zkMemoryService.getGame(parsed.sessionId);
+      const game = await zkMemoryService.getGame(newSessionId);
       if (game) {
         setGameState(game);
         console.log('[ImportAndStart] Initial game state loaded:', {
@@ -241,7 +265,7 @@ export function ZkMemoryGame({
       
       // Debug: Log the complete game state
       console.log('[ImportAndStart] Complete game state:', JSON.stringify({
-        sessionId: parsed.sessionId,
+        sessionId: newSessionId,
         player1: game?.player1,
         player2: game?.player2,
         currentTurn: game?.current_turn,
@@ -409,7 +433,7 @@ export function ZkMemoryGame({
     
     actionLock.current = false;
     setGamePhase('create');
-    setSessionId(createRandomSessionId());
+    setSessionId(0);
     setGameState(null);
     setDeck([]);
     setSalt('');
@@ -498,15 +522,6 @@ export function ZkMemoryGame({
 
           {createMode === 'create' && (
             <div className="space-y-4">
-              <div>
-                <label className="block mb-2">Session ID</label>
-                <input
-                  type="number"
-                  value={sessionId}
-                  onChange={(e) => setSessionId(Number(e.target.value))}
-                  className="w-full p-2 border rounded"
-                />
-              </div>
               <div>
                 <label className="block mb-2">Player 1 (You)</label>
                 <input
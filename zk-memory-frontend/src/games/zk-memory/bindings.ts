@@ -38,6 +38,7 @@ export const networks = {
   }
 } as const
 
+
 export const Errors = {
   1: {message:"GameNotFound"},
   2: {message:"GameNotActive"},
@@ -45,14 +46,16 @@ export const Errors = {
   4: {message:"CardAlreadyMatched"},
   5: {message:"InvalidProof"},
   6: {message:"InvalidPosition"},
-  7: {message:"NotPlayer"}
+  7: {message:"NotPlayer"},
+  8: {message:"SessionAlreadyExists"},
+  100: {message:"NoPendingAdmin"},
+  101: {message:"AdminProposalExpired"},
+  102: {message:"InvalidExpiry"},
+  103: {message:"MissingRole"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
-
 export type CardState = {tag: "FaceDown", values: void} | {tag: "Matched", values: void};
 
-
 export interface GameState {
   cards: Array<CardState>;
   current_turn: string;
@@ -70,45 +73,44 @@ export interface GameState {
   session_id: u32;
 }
 
-export interface Client {
-  /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
-   * 
-   * # Returns
-   * * `Address` - The GameHub contract address
-   */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+export type DataKey = {tag: "Game", values: readonly [u32]};
 
+/**
+ * Admin transfer waiting for the new admin to accept
+ */
+export interface PendingAdmin {
+  address: string;
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
-   * 
-   * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * Last ledger on which the proposal can be accepted
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  live_until_ledger: u32;
+}
 
-  /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
-   * 
-   * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
-   */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+/**
+ * Permission that can be granted to an account besides the admin
+ */
+export type Role = {tag: "Upgrader", values: void} | {tag: "HubManager", values: void} | {tag: "Pauser", values: void};
 
+export interface Client {
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information.
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points and a committed deck.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `deck_commitment` - Poseidon hash of the shuffled deck + salt (32 bytes)
    * 
    * # Returns
-   * * `GameState` - The complete game state
+   * * `u32` - Session ID allocated by the Game Hub
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameState>>>
+  start_game: ({player1, player2, player1_points, player2_points, deck_commitment}: {player1: string, player2: string, player1_points: i128, player2_points: i128, deck_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a flip_card transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -130,6 +132,73 @@ export interface Client {
    */
   flip_card: ({session_id, player, position, revealed_value, proof, public_inputs}: {session_id: u32, player: string, position: u32, revealed_value: u32, proof: Buffer, public_inputs: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `GameState` - The complete game state
+   */
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameState>>>
+
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the schema version of the stored data
+   * 
+   * # Returns
+   * * `u32` - Schema version; lags this build's version until data is migrated after an upgrade
+   */
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin transfer waiting to be accepted, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingAdmin>>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin proposal (proposed admin only)
+   * 
+   * # Returns
+   * * `Address` - The new admin address
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin (admin only). The transfer completes when
+   * `new_admin` calls `accept_admin`; proposing again replaces it.
+   * 
+   * # Arguments
+   * * `new_admin` - The proposed admin address
+   * * `live_until_ledger` - Last ledger on which the proposal can be accepted
+   */
+  propose_admin: ({new_admin, live_until_ledger}: {new_admin: string, live_until_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address (admin or hub manager)
+   * 
+   * # Arguments
+   * * `caller` - The admin or an account holding `HubManager`
+   * * `new_hub` - The new GameHub contract address
+   */
+  set_hub: ({caller, new_hub}: {caller: string, new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account (admin only)
+   * 
+   * # Arguments
+   * * `role` - The role to grant
+   * * `account` - The account receiving it
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -140,31 +209,42 @@ export interface Client {
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account was granted a role
+   * 
+   * # Returns
+   * * `bool` - True if granted (the admin holds every role implicitly)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (admin or upgrader)
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `caller` - The admin or an account holding `Upgrader`
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  upgrade: ({caller, new_wasm_hash}: {caller: string, new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points and a committed deck.
-   * This creates a session in the Game Hub and locks points before starting the game.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account (admin only)
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
-   * * `deck_commitment` - Poseidon hash of the shuffled deck + salt (32 bytes)
+   * * `role` - The role to revoke
+   * * `account` - The account losing it
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points, deck_commitment}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, deck_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -186,30 +266,45 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAANR2FtZU5vdEFjdGl2ZQAAAAAAAAIAAAAAAAAAC05vdFlvdXJUdXJuAAAAAAMAAAAAAAAAEkNhcmRBbHJlYWR5TWF0Y2hlZAAAAAAABAAAAAAAAAAMSW52YWxpZFByb29mAAAABQAAAAAAAAAPSW52YWxpZFBvc2l0aW9uAAAAAAYAAAAAAAAACU5vdFBsYXllcgAAAAAAAAc=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAANR2FtZU5vdEFjdGl2ZQAAAAAAAAIAAAAAAAAAC05vdFlvdXJUdXJuAAAAAAMAAAAAAAAAEkNhcmRBbHJlYWR5TWF0Y2hlZAAAAAAABAAAAAAAAAAMSW52YWxpZFByb29mAAAABQAAAAAAAAAPSW52YWxpZFBvc2l0aW9uAAAAAAYAAAAAAAAACU5vdFBsYXllcgAAAAAAAAcAAAAAAAAAFFNlc3Npb25BbHJlYWR5RXhpc3RzAAAACA==",
         "AAAAAgAAAAAAAAAAAAAACUNhcmRTdGF0ZQAAAAAAAAIAAAAAAAAAAAAAAAhGYWNlRG93bgAAAAAAAAAAAAAAB01hdGNoZWQA",
         "AAAAAQAAAAAAAAAAAAAACUdhbWVTdGF0ZQAAAAAAAA4AAAAAAAAABWNhcmRzAAAAAAAD6gAAB9AAAAAJQ2FyZFN0YXRlAAAAAAAAAAAAAAxjdXJyZW50X3R1cm4AAAATAAAAAAAAAA9kZWNrX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAACGZsaXBfb25lAAAD6AAAAAQAAAAAAAAADmZsaXBfb25lX3ZhbHVlAAAAAAPoAAAABAAAAAAAAAAJaXNfYWN0aXZlAAAAAAAAAQAAAAAAAAALcGFpcnNfZm91bmQAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGc2NvcmUxAAAAAAAEAAAAAAAAAAZzY29yZTIAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQ=",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAIFHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lU3RhdGVgIC0gVGhlIGNvbXBsZXRlIGdhbWUgc3RhdGUAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACUdhbWVTdGF0ZQAAAAAAAAM=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAAAAAAAAAAAACkFkbWluRXJyb3IAAAAAAAQAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAABkAAAAAAAAABRBZG1pblByb3Bvc2FsRXhwaXJlZAAAAGUAAAAAAAAADUludmFsaWRFeHBpcnkAAAAAAABmAAAAAAAAAAtNaXNzaW5nUm9sZQAAAABn",
+        "AAAAAQAAADJBZG1pbiB0cmFuc2ZlciB3YWl0aW5nIGZvciB0aGUgbmV3IGFkbWluIHRvIGFjY2VwdAAAAAAAAAAAAAxQZW5kaW5nQWRtaW4AAAACAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAxTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAgAAAD5QZXJtaXNzaW9uIHRoYXQgY2FuIGJlIGdyYW50ZWQgdG8gYW4gYWNjb3VudCBiZXNpZGVzIHRoZSBhZG1pbgAAAAAAAAAAAARSb2xlAAAAAwAAAAAAAAAdQ2FuIHJlcGxhY2UgdGhlIGNvbnRyYWN0IFdBU00AAAAAAAAIVXBncmFkZXIAAAAAAAAAWENhbiBjaGFuZ2UgdGhlIEdhbWVIdWIgYWRkcmVzcyBhIGdhbWUgcmVwb3J0cyB0byBhbmQgdGhlIHJhbmRvbW5lc3MKYmVhY29uIGl0IGRyYXdzIGZyb20AAAAKSHViTWFuYWdlcgAAAAAAAAAAABpDYW4gcGF1c2UgYW5kIHVucGF1c2UgcGxheQAAAAAABlBhdXNlcgAA",
+        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
+        "AAAAAAAAApNTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMgYW5kIGEgY29tbWl0dGVkIGRlY2suClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXIxYCAtIEFkZHJlc3Mgb2YgZmlyc3QgcGxheWVyCiogYHBsYXllcjJgIC0gQWRkcmVzcyBvZiBzZWNvbmQgcGxheWVyCiogYHBsYXllcjFfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAxCiogYHBsYXllcjJfcG9pbnRzYCAtIFBvaW50cyBhbW91bnQgY29tbWl0dGVkIGJ5IHBsYXllciAyCiogYGRlY2tfY29tbWl0bWVudGAgLSBQb3NlaWRvbiBoYXNoIG9mIHRoZSBzaHVmZmxlZCBkZWNrICsgc2FsdCAoMzIgYnl0ZXMpCgojIFJldHVybnMKKiBgdTMyYCAtIFNlc3Npb24gSUQgYWxsb2NhdGVkIGJ5IHRoZSBHYW1lIEh1YgAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAPZGVja19jb21taXRtZW50AAAAA+4AAAAgAAAAAQAAA+kAAAAEAAAAAw==",
         "AAAAAAAAAtxGbGlwIGEgY2FyZCB3aXRoIFpLIHByb29mIHZlcmlmaWNhdGlvbi4KUGxheWVycyBzdWJtaXQgYSBwb3NpdGlvbiwgcmV2ZWFsZWQgdmFsdWUsIGFuZCBaSyBwcm9vZiB0aGF0IHRoZSByZXZlYWwgaXMgaG9uZXN0LgoKKipaSyBQcm9vZjoqKiBUaGUgcHJvb2YgZGVtb25zdHJhdGVzIHRoYXQ6CjEuIFRoZSBwcm92ZXIga25vd3MgdGhlIGZ1bGwgZGVjayB0aGF0IG1hdGNoZXMgdGhlIG9uLWNoYWluIGNvbW1pdG1lbnQKMi4gVGhlIHJldmVhbGVkIHZhbHVlIGlzIGFjdHVhbGx5IGF0IHRoZSBjbGFpbWVkIHBvc2l0aW9uCjMuIFRoZSBkZWNrIGhhcyBub3QgYmVlbiB0YW1wZXJlZCB3aXRoIHNpbmNlIGNvbW1pdG1lbnQKCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBtYWtpbmcgdGhlIGZsaXAKKiBgcG9zaXRpb25gIC0gQ2FyZCBwb3NpdGlvbiB0byBmbGlwICgwLTE1KQoqIGByZXZlYWxlZF92YWx1ZWAgLSBUaGUgY2FyZCB2YWx1ZSBiZWluZyByZXZlYWxlZCAoMS04LCBlYWNoIGFwcGVhcnMgdHdpY2UpCiogYHByb29mYCAtIFpLIHByb29mIGJ5dGVzIChOb2lyL0JhcnJldGVuYmVyZyBnZW5lcmF0ZWQpCiogYHB1YmxpY19pbnB1dHNgIC0gUHVibGljIGlucHV0cyBmb3IgdmVyaWZpY2F0aW9uIFtwb3NpdGlvbiwgZGVja19jb21taXRtZW50LCByZXZlYWxlZF92YWx1ZV0AAAAJZmxpcF9jYXJkAAAAAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAhwb3NpdGlvbgAAAAQAAAAAAAAADnJldmVhbGVkX3ZhbHVlAAAAAAAEAAAAAAAAAAVwcm9vZgAAAAAAAA4AAAAAAAAADXB1YmxpY19pbnB1dHMAAAAAAAPqAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAIFHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lU3RhdGVgIC0gVGhlIGNvbXBsZXRlIGdhbWUgc3RhdGUAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAACUdhbWVTdGF0ZQAAAAAAAAM=",
+        "AAAAAAAAAJBHZXQgdGhlIHNjaGVtYSB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgZGF0YQoKIyBSZXR1cm5zCiogYHUzMmAgLSBTY2hlbWEgdmVyc2lvbjsgbGFncyB0aGlzIGJ1aWxkJ3MgdmVyc2lvbiB1bnRpbCBkYXRhIGlzIG1pZ3JhdGVkIGFmdGVyIGFuIHVwZ3JhZGUAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAADVHZXQgdGhlIGFkbWluIHRyYW5zZmVyIHdhaXRpbmcgdG8gYmUgYWNjZXB0ZWQsIGlmIGFueQAAAAAAABFnZXRfcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAB9AAAAAMUGVuZGluZ0FkbWlu",
+        "AAAAAAAAAGRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHByb3Bvc2FsIChwcm9wb3NlZCBhZG1pbiBvbmx5KQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIG5ldyBhZG1pbiBhZGRyZXNzAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAABMAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAAP5Qcm9wb3NlIGEgbmV3IGFkbWluIChhZG1pbiBvbmx5KS4gVGhlIHRyYW5zZmVyIGNvbXBsZXRlcyB3aGVuCmBuZXdfYWRtaW5gIGNhbGxzIGBhY2NlcHRfYWRtaW5gOyBwcm9wb3NpbmcgYWdhaW4gcmVwbGFjZXMgaXQuCgojIEFyZ3VtZW50cwoqIGBuZXdfYWRtaW5gIC0gVGhlIHByb3Bvc2VkIGFkbWluIGFkZHJlc3MKKiBgbGl2ZV91bnRpbF9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgb24gd2hpY2ggdGhlIHByb3Bvc2FsIGNhbiBiZSBhY2NlcHRlZAAAAAAADXByb3Bvc2VfYWRtaW4AAAAAAAACAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAfQAAAACkFkbWluRXJyb3IAAA==",
+        "AAAAAAAAAK9TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvciBodWIgbWFuYWdlcikKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBUaGUgYWRtaW4gb3IgYW4gYWNjb3VudCBob2xkaW5nIGBIdWJNYW5hZ2VyYAoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAdzZXRfaHViAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHbmV3X2h1YgAAAAATAAAAAA==",
+        "AAAAAAAAAHhHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gZ3JhbnQKKiBgYWNjb3VudGAgLSBUaGUgYWNjb3VudCByZWNlaXZpbmcgaXQAAAAKZ3JhbnRfcm9sZQAAAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAA==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAopTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMgYW5kIGEgY29tbWl0dGVkIGRlY2suClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgoqIGBkZWNrX2NvbW1pdG1lbnRgIC0gUG9zZWlkb24gaGFzaCBvZiB0aGUgc2h1ZmZsZWQgZGVjayArIHNhbHQgKDMyIGJ5dGVzKQAAAAAACnN0YXJ0X2dhbWUAAAAAAAYAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAAD2RlY2tfY29tbWl0bWVudAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA" ]),
+        "AAAAAAAAAHlDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgd2FzIGdyYW50ZWQgYSByb2xlCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGdyYW50ZWQgKHRoZSBhZG1pbiBob2xkcyBldmVyeSByb2xlIGltcGxpY2l0bHkpAAAAAAAACGhhc19yb2xlAAAAAgAAAAAAAAAEcm9sZQAAB9AAAAAEUm9sZQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAKpVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAoYWRtaW4gb3IgdXBncmFkZXIpCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gVGhlIGFkbWluIG9yIGFuIGFjY291bnQgaG9sZGluZyBgVXBncmFkZXJgCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAB3VwZ3JhZGUAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAHlSZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudCAoYWRtaW4gb25seSkKCiMgQXJndW1lbnRzCiogYHJvbGVgIC0gVGhlIHJvbGUgdG8gcmV2b2tlCiogYGFjY291bnRgIC0gVGhlIGFjY291bnQgbG9zaW5nIGl0AAAAAAAAC3Jldm9rZV9yb2xlAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
-        get_game: this.txFromJSON<Result<GameState>>,
+    start_game: this.txFromJSON<Result<u32>>,
         flip_card: this.txFromJSON<Result<void>>,
+        get_game: this.txFromJSON<Result<GameState>>,
+        version: this.txFromJSON<u32>,
+        get_pending_admin: this.txFromJSON<Option<PendingAdmin>>,
+        accept_admin: this.txFromJSON<Result<string>>,
+        propose_admin: this.txFromJSON<Result<void>>,
+        set_hub: this.txFromJSON<null>,
+        grant_role: this.txFromJSON<null>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>
+        has_role: this.txFromJSON<boolean>,
+        upgrade: this.txFromJSON<null>,
+        get_hub: this.txFromJSON<string>,
+        revoke_role: this.txFromJSON<null>
   }
 }
\ No newline at end of file
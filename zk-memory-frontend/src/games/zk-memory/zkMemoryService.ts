@@ -1,4 +1,4 @@
-import { Client as ZkMemoryClient, type Game } from './bindings';
+import { Client as ZkMemoryClient, type GameState as Game } from './bindings';
 import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, TransactionBuilder, StrKey, xdr, Address, authorizeEntry } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
@@ -69,9 +69,9 @@ export class ZkMemoryService {
   /**
    * Start a new game (requires multi-sig authorization)
    * Note: This requires both players to sign the transaction
+   * Returns the session ID allocated by the Game Hub
    */
   async startGame(
-    sessionId: number,
     player1: string,
     player2: string,
     player1Points: bigint,
@@ -82,7 +82,6 @@ export class ZkMemoryService {
   ) {
     const client = this.createSigningClient(player1, signer);
     const tx = await client.start_game({
-      session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
@@ -112,10 +111,11 @@ export class ZkMemoryService {
    *
    * Uses extended TTL (60 minutes) for multi-sig flow to allow time for both players to sign
    *
+   * Player 1's auth entry binds Player 2's address, so the opponent must be known up front.
    * Player 2 will later import this auth entry, extract and decrypt the deck data, and rebuild the transaction
+   * The session ID is allocated by the Game Hub when the transaction is submitted.
    */
   async prepareStartGame(
-    sessionId: number,
     player1: string,
     player2: string,
     player1Points: bigint,
@@ -134,7 +134,6 @@ export class ZkMemoryService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
@@ -248,12 +247,12 @@ export class ZkMemoryService {
    *
    * Auth entries from require_auth_for_args only contain the args that player is authorizing:
    * - Player address (from credentials)
-   * - Session ID (arg 0)
+   * - Opponent's address (arg 0)
    * - Player's points (arg 1)
    */
   parseAuthEntry(combinedPayload: string): {
-    sessionId: number;
     player1: string;
+    player2: string;
     player1Points: bigint;
     functionName: string;
     encryptionKey: string;
@@ -304,7 +303,7 @@ export class ZkMemoryService {
 
       // Extract arguments from the invocation
       // For start_game with require_auth_for_args, we have:
-      // 0: session_id (u32)
+      // 0: opponent (Address)
       // 1: player_points (i128)
       const args = contractFn.args();
       console.log('[parseAuthEntry] Number of args:', args.length);
@@ -313,18 +312,18 @@ export class ZkMemoryService {
         throw new Error(`Expected 2 arguments for start_game auth entry, got ${args.length}`);
       }
 
-      const sessionId = args[0].u32();
+      const player2 = Address.fromScVal(args[0]).toString();
       const player1Points = args[1].i128().lo().toBigInt();
 
       console.log('[parseAuthEntry] Extracted:', {
-        sessionId,
         player1,
+        player2,
         player1Points: player1Points.toString(),
       });
 
       return {
-        sessionId,
         player1,
+        player2,
         player1Points,
         functionName,
         encryptionKey,
@@ -340,7 +339,7 @@ export class ZkMemoryService {
    * STEP 2 (Player 2): Import Player 1's signed auth entry with embedded encrypted deck data
    * - Parses Player 1's signed auth entry to extract game parameters
    * - Decrypts the embedded deck data
-   * - Validates that the current user is Player 2
+   * - Validates that the current user is the Player 2 the auth entry was signed for
    * - Rebuilds the transaction with Player 2 as source
    * - Injects Player 1's signed auth entry (replacing the stub)
    * - Signs Player 2's auth entry if needed
@@ -367,8 +366,8 @@ export class ZkMemoryService {
     const gameParams = this.parseAuthEntry(combinedPayload);
 
     console.log('[importAndSignAuthEntry] Parsed game parameters:', {
-      sessionId: gameParams.sessionId,
       player1: gameParams.player1,
+      player2: gameParams.player2,
       player1Points: gameParams.player1Points.toString(),
     });
     
@@ -386,6 +385,11 @@ export class ZkMemoryService {
     if (player2Address === gameParams.player1) {
       throw new Error('Cannot play against yourself. Player 2 must be different from Player 1.');
     }
+
+    // Player 1 signed for a specific opponent; nobody else can complete the start
+    if (player2Address !== gameParams.player2) {
+      throw new Error(`This invite is for ${gameParams.player2}. Connect that wallet to accept it.`);
+    }
     
     // Extract just the auth entry XDR (first part before |)
     const player1SignedAuthEntryXdr = combinedPayload.split('|')[0];
@@ -403,9 +407,8 @@ export class ZkMemoryService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: gameParams.sessionId,
       player1: gameParams.player1,        // From auth entry
-      player2: player2Address,             // Provided by Player 2
+      player2: player2Address,             // Validated against auth entry
       player1_points: gameParams.player1Points, // From auth entry
       player2_points: player2Points,         // Provided by Player 2
       deck_commitment: deckCommitment,       // Provided by Player 2
@@ -486,6 +489,7 @@ export class ZkMemoryService {
    * - Signs transaction envelope and submits to network
    *
    * Can be called by either player, but typically Player 2 (the transaction source)
+   * Returns the session ID allocated by the Game Hub
    */
   async finalizeStartGame(
     xdr: string,
@@ -547,11 +551,10 @@ export class ZkMemoryService {
 
   /**
    * Parse transaction XDR to extract game details
-   * Returns session ID, player addresses, points, deck commitment, and transaction source
+   * Returns player addresses, points, deck commitment, and transaction source
    * Uses proper SDK methods to extract contract invocation parameters
    */
   parseTransactionXDR(xdr: string): {
-    sessionId: number;
     player1: string;
     player2: string;
     player1Points: bigint;
@@ -584,47 +587,42 @@ export class ZkMemoryService {
     const args = invokeContractArgs.args();
 
     // For start_game, the arguments are:
-    // 0: session_id (u32)
-    // 1: player1 (Address)
-    // 2: player2 (Address)
-    // 3: player1_points (i128)
-    // 4: player2_points (i128)
-    // 5: deck_commitment (BytesN<32>)
+    // 0: player1 (Address)
+    // 1: player2 (Address)
+    // 2: player1_points (i128)
+    // 3: player2_points (i128)
+    // 4: deck_commitment (BytesN<32>)
 
     if (functionName !== 'start_game') {
       throw new Error(`Unexpected function: ${functionName}. Expected start_game.`);
     }
 
-    if (args.length !== 6) {
-      throw new Error(`Expected 6 arguments for start_game, got ${args.length}`);
+    if (args.length !== 5) {
+      throw new Error(`Expected 5 arguments for start_game, got ${args.length}`);
     }
 
-    // Extract session_id (u32)
-    const sessionId = args[0].u32();
-
     // Extract player1 (Address)
-    const player1ScVal = args[1];
+    const player1ScVal = args[0];
     const player1Address = player1ScVal.address().accountId().ed25519();
     const player1 = StrKey.encodeEd25519PublicKey(player1Address);
 
     // Extract player2 (Address)
-    const player2ScVal = args[2];
+    const player2ScVal = args[1];
     const player2Address = player2ScVal.address().accountId().ed25519();
     const player2 = StrKey.encodeEd25519PublicKey(player2Address);
 
     // Extract points (i128)
-    const player1PointsScVal = args[3];
+    const player1PointsScVal = args[2];
     const player1Points = player1PointsScVal.i128().lo().toBigInt();
 
-    const player2PointsScVal = args[4];
+    const player2PointsScVal = args[3];
     const player2Points = player2PointsScVal.i128().lo().toBigInt();
 
     // Extract deck_commitment (BytesN<32>)
-    const deckCommitmentScVal = args[5];
+    const deckCommitmentScVal = args[4];
     const deckCommitment = Buffer.from(deckCommitmentScVal.bytes());
 
     return {
-      sessionId,
       player1,
       player2,
       player1Points,
@@ -0,0 +1,70 @@
+/**
+ * Session utilities for games whose session IDs are issued by the Game Hub
+ */
+
+import { rpc, xdr, nativeToScVal, scValToNative } from '@stellar/stellar-sdk';
+import { MOCK_GAME_HUB_CONTRACT, RPC_URL, MULTI_SIG_AUTH_TTL_MINUTES } from './constants';
+
+/**
+ * Get the latest ledger sequence, e.g. to bound a later event search
+ * @returns Latest ledger sequence number
+ */
+export async function getLatestLedgerSequence(): Promise<number> {
+  const server = new rpc.Server(RPC_URL);
+  const latestLedger = await server.getLatestLedger();
+  return latestLedger.sequence;
+}
+
+/**
+ * Find the session the hub allocated for a game between two players.
+ * Player 1 signs an auth entry and never sees the start transaction's result,
+ * so the session ID is recovered from the hub's `["game_started", "v1"]` event.
+ *
+ * @param gameId - The game contract that started the session
+ * @param player1 - Player 1's address
+ * @param player2 - Player 2's address
+ * @param startLedger - First ledger to search (before the game could have started)
+ * @returns The newest matching session ID, or null if the game has not started yet
+ */
+export async function findStartedSession(
+  gameId: string,
+  player1: string,
+  player2: string,
+  startLedger: number
+): Promise<number | null> {
+  const server = new rpc.Server(RPC_URL);
+  const topics = [
+    nativeToScVal('game_started', { type: 'symbol' }).toXDR('base64'),
+    nativeToScVal('v1', { type: 'symbol' }).toXDR('base64'),
+  ];
+
+  const response = await server.getEvents({
+    startLedger,
+    filters: [{ type: 'contract', contractIds: [MOCK_GAME_HUB_CONTRACT], topics: [topics] }],
+    limit: 100,
+  });
+
+  let sessionId: number | null = null;
+  for (const event of response.events) {
+    const data = scValToNative(event.value);
+    if (data.game_id === gameId && data.player1 === player1 && data.player2 === player2) {
+      sessionId = Number(data.session_id);
+    }
+  }
+  return sessionId;
+}
+
+/**
+ * Earliest ledger a multi-sig start game auth entry could have been signed on,
+ * derived from its signature expiration and the TTL it was signed with
+ * @param authEntryXdr - Player 1's signed auth entry in XDR format
+ * @returns Ledger sequence to start searching for the started session from
+ */
+export function getAuthEntrySignedLedger(authEntryXdr: string): number {
+  const authEntry = xdr.SorobanAuthorizationEntry.fromXDR(authEntryXdr, 'base64');
+  const expiration = authEntry.credentials().address().signatureExpirationLedger();
+
+  // Stellar ledgers close approximately every 5 seconds
+  const LEDGERS_PER_MINUTE = 12;
+  return Math.max(1, expiration - MULTI_SIG_AUTH_TTL_MINUTES * LEDGERS_PER_MINUTE);
+}